@@ -205,6 +205,8 @@ impl ApplicationRunner {
             cx.send_event(event);
         }
 
+        cx.process_timers();
+
         // Events
         while self.event_manager.flush_events(cx.context()) {}
 
@@ -74,6 +74,14 @@ impl AccessNode {
         self.node_builder.set_word_lengths(word_lengths);
     }
 
+    pub fn set_table_row_count(&mut self, count: usize) {
+        self.node_builder.set_table_row_count(count);
+    }
+
+    pub fn set_table_column_count(&mut self, count: usize) {
+        self.node_builder.set_table_column_count(count);
+    }
+
     pub fn set_numeric_value_step(&mut self, value: f64) {
         self.node_builder.set_numeric_value_step(value);
     }
@@ -3,6 +3,7 @@ use std::collections::HashSet;
 
 use femtovg::{renderer::OpenGl, Canvas};
 use fnv::FnvHashMap;
+use instant::Instant;
 
 use super::EventProxy;
 use crate::style::SystemFlags;
@@ -15,6 +16,7 @@ use crate::{
     state::ModelOrView,
     style::Style,
     systems::*,
+    window::WindowState,
 };
 use vizia_id::GenerationalId;
 
@@ -109,6 +111,138 @@ impl<'a> BackendContext<'a> {
         self.0.cache.set_clip_region(Entity::root(), bounding_box);
 
         self.0.canvases.insert(Entity::root(), canvas);
+        self.0.windows.insert(Entity::root(), WindowState::new(None, dpi_factor as f64));
+    }
+
+    /// Returns the entities and descriptions of the [`Window`] views which the backend has not
+    /// created a window for yet.
+    pub fn pending_windows(&mut self) -> Vec<(Entity, WindowDescription)> {
+        self.0
+            .windows
+            .iter_mut()
+            .filter_map(|(entity, state)| {
+                state.description.take().map(|description| (*entity, description))
+            })
+            .collect()
+    }
+
+    /// Adds the canvas of a window which was created for a [`Window`] view.
+    ///
+    /// The size is the inner size of the window in physical pixels.
+    pub fn add_window(
+        &mut self,
+        window: Entity,
+        canvas: Canvas<OpenGl>,
+        size: (u32, u32),
+        scale_factor: f64,
+    ) {
+        self.0.canvases.insert(window, canvas);
+        self.set_window_scale_factor(window, scale_factor);
+        self.set_window_size(window, size);
+    }
+
+    /// Returns true if the entity is the content of a window which has not been closed.
+    pub fn is_window(&self, window: Entity) -> bool {
+        self.0.windows.contains_key(&window)
+    }
+
+    /// Drops the canvas of a window which has been closed. The graphics context of the window must
+    /// be current.
+    pub fn remove_canvas(&mut self, window: Entity) {
+        self.0.canvases.remove(&window);
+        self.0.text_context.remove_glyph_cache(window);
+    }
+
+    /// Sets the inner size of a window, in physical pixels, which was created for a [`Window`]
+    /// view.
+    pub fn set_window_size(&mut self, window: Entity, size: (u32, u32)) {
+        let scale_factor = match self.0.windows.get(&window) {
+            Some(state) => state.scale_factor,
+            None => return,
+        };
+
+        let width = size.0 as f64 / scale_factor;
+        let height = size.1 as f64 / scale_factor;
+        self.0.style.width.insert(window, Units::Pixels(width as f32));
+        self.0.style.height.insert(window, Units::Pixels(height as f32));
+        self.needs_refresh();
+    }
+
+    /// Sets the scale factor of a window which was created for a [`Window`] view.
+    pub fn set_window_scale_factor(&mut self, window: Entity, scale_factor: f64) {
+        if let Some(state) = self.0.windows.get_mut(&window) {
+            state.scale_factor = scale_factor;
+        }
+
+        self.needs_refresh();
+    }
+
+    /// Returns the requests to change a window, such as [`WindowEvent::SetTitle`], which have been
+    /// emitted by the content of a [`Window`] view since the last call.
+    pub fn take_window_requests(&mut self, window: Entity) -> Vec<WindowEvent> {
+        self.0
+            .views
+            .get_mut(&window)
+            .and_then(|view| view.downcast_mut::<Window>())
+            .map(|view| std::mem::take(&mut view.requests))
+            .unwrap_or_default()
+    }
+
+    /// Removes the content of a window which has been closed. The backend should drop the canvas
+    /// of the window with [`remove_canvas`](Self::remove_canvas) afterwards.
+    pub fn close_window(&mut self, window: Entity) {
+        if window != Entity::root() && self.0.windows.contains_key(&window) {
+            self.0.remove(window);
+        }
+    }
+
+    /// Moves keyboard focus when a window gains or loses focus, so that each window remembers
+    /// which of its views was focused.
+    pub fn set_window_focused(&mut self, window: Entity, focused: bool) {
+        if focused {
+            let restore = match self.0.windows.get(&window) {
+                Some(state) => state.focused,
+                None => return,
+            };
+
+            let restore =
+                if self.0.entity_manager.is_alive(restore) && self.0.window_of(restore) == window {
+                    restore
+                } else {
+                    window
+                };
+
+            if self.0.window_of(self.0.focused) != window {
+                self.0.with_current(restore, |cx| cx.focus());
+            }
+        } else if self.0.window_of(self.0.focused) == window {
+            let current = self.0.focused;
+            if let Some(state) = self.0.windows.get_mut(&window) {
+                state.focused = current;
+            }
+        }
+    }
+
+    /// Sets the window which mouse and touch input is received from.
+    pub fn set_cursor_window(&mut self, window: Entity) {
+        if self.0.windows.contains_key(&window) {
+            self.0.cursor_window = window;
+        }
+    }
+
+    /// Converts a position in physical pixels within a window to the coordinates used for layout,
+    /// in which the content of each window is positioned relative to its parent.
+    pub fn window_to_layout(&self, window: Entity, x: f32, y: f32) -> (f32, f32) {
+        let bounds = self.0.cache.get_bounds(window);
+        let scale = self.0.window_scale(window);
+        (bounds.x + x / scale, bounds.y + y / scale)
+    }
+
+    /// Converts a position in layout coordinates to physical pixels within a window.
+    pub fn layout_to_window(&self, window: Entity, x: f32, y: f32) -> (f32, f32) {
+        let bounds = self.0.cache.get_bounds(window);
+        let scale = self.0.window_scale(window);
+        ((x - bounds.x) * scale, (y - bounds.y) * scale)
     }
 
     pub fn environment(&self) -> &Environment {
@@ -124,7 +258,12 @@ impl<'a> BackendContext<'a> {
     }
 
     pub fn draw(&mut self) {
-        draw_system(self.0);
+        draw_system(self.0, Entity::root());
+    }
+
+    /// Draws the content of a window which was created for a [`Window`] view.
+    pub fn draw_window(&mut self, window: Entity) {
+        draw_system(self.0, window);
     }
 
     pub fn load_images(&mut self) {
@@ -178,6 +317,18 @@ impl<'a> BackendContext<'a> {
         !self.0.event_queue.is_empty()
     }
 
+    /// Queues the events of any timers and delayed events which are due.
+    pub fn process_timers(&mut self) {
+        self.0.timers.process(Instant::now(), &mut self.0.event_queue);
+    }
+
+    /// Returns the time at which the next timer or delayed event is due, if any.
+    ///
+    /// Backends which wait for window events should wake up at this time.
+    pub fn next_timer_deadline(&self) -> Option<Instant> {
+        self.0.timers.next_deadline()
+    }
+
     pub fn accesskit_node_classes(&mut self) -> &mut accesskit::NodeClassSet {
         &mut self.style().accesskit_node_classes
     }
@@ -287,7 +438,7 @@ impl<'a> BackendContext<'a> {
             Event::new(message)
                 .target(self.0.current)
                 .origin(Entity::root())
-                .propagate(Propagation::Up),
+                .propagate(Propagation::DownUp),
         );
     }
 
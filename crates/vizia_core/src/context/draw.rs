@@ -36,6 +36,8 @@ impl DrawCache {
 /// A restricted context used when drawing.
 pub struct DrawContext<'a> {
     pub(crate) current: Entity,
+    /// The window which is being drawn.
+    pub(crate) window: Entity,
     pub captured: &'a Entity,
     pub focused: &'a Entity,
     pub hovered: &'a Entity,
@@ -76,8 +78,10 @@ macro_rules! style_getter_untranslated {
 impl<'a> DrawContext<'a> {
     /// Creates a new `DrawContext` from the given `Context`.
     pub fn new(cx: &'a mut Context) -> Self {
+        let window = cx.window_of(cx.current);
         Self {
             current: cx.current,
+            window,
             captured: &cx.captured,
             focused: &cx.focused,
             hovered: &cx.hovered,
@@ -108,6 +112,11 @@ impl<'a> DrawContext<'a> {
         &self.style.default_font
     }
 
+    /// Returns the layout direction of the current entity.
+    pub fn layout_direction(&self) -> LayoutDirection {
+        self.style.layout_direction(self.current)
+    }
+
     /// Returns the font-size of the current entity in physical coordinates.
     pub fn font_size(&self, entity: Entity) -> f32 {
         self.logical_to_physical(self.style.font_size.get(entity).copied().unwrap_or(16.0))
@@ -169,9 +178,14 @@ impl<'a> DrawContext<'a> {
     }
 
     pub fn draw_text(&mut self, canvas: &mut Canvas, origin: (f32, f32), justify: (f32, f32)) {
-        if let Ok(draw_commands) =
-            self.text_context.fill_to_cmds(canvas, self.current, origin, justify, *self.text_config)
-        {
+        if let Ok(draw_commands) = self.text_context.fill_to_cmds(
+            canvas,
+            self.window,
+            self.current,
+            origin,
+            justify,
+            *self.text_config,
+        ) {
             for (color, cmds) in draw_commands.into_iter() {
                 let temp_paint =
                     Paint::color(femtovg::Color::rgba(color.r(), color.g(), color.b(), color.a()));
@@ -195,6 +209,31 @@ impl<'a> DrawContext<'a> {
         }
     }
 
+    /// Underlines the uncommitted IME preedit text of the current view, if any.
+    pub fn draw_preedit(
+        &mut self,
+        canvas: &mut Canvas,
+        origin: (f32, f32),
+        justify: (f32, f32),
+        width: f32,
+    ) {
+        let rects = self.text_context.layout_preedit(
+            self.current,
+            origin,
+            justify,
+            self.logical_to_physical(width),
+        );
+
+        if !rects.is_empty() {
+            let color = self.font_color().copied().unwrap_or(Color::black());
+            let mut path = Path::new();
+            for (x, y, w, h) in rects {
+                path.rect(x, y, w, h);
+            }
+            canvas.fill_path(&mut path, &Paint::color(color.into()));
+        }
+    }
+
     pub fn draw_caret(
         &mut self,
         canvas: &mut Canvas,
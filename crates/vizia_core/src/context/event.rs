@@ -4,19 +4,22 @@ use std::collections::{HashMap, HashSet, VecDeque};
 use std::error::Error;
 
 use fnv::FnvHashMap;
+use instant::{Duration, Instant};
 
 use crate::cache::CachedData;
-use crate::events::ViewHandler;
+use crate::events::{Gestures, Timers, ViewHandler};
+use crate::layout::debug::layout_dump;
 use crate::prelude::*;
 use crate::resource::ResourceManager;
 use crate::state::ModelDataStore;
 use crate::style::{Style, SystemFlags};
 use vizia_id::GenerationalId;
-use vizia_input::{Modifiers, MouseState};
+use vizia_input::{Modifiers, MouseState, TouchState};
 use vizia_storage::SparseSet;
 
 use crate::context::EmitContext;
 use crate::text::TextContext;
+use crate::window::WindowState;
 #[cfg(feature = "clipboard")]
 use copypasta::ClipboardProvider;
 
@@ -34,14 +37,20 @@ pub struct EventContext<'a> {
     pub tree: &'a Tree<Entity>,
     pub(crate) data: &'a mut SparseSet<ModelDataStore>,
     pub(crate) views: &'a mut FnvHashMap<Entity, Box<dyn ViewHandler>>,
+    windows: &'a FnvHashMap<Entity, WindowState>,
     listeners:
         &'a mut HashMap<Entity, Box<dyn Fn(&mut dyn ViewHandler, &mut EventContext, &mut Event)>>,
     pub resource_manager: &'a ResourceManager,
     pub text_context: &'a mut TextContext,
     pub modifiers: &'a Modifiers,
     pub mouse: &'a MouseState<Entity>,
+    touches: &'a mut TouchState<Entity>,
     pub(crate) event_queue: &'a mut VecDeque<Event>,
+    timers: &'a mut Timers,
+    gestures: &'a mut Gestures,
+    dialogs: &'a mut Vec<Box<dyn FnOnce(&mut Context)>>,
     cursor_icon_locked: &'a mut bool,
+    layout_debug: &'a mut bool,
     window_size: &'a mut WindowSize,
     user_scale_factor: &'a mut f64,
     #[cfg(feature = "clipboard")]
@@ -63,13 +72,19 @@ impl<'a> EventContext<'a> {
             tree: &cx.tree,
             data: &mut cx.data,
             views: &mut cx.views,
+            windows: &cx.windows,
             listeners: &mut cx.listeners,
             resource_manager: &cx.resource_manager,
             text_context: &mut cx.text_context,
             modifiers: &cx.modifiers,
             mouse: &cx.mouse,
+            touches: &mut cx.touches,
             event_queue: &mut cx.event_queue,
+            timers: &mut cx.timers,
+            gestures: &mut cx.gestures,
+            dialogs: &mut cx.dialogs,
             cursor_icon_locked: &mut cx.cursor_icon_locked,
+            layout_debug: &mut cx.layout_debug,
             window_size: &mut cx.window_size,
             user_scale_factor: &mut cx.user_scale_factor,
             #[cfg(feature = "clipboard")]
@@ -87,6 +102,19 @@ impl<'a> EventContext<'a> {
         self.current
     }
 
+    /// Returns the window which displays an entity, which is the main window at the root unless
+    /// the entity is the content of a [`Window`] view.
+    pub(crate) fn window_of(&self, entity: Entity) -> Entity {
+        if self.windows.len() < 2 {
+            return Entity::root();
+        }
+
+        entity
+            .parent_iter(self.tree)
+            .find(|ancestor| self.windows.contains_key(ancestor))
+            .unwrap_or(Entity::root())
+    }
+
     /// Add a listener to an entity.
     ///
     /// A listener can be used to handle events which would not normally propagate to the entity.
@@ -128,6 +156,33 @@ impl<'a> EventContext<'a> {
         }
     }
 
+    /// Handles the long press which was delivered to the current entity, so that releasing it does
+    /// not also press the view. Returns false if another view has captured the mouse since the
+    /// press began, in which case the long press should be ignored.
+    pub(crate) fn handle_long_press(&mut self) -> bool {
+        if *self.captured != Entity::null() && *self.captured != self.current {
+            return false;
+        }
+
+        self.gestures.set_long_pressed();
+        true
+    }
+
+    /// Returns the touches which are in contact with the window.
+    pub fn touches(&self) -> &TouchState<Entity> {
+        self.touches
+    }
+
+    /// Capture the events of a touch for the current entity until the touch ends.
+    pub fn capture_touch(&mut self, id: u64) {
+        self.touches.capture(id, self.current);
+    }
+
+    /// Release the capture of a touch if it was captured by the current entity.
+    pub fn release_touch(&mut self, id: u64) {
+        self.touches.release(id, self.current);
+    }
+
     /// Enables or disables pseudoclasses for the focus of an entity
     fn set_focus_pseudo_classes(&mut self, focused: Entity, enabled: bool, focus_visible: bool) {
         #[cfg(debug_assertions)]
@@ -311,6 +366,58 @@ impl<'a> EventContext<'a> {
         self.style.needs_redraw();
     }
 
+    /// Adds a timer which emits a [`TimerEvent::Tick`] directly to the target entity once the
+    /// interval has elapsed, and again after every interval if `repeat` is true.
+    pub fn add_timer(&mut self, interval: Duration, repeat: bool, target: Entity) -> Timer {
+        self.timers.add(Instant::now(), interval, repeat, target)
+    }
+
+    /// Sends an event containing a message up the tree from the current entity after a delay.
+    pub fn emit_after<M: Any + Send>(&mut self, delay: Duration, message: M) -> Timer {
+        let event = Event::new(message)
+            .target(self.current)
+            .origin(self.current)
+            .propagate(Propagation::Up);
+        self.timers.schedule(Instant::now(), delay, event)
+    }
+
+    /// Cancels a timer or a delayed event. Does nothing if it has already finished.
+    pub fn cancel_timer(&mut self, timer: Timer) {
+        self.timers.cancel(timer);
+    }
+
+    /// Opens a modal [`Dialog`] with the content built by `content`. The dialog is built once the
+    /// current events have been dispatched.
+    ///
+    /// The result callback is called with the result of the [`DialogEvent`] which closes the
+    /// dialog, or with `None` if the dialog is cancelled.
+    pub fn open_dialog<R, F, C>(&mut self, content: F, on_result: C)
+    where
+        R: 'static + Send,
+        F: 'static + FnOnce(&mut Context),
+        C: 'static + FnOnce(&mut EventContext, Option<R>),
+    {
+        let opener = self.current;
+        self.dialogs.push(Box::new(move |cx| Dialog::open(cx, opener, content, on_result)));
+    }
+
+    /// Sets whether the layout debugging overlay is drawn on top of the window.
+    pub fn set_layout_debug(&mut self, enabled: bool) {
+        *self.layout_debug = enabled;
+        self.needs_redraw();
+    }
+
+    /// Returns whether the layout debugging overlay is enabled.
+    pub fn layout_debug(&self) -> bool {
+        *self.layout_debug
+    }
+
+    /// Prints the tree with the computed position and size of each view and the layout
+    /// properties which produced them.
+    pub fn dump_layout(&self) {
+        print!("{}", layout_dump(self.tree, self.views, self.cache, self.style));
+    }
+
     pub fn reload_styles(&mut self) -> Result<(), std::io::Error> {
         if self.resource_manager.themes.is_empty() && self.resource_manager.stylesheets.is_empty() {
             return Ok(());
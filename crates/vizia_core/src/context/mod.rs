@@ -5,7 +5,7 @@ mod event;
 mod proxy;
 mod resource;
 
-use instant::Instant;
+use instant::{Duration, Instant};
 use std::any::{Any, TypeId};
 use std::collections::{HashMap, VecDeque};
 use std::iter::once;
@@ -29,16 +29,18 @@ pub use resource::*;
 
 use crate::cache::CachedData;
 use crate::environment::Environment;
-use crate::events::ViewHandler;
+use crate::events::{DragDrop, Gestures, Timers, ViewHandler};
 #[cfg(feature = "embedded_fonts")]
 use crate::fonts;
+use crate::layout::debug::layout_dump;
 use crate::prelude::*;
 use crate::resource::{ImageRetentionPolicy, ResourceManager};
 use crate::state::{BindingHandler, ModelDataStore};
 use crate::style::Style;
 use crate::text::{TextConfig, TextContext};
+use crate::window::WindowState;
 use vizia_id::{GenerationalId, IdManager};
-use vizia_input::{Modifiers, MouseState};
+use vizia_input::{Modifiers, MouseState, TouchState};
 use vizia_storage::TreeExt;
 use vizia_storage::{ChildIterator, SparseSet};
 
@@ -61,6 +63,9 @@ pub struct Context {
     pub(crate) data: DataStore,
     pub(crate) bindings: Views,
     pub(crate) event_queue: VecDeque<Event>,
+    pub(crate) timers: Timers,
+    pub(crate) drag_drop: DragDrop,
+    pub(crate) gestures: Gestures,
     pub(crate) tree_updates: Vec<accesskit::TreeUpdate>,
     pub(crate) listeners:
         HashMap<Entity, Box<dyn Fn(&mut dyn ViewHandler, &mut EventContext, &mut Event)>>,
@@ -70,8 +75,13 @@ pub struct Context {
     pub(crate) draw_cache: DrawCache,
 
     pub(crate) canvases: HashMap<Entity, crate::prelude::Canvas>,
+    /// The windows which display the tree, keyed by the entity at the root of their content.
+    pub(crate) windows: FnvHashMap<Entity, WindowState>,
+    /// The window which the mouse cursor was last moved over.
+    pub(crate) cursor_window: Entity,
     //environment: Environment,
     pub(crate) mouse: MouseState<Entity>,
+    pub(crate) touches: TouchState<Entity>,
     pub(crate) modifiers: Modifiers,
 
     pub(crate) captured: Entity,
@@ -79,7 +89,13 @@ pub struct Context {
     pub(crate) hovered: Entity,
     pub(crate) focused: Entity,
     pub(crate) focus_stack: Vec<Entity>,
+    // Dialogs opened while handling events, which are built once the events have been dispatched.
+    pub(crate) dialogs: Vec<Box<dyn FnOnce(&mut Context)>>,
+    // Views which are built outside of the entity they belong to, such as tooltips and context
+    // menus, keyed by that entity so that they are removed with it.
+    pub(crate) dependents: FnvHashMap<Entity, Vec<Entity>>,
     pub(crate) cursor_icon_locked: bool,
+    pub(crate) layout_debug: bool,
 
     pub(crate) resource_manager: ResourceManager,
 
@@ -148,19 +164,28 @@ impl Context {
             cache,
             draw_cache: DrawCache::new(),
             canvases: HashMap::new(),
+            windows: FnvHashMap::default(),
+            cursor_window: Entity::root(),
             // environment: Environment::new(),
             event_queue: VecDeque::new(),
+            timers: Timers::default(),
+            drag_drop: DragDrop::default(),
+            gestures: Gestures::default(),
             tree_updates: Vec::new(),
             listeners: HashMap::default(),
             global_listeners: vec![],
             mouse: MouseState::default(),
+            touches: TouchState::default(),
             modifiers: Modifiers::empty(),
             captured: Entity::null(),
             triggered: Entity::null(),
             hovered: Entity::root(),
             focused: Entity::root(),
             focus_stack: Vec::new(),
+            dialogs: Vec::new(),
+            dependents: FnvHashMap::default(),
             cursor_icon_locked: false,
+            layout_debug: false,
             resource_manager: ResourceManager::new(),
             text_context: TextContext::new_from_locale_and_db(
                 sys_locale::get_locale().unwrap_or_else(|| "en-US".to_owned()),
@@ -197,7 +222,9 @@ impl Context {
         result.style.needs_relayout();
         result.style.needs_redraw();
 
-        Environment::new().build(&mut result);
+        let environment = Environment::new();
+        result.style.default_direction = LayoutDirection::from_locale(&environment.locale);
+        environment.build(&mut result);
 
         result.entity_manager.create();
         result.set_default_font(&["Roboto"]);
@@ -232,6 +259,37 @@ impl Context {
         self.data::<Environment>().unwrap()
     }
 
+    /// Returns the window which displays an entity, which is the main window at the root unless
+    /// the entity is the content of a [`Window`] view.
+    pub(crate) fn window_of(&self, entity: Entity) -> Entity {
+        // The main window is the only window in most applications.
+        if self.windows.len() < 2 {
+            return Entity::root();
+        }
+
+        entity
+            .parent_iter(&self.tree)
+            .find(|ancestor| self.windows.contains_key(ancestor))
+            .unwrap_or(Entity::root())
+    }
+
+    /// Marks a view which is built outside of an entity, such as in the window of the entity, to
+    /// be removed with the entity.
+    pub(crate) fn add_dependent(&mut self, entity: Entity, dependent: Entity) {
+        self.dependents.entry(entity).or_default().push(dependent);
+    }
+
+    /// Returns the factor by which the content of a window is scaled when it is drawn, which is
+    /// the ratio between the scale factors of the window and the main window.
+    pub(crate) fn window_scale(&self, window: Entity) -> f32 {
+        match self.windows.get(&window) {
+            Some(state) if window != Entity::root() => {
+                (state.scale_factor / self.style.dpi_factor) as f32
+            }
+            _ => 1.0,
+        }
+    }
+
     /// The window's size in logical pixels, before
     /// [`user_scale_factor()`][Self::user_scale_factor()] gets applied to it. If this value changed
     /// during a frame then the window will be resized and a [`WindowEvent::GeometryChanged`] will be
@@ -347,8 +405,39 @@ impl Context {
         }
     }
 
+    // Stops the bindings of an entity and its descendants from rebuilding when their data changes,
+    // so that a view which no longer has any data can be kept until it is removed.
+    pub(crate) fn unbind(&mut self, entity: Entity) {
+        let branch = entity.branch_iter(&self.tree).collect::<Vec<_>>();
+        for entity in branch {
+            if let Some(binding) = self.bindings.remove(&entity) {
+                binding.remove(self);
+            }
+        }
+    }
+
     pub fn remove(&mut self, entity: Entity) {
-        let delete_list = entity.branch_iter(&self.tree).collect::<Vec<_>>();
+        // The entity may have been removed already, in which case its slot may have been reused.
+        if !self.entity_manager.is_alive(entity) {
+            return;
+        }
+
+        let mut delete_list = entity.branch_iter(&self.tree).collect::<Vec<_>>();
+
+        // Dependents are appended after the branch which owns them, so that they are removed first.
+        let mut index = 0;
+        while index < delete_list.len() {
+            if let Some(dependents) = self.dependents.remove(&delete_list[index]) {
+                for dependent in dependents {
+                    if self.entity_manager.is_alive(dependent) && !delete_list.contains(&dependent)
+                    {
+                        delete_list.extend(dependent.branch_iter(&self.tree));
+                    }
+                }
+            }
+
+            index += 1;
+        }
 
         if !delete_list.is_empty() {
             self.style.needs_restyle();
@@ -388,6 +477,8 @@ impl Context {
                 self.captured = Entity::null();
             }
 
+            self.touches.release_all(*entity);
+
             self.tree.remove(*entity).expect("");
             self.cache.remove(*entity);
             self.draw_cache.remove(*entity);
@@ -396,6 +487,15 @@ impl Context {
             self.views.remove(entity);
             self.entity_manager.destroy(*entity);
             self.text_context.clear_buffer(*entity);
+            self.timers.remove_entity(*entity);
+            self.drag_drop.remove_entity(*entity);
+            self.gestures.remove_entity(*entity);
+            // The canvas of a window is dropped by the backend, which owns its graphics context.
+            self.windows.remove(entity);
+
+            if self.cursor_window == *entity {
+                self.cursor_window = Entity::root();
+            }
         }
     }
 
@@ -531,10 +631,83 @@ impl Context {
     pub fn resolve_entity_identifier(&self, identity: &str) -> Option<Entity> {
         self.entity_identifiers.get(identity).cloned()
     }
+
+    /// Adds a timer which emits a [`TimerEvent::Tick`] directly to the target entity once the
+    /// interval has elapsed, and again after every interval if `repeat` is true.
+    ///
+    /// The returned handle can be used to cancel the timer with [`cancel_timer`](Context::cancel_timer).
+    /// Timers are removed when their target entity is removed.
+    pub fn add_timer(&mut self, interval: Duration, repeat: bool, target: Entity) -> Timer {
+        self.timers.add(Instant::now(), interval, repeat, target)
+    }
+
+    /// Opens a modal [`Dialog`] with the content built by `content`.
+    ///
+    /// The result callback is called with the result of the [`DialogEvent`] which closes the
+    /// dialog, or with `None` if the dialog is cancelled.
+    pub fn open_dialog<R, F, C>(&mut self, content: F, on_result: C)
+    where
+        R: 'static + Send,
+        F: FnOnce(&mut Context),
+        C: 'static + FnOnce(&mut EventContext, Option<R>),
+    {
+        let opener = self.current;
+        Dialog::open(self, opener, content, on_result);
+    }
+
+    /// Sends an event containing a message up the tree from the current entity after a delay.
+    ///
+    /// The returned handle can be used to cancel the event before it is sent with
+    /// [`cancel_timer`](Context::cancel_timer).
+    pub fn emit_after<M: Any + Send>(&mut self, delay: Duration, message: M) -> Timer {
+        let event = Event::new(message)
+            .target(self.current)
+            .origin(self.current)
+            .propagate(Propagation::Up);
+        self.timers.schedule(Instant::now(), delay, event)
+    }
+
+    /// Cancels a timer or a delayed event. Does nothing if it has already finished.
+    pub fn cancel_timer(&mut self, timer: Timer) {
+        self.timers.cancel(timer);
+    }
+
+    // Removes an entity after a delay, e.g. once an exit animation has finished playing.
+    pub(crate) fn remove_after(&mut self, entity: Entity, delay: Duration) {
+        let event = Event::new(InternalEvent::Remove(entity))
+            .target(entity)
+            .origin(entity)
+            .propagate(Propagation::Direct);
+        self.timers.schedule(Instant::now(), delay, event);
+    }
+
+    /// Sets whether the layout debugging overlay is drawn on top of the window.
+    ///
+    /// The overlay outlines the bounds of every view and shows its space, child space, clip
+    /// region and entity id. In debug builds it can also be toggled with Ctrl+Shift+Alt+L.
+    pub fn set_layout_debug(&mut self, enabled: bool) {
+        self.layout_debug = enabled;
+        self.needs_redraw();
+    }
+
+    /// Returns whether the layout debugging overlay is enabled.
+    pub fn layout_debug(&self) -> bool {
+        self.layout_debug
+    }
+
+    /// Prints the tree with the computed position and size of each view and the layout
+    /// properties which produced them.
+    pub fn dump_layout(&self) {
+        print!("{}", layout_dump(&self.tree, &self.views, &self.cache, &self.style));
+    }
 }
 
 pub(crate) enum InternalEvent {
     Redraw,
+    // Removes an entity from the tree if it has not already been removed.
+    Remove(Entity),
+    // Moves the keyboard focus to the next navigatable view, or the previous one if true.
+    MoveFocus(bool),
     LoadImage {
         path: String,
         image: Mutex<Option<image::DynamicImage>>,
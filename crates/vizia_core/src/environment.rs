@@ -1,4 +1,5 @@
 use crate::prelude::Wrapper;
+use crate::style::LayoutDirection;
 use unic_langid::LanguageIdentifier;
 use vizia_derive::Lens;
 
@@ -29,15 +30,24 @@ pub enum EnvironmentEvent {
 }
 
 impl Model for Environment {
-    fn event(&mut self, _: &mut EventContext, event: &mut Event) {
-        event.map(|event, _| match event {
-            EnvironmentEvent::SetLocale(locale) => {
-                self.locale = locale.clone();
+    fn event(&mut self, cx: &mut EventContext, event: &mut Event) {
+        event.map(|event, _| {
+            match event {
+                EnvironmentEvent::SetLocale(locale) => {
+                    self.locale = locale.clone();
+                }
+
+                EnvironmentEvent::UseSystemLocale => {
+                    self.locale =
+                        sys_locale::get_locale().map(|l| l.parse().unwrap()).unwrap_or_default();
+                }
             }
 
-            EnvironmentEvent::UseSystemLocale => {
-                self.locale =
-                    sys_locale::get_locale().map(|l| l.parse().unwrap()).unwrap_or_default();
+            // Views which don't specify a direction follow the direction of the locale.
+            let direction = LayoutDirection::from_locale(&self.locale);
+            if cx.style.default_direction != direction {
+                cx.style.default_direction = direction;
+                cx.needs_relayout();
             }
         });
     }
@@ -0,0 +1,288 @@
+use std::any::Any;
+
+use fnv::FnvHashMap;
+
+use crate::prelude::*;
+
+/// The default distance in logical pixels which the cursor must move with the left mouse button
+/// pressed before a view made draggable with the `draggable` modifier starts a drag.
+pub const DEFAULT_DRAG_THRESHOLD: f32 = 4.0;
+
+/// Events emitted while a view made draggable with the `draggable` modifier is dragged.
+///
+/// Each event is sent directly to the view it concerns, so a view does not receive the drag
+/// events of its descendants.
+///
+/// This type is part of the prelude.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DragEvent {
+    /// Emitted to the source of a drag once the cursor moves beyond its drag threshold.
+    DragStart,
+    /// Emitted to a drop target when the cursor moves over it with a payload it accepts.
+    DragEnter,
+    /// Emitted to a drop target when the cursor moves away from it, or the drag is cancelled
+    /// while over it.
+    DragLeave,
+    /// Emitted to a drop target after its `on_drop` callback has received the payload.
+    Drop,
+    /// Emitted to the source of a drag when it ends, with whether the payload was dropped on a
+    /// drop target.
+    DragEnd(bool),
+}
+
+// A view which contains the content of a drag preview and follows the cursor.
+struct DragPreview;
+
+impl View for DragPreview {
+    fn element(&self) -> Option<&'static str> {
+        Some("drag-preview")
+    }
+}
+
+pub(crate) struct DragSource {
+    pub payload: Box<dyn Fn() -> Box<dyn Any + Send>>,
+    pub preview: Option<Box<dyn Fn(&mut Context)>>,
+    pub threshold: f32,
+}
+
+pub(crate) struct DropTarget {
+    pub accepts: Box<dyn Fn(&dyn Any) -> bool>,
+    pub on_drop: Box<dyn Fn(&mut EventContext, Box<dyn Any + Send>)>,
+}
+
+struct DragState {
+    source: Entity,
+    // The cursor position when the mouse button was pressed, in physical pixels.
+    start: (f32, f32),
+    // The cursor position relative to the source when the mouse button was pressed.
+    offset: (f32, f32),
+    // The payload of the drag, which is only created once the drag has started.
+    payload: Option<Box<dyn Any + Send>>,
+    preview: Entity,
+    target: Entity,
+}
+
+/// The drag sources and drop targets of a context, and the drag which is in progress.
+#[derive(Default)]
+pub(crate) struct DragDrop {
+    pub sources: FnvHashMap<Entity, DragSource>,
+    pub targets: FnvHashMap<Entity, DropTarget>,
+    state: Option<DragState>,
+}
+
+impl DragDrop {
+    /// Removes an entity as a drag source and drop target.
+    pub fn remove_entity(&mut self, entity: Entity) {
+        self.sources.remove(&entity);
+        self.targets.remove(&entity);
+
+        if let Some(state) = &mut self.state {
+            if state.source == entity {
+                state.source = Entity::null();
+            }
+
+            if state.target == entity {
+                state.target = Entity::null();
+            }
+        }
+    }
+
+    /// Returns `true` if a drag source has been pressed, whether or not the drag has started.
+    pub fn is_pending(&self) -> bool {
+        self.state.is_some()
+    }
+}
+
+fn send(cx: &mut Context, target: Entity, message: DragEvent) {
+    if target != Entity::null() {
+        cx.event_queue.push_back(
+            Event::new(message)
+                .target(target)
+                .origin(Entity::root())
+                .propagate(Propagation::Direct),
+        );
+    }
+}
+
+fn set_drag_over(cx: &mut Context, entity: Entity, flag: bool) {
+    if let Some(pseudo_classes) = cx.style.pseudo_classes.get_mut(entity) {
+        pseudo_classes.set(PseudoClass::DRAG_OVER, flag);
+    }
+
+    cx.needs_restyle();
+}
+
+/// Prepares a drag if the left mouse button was pressed on a drag source or one of its
+/// descendants. The drag starts once the cursor moves beyond the threshold of the source.
+pub(crate) fn drag_mouse_down(cx: &mut Context) {
+    let source =
+        cx.hovered.parent_iter(&cx.tree).find(|entity| cx.drag_drop.sources.contains_key(entity));
+
+    if let Some(source) = source {
+        let start = (cx.mouse.cursorx, cx.mouse.cursory);
+        let bounds = cx.cache.get_bounds(source);
+
+        cx.drag_drop.state = Some(DragState {
+            source,
+            start,
+            offset: (start.0 - bounds.x, start.1 - bounds.y),
+            payload: None,
+            preview: Entity::null(),
+            target: Entity::null(),
+        });
+    }
+}
+
+/// Starts a drag once the cursor has moved far enough, then moves the drag preview and updates
+/// the drop target under the cursor.
+pub(crate) fn drag_mouse_move(cx: &mut Context) {
+    let (source_entity, start, started) = match &cx.drag_drop.state {
+        Some(state) => (state.source, state.start, state.payload.is_some()),
+        None => return,
+    };
+
+    let (cursorx, cursory) = (cx.mouse.cursorx, cx.mouse.cursory);
+
+    if !started {
+        let source = match cx.drag_drop.sources.remove(&source_entity) {
+            Some(source) => source,
+            None => {
+                cx.drag_drop.state = None;
+                return;
+            }
+        };
+
+        let (dx, dy) = (cursorx - start.0, cursory - start.1);
+        if (dx * dx + dy * dy).sqrt() < cx.style.logical_to_physical(source.threshold) {
+            cx.drag_drop.sources.insert(source_entity, source);
+            return;
+        }
+
+        let payload = (source.payload)();
+
+        let mut preview = Entity::null();
+        if let Some(content) = &source.preview {
+            cx.with_current(Entity::root(), |cx| {
+                preview = DragPreview
+                    .build(cx, |cx| (content)(cx))
+                    .position_type(PositionType::SelfDirected)
+                    .size(Auto)
+                    .hoverable(false)
+                    .z_order(i32::MAX)
+                    .entity;
+            });
+        }
+
+        cx.drag_drop.sources.insert(source_entity, source);
+
+        if let Some(state) = &mut cx.drag_drop.state {
+            state.payload = Some(payload);
+            state.preview = preview;
+        }
+
+        send(cx, source_entity, DragEvent::DragStart);
+    }
+
+    let state = match &cx.drag_drop.state {
+        Some(state) => state,
+        None => return,
+    };
+
+    if state.preview != Entity::null() {
+        let left = cx.style.physical_to_logical(cursorx - state.offset.0);
+        let top = cx.style.physical_to_logical(cursory - state.offset.1);
+        cx.style.left.insert(state.preview, Pixels(left));
+        cx.style.top.insert(state.preview, Pixels(top));
+        cx.style.needs_relayout();
+    }
+
+    // The nearest drop target at or above the hovered entity which accepts the payload.
+    let target = state.payload.as_ref().and_then(|payload| {
+        cx.hovered.parent_iter(&cx.tree).find(|entity| {
+            cx.drag_drop
+                .targets
+                .get(entity)
+                .map_or(false, |drop_target| (drop_target.accepts)(&**payload))
+        })
+    });
+    let target = target.unwrap_or(Entity::null());
+
+    let previous = state.target;
+    if target != previous {
+        if let Some(state) = &mut cx.drag_drop.state {
+            state.target = target;
+        }
+
+        if previous != Entity::null() {
+            set_drag_over(cx, previous, false);
+            send(cx, previous, DragEvent::DragLeave);
+        }
+
+        if target != Entity::null() {
+            set_drag_over(cx, target, true);
+            send(cx, target, DragEvent::DragEnter);
+        }
+    }
+}
+
+/// Ends the drag, if any, dropping the payload on the current drop target. Returns true if a
+/// drag had started, in which case the mouse release should not also press the source.
+pub(crate) fn drag_mouse_up(cx: &mut Context) -> bool {
+    let state = match cx.drag_drop.state.take() {
+        Some(state) => state,
+        None => return false,
+    };
+
+    let payload = match state.payload {
+        Some(payload) => payload,
+        None => return false,
+    };
+
+    let dropped = state.target != Entity::null();
+    if dropped {
+        set_drag_over(cx, state.target, false);
+
+        if let Some(drop_target) = cx.drag_drop.targets.remove(&state.target) {
+            cx.with_current(state.target, |cx| {
+                (drop_target.on_drop)(&mut EventContext::new(cx), payload);
+            });
+
+            cx.drag_drop.targets.insert(state.target, drop_target);
+        }
+
+        send(cx, state.target, DragEvent::Drop);
+    }
+
+    end_drag(cx, state.source, state.preview, dropped);
+
+    true
+}
+
+/// Cancels the drag, if any, without dropping the payload. Returns true if a drag had started.
+pub(crate) fn cancel_drag(cx: &mut Context) -> bool {
+    let state = match cx.drag_drop.state.take() {
+        Some(state) => state,
+        None => return false,
+    };
+
+    if state.payload.is_none() {
+        return false;
+    }
+
+    if state.target != Entity::null() {
+        set_drag_over(cx, state.target, false);
+        send(cx, state.target, DragEvent::DragLeave);
+    }
+
+    end_drag(cx, state.source, state.preview, false);
+
+    true
+}
+
+fn end_drag(cx: &mut Context, source: Entity, preview: Entity, dropped: bool) {
+    if preview != Entity::null() {
+        cx.remove(preview);
+    }
+
+    send(cx, source, DragEvent::DragEnd(dropped));
+}
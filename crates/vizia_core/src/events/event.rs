@@ -7,18 +7,34 @@ use vizia_id::GenerationalId;
 /// This type is part of the prelude.
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub enum Propagation {
-    // /// Events propagate down the tree to the target entity, e.g. from grand-parent to parent to child (target)
-    // Down,
+    /// Events propagate down the tree to the target entity, e.g. from grand-parent to parent to child (target).
+    /// Ancestors receive the event in their capture handlers before it reaches the target.
+    Down,
     /// Events propagate up the tree from the target entity from ancestor to ancestor, e.g. from child (target) to parent to grand-parent etc...
     Up,
-    // /// Events propagate down the tree to the target entity and then back up to the root
-    // DownUp,
+    /// Events propagate down the tree to the target entity and then back up to the root, combining `Down` and `Up`.
+    DownUp,
     /// Events propagate starting at the target entity and visiting every entity that is a descendent of the target
     Subtree,
     /// Events propagate directly to the target entity and to no others
     Direct,
 }
 
+/// The phase of an event as it travels through the tree.
+///
+/// This type is part of the prelude.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EventPhase {
+    /// The event is travelling down the tree from the root and is received by the capture handlers
+    /// of the ancestors of the target.
+    Capture,
+    /// The event has reached the target entity. Events which propagate to a subtree are received
+    /// in this phase by every entity of the subtree.
+    Target,
+    /// The event is travelling up the tree from the target to the root.
+    Bubble,
+}
+
 /// A wrapper around a message, providing metadata on how the event travels through the tree.
 ///
 /// This type is part of the prelude.
@@ -83,6 +99,11 @@ impl Event {
         self.meta.consume();
     }
 
+    /// Consumes the event and prevents any remaining handlers of the current entity from receiving it.
+    pub fn stop_immediate(&mut self) {
+        self.meta.stop_immediate();
+    }
+
     /// Tries to downcast the event message to the specified type. If the downcast was successful,
     /// the downcasted message and the event meta data get passed into `f`.
     pub fn map<M, F>(&mut self, f: F)
@@ -126,6 +147,10 @@ pub struct EventMeta {
     pub consumable: bool,
     /// Determines whether the event should continue to be propagated
     pub(crate) consumed: bool,
+    /// Determines whether the remaining handlers of the current entity should receive the event
+    pub(crate) stopped_immediately: bool,
+    /// The current phase of the event on its propagation path
+    pub(crate) phase: EventPhase,
     /// Specifies an order index which is used to sort the event queue
     pub order: i32,
 }
@@ -139,9 +164,23 @@ impl EventMeta {
 
 impl EventMeta {
     /// Consumes the event to prevent it from continuing on its propagation path.
+    ///
+    /// The remaining models of the current entity which haven't received the event yet still
+    /// receive it. Use [`stop_immediate`](EventMeta::stop_immediate) to prevent this too.
     pub fn consume(&mut self) {
         self.consumed = true;
     }
+
+    /// Consumes the event and prevents any remaining handlers of the current entity from receiving it.
+    pub fn stop_immediate(&mut self) {
+        self.consumed = true;
+        self.stopped_immediately = true;
+    }
+
+    /// Returns the current phase of the event on its propagation path.
+    pub fn phase(&self) -> EventPhase {
+        self.phase
+    }
 }
 
 impl Default for EventMeta {
@@ -152,6 +191,8 @@ impl Default for EventMeta {
             propagation: Propagation::Up,
             consumable: true,
             consumed: false,
+            stopped_immediately: false,
+            phase: EventPhase::Target,
             order: 0,
         }
     }
@@ -9,6 +9,8 @@ pub trait ViewHandler: Any {
 
     fn event(&mut self, cx: &mut EventContext, event: &mut Event);
 
+    fn capture_event(&mut self, cx: &mut EventContext, event: &mut Event);
+
     fn draw(&self, cx: &mut DrawContext, canvas: &mut Canvas);
 
     fn accessibility(&self, cx: &mut AccessContext, node: &mut AccessNode);
@@ -1,9 +1,13 @@
 use crate::context::{InternalEvent, ResourceContext};
-use crate::events::EventMeta;
+use crate::events::{
+    cancel_drag, drag_mouse_down, drag_mouse_move, drag_mouse_up, gesture_cancel,
+    gesture_mouse_down, gesture_mouse_move, gesture_mouse_up, gesture_touch_update, EventMeta,
+    EventPhase,
+};
 use crate::prelude::*;
 #[cfg(debug_assertions)]
 use crate::systems::compute_matched_rules;
-use crate::systems::hover_system;
+use crate::systems::{hit_test, hover_system};
 use crate::tree::{focus_backward, focus_forward, is_navigatable};
 use instant::{Duration, Instant};
 use std::any::Any;
@@ -42,6 +46,8 @@ impl EventManager {
             // handle internal events
             event.map(|internal_event, _| match internal_event {
                 InternalEvent::Redraw => context.needs_redraw(),
+                InternalEvent::Remove(entity) => context.remove(*entity),
+                InternalEvent::MoveFocus(backwards) => move_focus(context, *backwards),
                 InternalEvent::LoadImage { path, image, policy } => {
                     if let Some(image) = image.lock().unwrap().take() {
                         ResourceContext::new(context).load_image(path.clone(), image, *policy);
@@ -97,8 +103,26 @@ impl EventManager {
 
             // Define the target to prevent multiple mutable borrows error
             let target = event.meta.target;
+            let propagation = event.meta.propagation;
+
+            // Propagate down from root to target (not including target)
+            if matches!(propagation, Propagation::Down | Propagation::DownUp) {
+                event.meta.phase = EventPhase::Capture;
+
+                let ancestors = target.parent_iter(context.tree).skip(1).collect::<Vec<_>>();
+                for entity in ancestors.into_iter().rev() {
+                    // Send event to the capture handlers of all entities before the target
+                    visit_entity(context, entity, event);
+
+                    // Skip to the next event if the current event is consumed
+                    if event.meta.consumed {
+                        continue 'events;
+                    }
+                }
+            }
 
             // Send event to target
+            event.meta.phase = EventPhase::Target;
             visit_entity(context, target, event);
 
             if event.meta.consumed {
@@ -106,7 +130,9 @@ impl EventManager {
             }
 
             // Propagate up from target to root (not including target)
-            if event.meta.propagation == Propagation::Up {
+            if matches!(propagation, Propagation::Up | Propagation::DownUp) {
+                event.meta.phase = EventPhase::Bubble;
+
                 // Walk up the tree from parent to parent
                 for entity in target.parent_iter(context.tree) {
                     // Skip the target entity
@@ -124,7 +150,7 @@ impl EventManager {
                 }
             }
 
-            if event.meta.propagation == Propagation::Subtree {
+            if propagation == Propagation::Subtree {
                 for entity in target.branch_iter(context.tree) {
                     // Skip the target entity
                     if entity == event.meta.target {
@@ -140,13 +166,39 @@ impl EventManager {
                     }
                 }
             }
+
+            // Ctrl+Tab moves the focus once it has been dispatched without being consumed, such as
+            // by a tab view.
+            event.map(|window_event, meta| {
+                if let WindowEvent::KeyDown(Code::Tab, _) = window_event {
+                    if meta.origin == Entity::root() && context.modifiers.contains(Modifiers::CTRL)
+                    {
+                        let backwards = context.modifiers.contains(Modifiers::SHIFT);
+                        context.event_queue.push_back(
+                            Event::new(InternalEvent::MoveFocus(backwards))
+                                .target(Entity::root())
+                                .origin(Entity::root())
+                                .propagate(Propagation::Direct),
+                        );
+                    }
+                }
+            });
+        }
+
+        // Build the dialogs which were opened by the event handlers.
+        for build in std::mem::take(&mut context.dialogs) {
+            (build)(context);
         }
 
         !context.event_queue.is_empty()
     }
 }
 
+// Sends an event to the models and view of an entity, using their capture handlers during the
+// capture phase.
 fn visit_entity(cx: &mut EventContext, entity: Entity, event: &mut Event) {
+    let capture = event.meta.phase == EventPhase::Capture;
+
     // Send event to models attached to the entity
     if let Some(ids) = cx
         .data
@@ -161,12 +213,21 @@ fn visit_entity(cx: &mut EventContext, entity: Entity, event: &mut Event) {
             {
                 cx.current = entity;
 
-                model.event(cx, event);
+                if capture {
+                    model.capture_event(cx, event);
+                } else {
+                    model.event(cx, event);
+                }
 
                 cx.data
                     .get_mut(entity)
                     .and_then(|model_data_store| model_data_store.models.insert(id, model));
             }
+
+            // Return early if a model stopped the event from reaching the other handlers
+            if event.meta.stopped_immediately {
+                return;
+            }
         }
     }
 
@@ -179,7 +240,11 @@ fn visit_entity(cx: &mut EventContext, entity: Entity, event: &mut Event) {
     if let Some(mut view) = cx.views.remove(&entity) {
         // cx.with_current(entity, |cx| {
         cx.current = entity;
-        view.event(cx, event);
+        if capture {
+            view.capture_event(cx, event);
+        } else {
+            view.event(cx, event);
+        }
         // });
 
         cx.views.insert(entity, view);
@@ -195,6 +260,8 @@ fn internal_state_updates(context: &mut Context, window_event: &WindowEvent, met
             context.mouse.cursory = *y;
 
             hover_system(context);
+            drag_mouse_move(context);
+            gesture_mouse_move(context);
             mutate_direct_or_up(meta, context.captured, context.hovered, false);
         }
         WindowEvent::MouseDown(button) => {
@@ -211,6 +278,8 @@ fn internal_state_updates(context: &mut Context, window_event: &WindowEvent, met
                     {
                         pseudo_classes.set(PseudoClass::ACTIVE, true);
                     }
+                    drag_mouse_down(context);
+                    gesture_mouse_down(context);
                     let focusable = context
                         .style
                         .abilities
@@ -291,7 +360,11 @@ fn internal_state_updates(context: &mut Context, window_event: &WindowEvent, met
             }
 
             if matches!(button, MouseButton::Left) {
-                if context.hovered == context.triggered {
+                // A mouse release which ends a drag or a long press does not also press the view.
+                let dragged = drag_mouse_up(context);
+                let long_pressed = gesture_mouse_up(context);
+
+                if context.hovered == context.triggered && !dragged && !long_pressed {
                     emit_direct_or_up(
                         context,
                         WindowEvent::Press { mouse: true },
@@ -313,7 +386,71 @@ fn internal_state_updates(context: &mut Context, window_event: &WindowEvent, met
 
             mutate_direct_or_up(meta, context.captured, context.hovered, true);
         }
-        WindowEvent::MouseScroll(_, _) => {
+        WindowEvent::MouseScroll(_, _)
+        | WindowEvent::Pan(..)
+        | WindowEvent::Magnify(..)
+        | WindowEvent::Rotate(..)
+        | WindowEvent::SmartMagnify => {
+            meta.target = context.hovered;
+        }
+        WindowEvent::TouchStart(touch) | WindowEvent::TouchMove(touch) => {
+            let primary = context.touches.update(*touch);
+            gesture_touch_update(context);
+
+            let hit = hit_test(context, touch.x, touch.y);
+            mutate_direct_or_up(meta, context.touches.captured(touch.id), hit, true);
+
+            // The primary touch emulates the left mouse button so that views which only handle
+            // mouse input can be used with touch.
+            if primary {
+                emit_emulated(context, WindowEvent::MouseMove(touch.x, touch.y));
+                if matches!(window_event, WindowEvent::TouchStart(_)) {
+                    emit_emulated(context, WindowEvent::MouseDown(MouseButton::Left));
+                }
+            }
+        }
+        WindowEvent::TouchEnd(touch) => {
+            let hit = hit_test(context, touch.x, touch.y);
+            mutate_direct_or_up(meta, context.touches.captured(touch.id), hit, true);
+
+            let primary = context.touches.remove(touch.id);
+            gesture_touch_update(context);
+
+            if primary {
+                emit_emulated(context, WindowEvent::MouseUp(MouseButton::Left));
+            }
+        }
+        WindowEvent::TouchCancel(touch) => {
+            let hit = hit_test(context, touch.x, touch.y);
+            mutate_direct_or_up(meta, context.touches.captured(touch.id), hit, true);
+
+            let primary = context.touches.remove(touch.id);
+            gesture_touch_update(context);
+
+            // A touch which is cancelled by the system did not end intentionally, so the emulated
+            // mouse button is released without a mouse release which would press the view.
+            if primary {
+                context.mouse.left.state = MouseButtonState::Released;
+                cancel_drag(context);
+                gesture_cancel(context);
+
+                if let Some(pseudo_classes) =
+                    context.style.pseudo_classes.get_mut(context.triggered)
+                {
+                    pseudo_classes.set(PseudoClass::ACTIVE, false);
+                }
+                context.needs_restyle();
+
+                context.triggered = Entity::null();
+                context.captured = Entity::null();
+            }
+        }
+        WindowEvent::FileHovered(_)
+        | WindowEvent::FileDropped(_)
+        | WindowEvent::FileHoverCancelled => {
+            // The layout may have changed since the cursor last moved, so the hovered entity is
+            // recomputed from the last known cursor position.
+            hover_system(context);
             meta.target = context.hovered;
         }
         WindowEvent::KeyDown(code, _) => {
@@ -437,76 +574,33 @@ fn internal_state_updates(context: &mut Context, window_event: &WindowEvent, met
                 }
             }
 
-            if *code == Code::F5 {
-                EventContext::new(context).reload_styles().unwrap();
+            #[cfg(debug_assertions)]
+            if *code == Code::KeyL
+                && context.modifiers == Modifiers::CTRL | Modifiers::SHIFT | Modifiers::ALT
+            {
+                context.set_layout_debug(!context.layout_debug());
             }
 
-            if *code == Code::Tab {
-                let lock_focus_to = context.tree.lock_focus_within(context.focused);
-                if context.modifiers.contains(Modifiers::SHIFT) {
-                    let prev_focused = if let Some(prev_focused) =
-                        focus_backward(context, context.focused, lock_focus_to)
-                    {
-                        prev_focused
-                    } else {
-                        TreeIterator::full(&context.tree)
-                            .filter(|node| is_navigatable(context, *node, lock_focus_to))
-                            .next_back()
-                            .unwrap_or(Entity::root())
-                    };
-
-                    if prev_focused != context.focused {
-                        context.event_queue.push_back(
-                            Event::new(WindowEvent::FocusOut)
-                                .target(context.focused)
-                                .origin(Entity::root()),
-                        );
-                        context.event_queue.push_back(
-                            Event::new(WindowEvent::FocusIn)
-                                .target(prev_focused)
-                                .origin(Entity::root()),
-                        );
+            #[cfg(debug_assertions)]
+            if *code == Code::KeyD
+                && context.modifiers == Modifiers::CTRL | Modifiers::SHIFT | Modifiers::ALT
+            {
+                context.dump_layout();
+            }
 
-                        if let Some(pseudo_classes) =
-                            context.style.pseudo_classes.get_mut(context.triggered)
-                        {
-                            pseudo_classes.set(PseudoClass::ACTIVE, false);
-                        }
-                        context.needs_restyle();
-                        context.triggered = Entity::null();
-                    }
-                } else {
-                    let next_focused = if let Some(next_focused) =
-                        focus_forward(context, context.focused, lock_focus_to)
-                    {
-                        next_focused
-                    } else {
-                        TreeIterator::full(&context.tree)
-                            .find(|node| is_navigatable(context, *node, lock_focus_to))
-                            .unwrap_or(Entity::root())
-                    };
+            if *code == Code::Escape && cancel_drag(context) {
+                meta.consume();
+            }
 
-                    if next_focused != context.focused {
-                        context.event_queue.push_back(
-                            Event::new(WindowEvent::FocusOut)
-                                .target(context.focused)
-                                .origin(Entity::root()),
-                        );
-                        context.event_queue.push_back(
-                            Event::new(WindowEvent::FocusIn)
-                                .target(next_focused)
-                                .origin(Entity::root()),
-                        );
+            if *code == Code::F5 {
+                EventContext::new(context).reload_styles().unwrap();
+            }
 
-                        if let Some(pseudo_classes) =
-                            context.style.pseudo_classes.get_mut(context.triggered)
-                        {
-                            pseudo_classes.set(PseudoClass::ACTIVE, false);
-                        }
-                        context.needs_restyle();
-                        context.triggered = Entity::null();
-                    }
-                }
+            // Ctrl+Tab moves the focus once it has been dispatched, unless a view such as a tab view
+            // consumes it.
+            if *code == Code::Tab && !context.modifiers.contains(Modifiers::CTRL) {
+                let backwards = context.modifiers.contains(Modifiers::SHIFT);
+                move_focus(context, backwards);
             }
 
             if matches!(*code, Code::Enter | Code::NumpadEnter | Code::Space) {
@@ -538,7 +632,7 @@ fn internal_state_updates(context: &mut Context, window_event: &WindowEvent, met
                 context.triggered = Entity::null();
             }
         }
-        WindowEvent::CharInput(_) => {
+        WindowEvent::CharInput(_) | WindowEvent::ImePreedit(..) | WindowEvent::ImeCommit(_) => {
             meta.target = context.focused;
         }
         WindowEvent::FocusOut => {
@@ -553,13 +647,78 @@ fn internal_state_updates(context: &mut Context, window_event: &WindowEvent, met
     }
 }
 
+// Moves the keyboard focus to the next or previous navigatable view.
+fn move_focus(context: &mut Context, backwards: bool) {
+    let lock_focus_to = context.tree.lock_focus_within(context.focused);
+    if backwards {
+        let prev_focused =
+            if let Some(prev_focused) = focus_backward(context, context.focused, lock_focus_to) {
+                prev_focused
+            } else {
+                TreeIterator::full(&context.tree)
+                    .filter(|node| is_navigatable(context, *node, lock_focus_to))
+                    .next_back()
+                    .unwrap_or(Entity::root())
+            };
+
+        if prev_focused != context.focused {
+            context.event_queue.push_back(
+                Event::new(WindowEvent::FocusOut).target(context.focused).origin(Entity::root()),
+            );
+            context.event_queue.push_back(
+                Event::new(WindowEvent::FocusIn).target(prev_focused).origin(Entity::root()),
+            );
+
+            if let Some(pseudo_classes) = context.style.pseudo_classes.get_mut(context.triggered) {
+                pseudo_classes.set(PseudoClass::ACTIVE, false);
+            }
+            context.needs_restyle();
+            context.triggered = Entity::null();
+        }
+    } else {
+        let next_focused =
+            if let Some(next_focused) = focus_forward(context, context.focused, lock_focus_to) {
+                next_focused
+            } else {
+                TreeIterator::full(&context.tree)
+                    .find(|node| is_navigatable(context, *node, lock_focus_to))
+                    .unwrap_or(Entity::root())
+            };
+
+        if next_focused != context.focused {
+            context.event_queue.push_back(
+                Event::new(WindowEvent::FocusOut).target(context.focused).origin(Entity::root()),
+            );
+            context.event_queue.push_back(
+                Event::new(WindowEvent::FocusIn).target(next_focused).origin(Entity::root()),
+            );
+
+            if let Some(pseudo_classes) = context.style.pseudo_classes.get_mut(context.triggered) {
+                pseudo_classes.set(PseudoClass::ACTIVE, false);
+            }
+            context.needs_restyle();
+            context.triggered = Entity::null();
+        }
+    }
+}
+
+// Queues a mouse event emulated from touch input, which is handled like one from the window.
+fn emit_emulated(context: &mut Context, window_event: WindowEvent) {
+    context.event_queue.push_back(
+        Event::new(window_event)
+            .target(Entity::root())
+            .origin(Entity::root())
+            .propagate(Propagation::DownUp),
+    );
+}
+
 pub fn mutate_direct_or_up(meta: &mut EventMeta, direct: Entity, up: Entity, root: bool) {
     if direct != Entity::null() {
         meta.target = direct;
         meta.propagation = Propagation::Direct;
     } else if up != Entity::root() || root {
         meta.target = up;
-        meta.propagation = Propagation::Up;
+        meta.propagation = Propagation::DownUp;
     } else {
         meta.consume();
     }
@@ -576,3 +735,194 @@ pub fn emit_direct_or_up<M: Any + Send>(
     mutate_direct_or_up(&mut event.meta, direct, up, root);
     context.emit_custom(event);
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    type Log = Rc<RefCell<Vec<(&'static str, EventPhase)>>>;
+
+    struct Ping;
+
+    #[derive(Clone, Copy)]
+    enum Action {
+        Pass,
+        Consume,
+        StopImmediate,
+    }
+
+    // Records the pings it receives, and then passes, consumes or stops them.
+    fn record(name: &'static str, log: &Log, action: Action, event: &mut Event) {
+        event.map(|_: &Ping, meta| {
+            log.borrow_mut().push((name, meta.phase()));
+            match action {
+                Action::Pass => {}
+                Action::Consume => meta.consume(),
+                Action::StopImmediate => meta.stop_immediate(),
+            }
+        });
+    }
+
+    struct Recorder<T> {
+        name: &'static str,
+        log: Log,
+        capture: Action,
+        bubble: Action,
+        p: std::marker::PhantomData<T>,
+    }
+
+    impl<T: 'static> Recorder<T> {
+        fn new(name: &'static str, log: &Log, capture: Action, bubble: Action) -> Self {
+            Self { name, log: log.clone(), capture, bubble, p: std::marker::PhantomData::default() }
+        }
+    }
+
+    impl<T: 'static> Model for Recorder<T> {
+        fn capture_event(&mut self, _: &mut EventContext, event: &mut Event) {
+            record(self.name, &self.log, self.capture, event);
+        }
+
+        fn event(&mut self, _: &mut EventContext, event: &mut Event) {
+            record(self.name, &self.log, self.bubble, event);
+        }
+    }
+
+    struct RecorderView {
+        log: Log,
+    }
+
+    impl View for RecorderView {
+        fn event(&mut self, _: &mut EventContext, event: &mut Event) {
+            record("view", &self.log, Action::Pass, event);
+        }
+    }
+
+    // Builds an outer view containing an inner view, each with a recorder, and sends a ping to the
+    // inner view.
+    fn ping(propagation: Propagation, outer: (Action, Action), inner: (Action, Action)) -> Log {
+        let log = Log::default();
+        let cx = &mut Context::default();
+
+        let mut target = Entity::null();
+        VStack::new(cx, |cx| {
+            Recorder::<()>::new("outer", &log, outer.0, outer.1).build(cx);
+            target = VStack::new(cx, |cx| {
+                Recorder::<()>::new("inner", &log, inner.0, inner.1).build(cx);
+            })
+            .entity;
+        });
+
+        cx.emit_custom(Event::new(Ping).target(target).origin(target).propagate(propagation));
+        EventManager::new().flush_events(cx);
+        log
+    }
+
+    #[test]
+    fn test_capture_order() {
+        let pass = (Action::Pass, Action::Pass);
+
+        let log = ping(Propagation::Down, pass, pass);
+        assert_eq!(
+            *log.borrow(),
+            vec![("outer", EventPhase::Capture), ("inner", EventPhase::Target)]
+        );
+
+        let log = ping(Propagation::DownUp, pass, pass);
+        assert_eq!(
+            *log.borrow(),
+            vec![
+                ("outer", EventPhase::Capture),
+                ("inner", EventPhase::Target),
+                ("outer", EventPhase::Bubble)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_consume_during_capture() {
+        let pass = (Action::Pass, Action::Pass);
+        let log = ping(Propagation::DownUp, (Action::Consume, Action::Pass), pass);
+        assert_eq!(*log.borrow(), vec![("outer", EventPhase::Capture)]);
+    }
+
+    // Builds a view with two models which handle a ping in the same way, and sends it a ping.
+    fn ping_models(action: Action) -> Log {
+        let log = Log::default();
+        let cx = &mut Context::default();
+
+        let target = RecorderView { log: log.clone() }
+            .build(cx, |cx| {
+                Recorder::<u8>::new("model", &log, Action::Pass, action).build(cx);
+                Recorder::<u16>::new("model", &log, Action::Pass, action).build(cx);
+            })
+            .entity;
+
+        cx.emit_custom(Event::new(Ping).target(target).origin(target).propagate(Propagation::Up));
+        EventManager::new().flush_events(cx);
+        log
+    }
+
+    #[test]
+    fn test_stop_immediate() {
+        // A consumed event still reaches the other models of the entity, but not its view.
+        let log = ping_models(Action::Consume);
+        assert_eq!(*log.borrow(), vec![("model", EventPhase::Target); 2]);
+
+        // An event which is stopped immediately does not reach the other model.
+        let log = ping_models(Action::StopImmediate);
+        assert_eq!(*log.borrow(), vec![("model", EventPhase::Target)]);
+
+        let log = ping_models(Action::Pass);
+        assert_eq!(
+            *log.borrow(),
+            vec![
+                ("model", EventPhase::Target),
+                ("model", EventPhase::Target),
+                ("view", EventPhase::Target)
+            ]
+        );
+    }
+
+    // Consumes Ctrl+Tab, like a tab view does.
+    struct CtrlTabConsumer;
+
+    impl Model for CtrlTabConsumer {
+        fn event(&mut self, _: &mut EventContext, event: &mut Event) {
+            event.map(|window_event, meta| {
+                if let WindowEvent::KeyDown(Code::Tab, _) = window_event {
+                    meta.consume();
+                }
+            });
+        }
+    }
+
+    // Builds a navigable element, and returns whether Ctrl+Tab focuses it.
+    fn ctrl_tab_focuses(consume: bool) -> bool {
+        let cx = &mut Context::default();
+        if consume {
+            CtrlTabConsumer.build(cx);
+        }
+
+        let element = Element::new(cx).navigable(true).entity;
+
+        cx.modifiers = Modifiers::CTRL;
+        cx.emit_custom(
+            Event::new(WindowEvent::KeyDown(Code::Tab, None))
+                .target(Entity::root())
+                .origin(Entity::root())
+                .propagate(Propagation::DownUp),
+        );
+
+        let mut event_manager = EventManager::new();
+        while event_manager.flush_events(cx) {}
+        cx.focused == element
+    }
+
+    #[test]
+    fn test_ctrl_tab() {
+        assert!(ctrl_tab_focuses(false));
+        assert!(!ctrl_tab_focuses(true));
+    }
+}
@@ -0,0 +1,441 @@
+use fnv::FnvHashSet;
+use instant::{Duration, Instant};
+
+use crate::prelude::*;
+use crate::systems::hit_test;
+
+/// The time for which the left mouse button or a touch must be held in place before a
+/// [`WindowEvent::LongPress`] is emitted.
+pub const LONG_PRESS_DURATION: Duration = Duration::from_millis(500);
+
+struct PressState {
+    // The nearest views to the pressed view which recognize a pan and a long press, or null.
+    pan_target: Entity,
+    long_press_target: Entity,
+    // The cursor position of the last pan event, in physical pixels.
+    last: (f32, f32),
+    long_press: Option<Timer>,
+    deadline: Instant,
+    panning: bool,
+    // Whether the long press was handled, in which case the release does not press the view.
+    long_pressed: bool,
+}
+
+struct PinchState {
+    target: Entity,
+    ids: (u64, u64),
+    // The distance in physical pixels and the angle in degrees between the two touches.
+    distance: f32,
+    angle: f32,
+}
+
+/// The views which recognize gestures, and the gestures which are being recognized from mouse
+/// and touch input.
+#[derive(Default)]
+pub(crate) struct Gestures {
+    pub pan_targets: FnvHashSet<Entity>,
+    pub long_press_targets: FnvHashSet<Entity>,
+    press: Option<PressState>,
+    pinch: Option<PinchState>,
+}
+
+impl Gestures {
+    /// Stops recognizing gestures for an entity and sending gesture events to it.
+    pub fn remove_entity(&mut self, entity: Entity) {
+        self.pan_targets.remove(&entity);
+        self.long_press_targets.remove(&entity);
+
+        if let Some(press) = &mut self.press {
+            if press.pan_target == entity {
+                press.pan_target = Entity::null();
+            }
+
+            if press.long_press_target == entity {
+                press.long_press_target = Entity::null();
+            }
+        }
+
+        if let Some(pinch) = &mut self.pinch {
+            if pinch.target == entity {
+                pinch.target = Entity::null();
+            }
+        }
+    }
+
+    /// Marks the long press which is in progress as handled, so that releasing it does not press
+    /// the view.
+    pub fn set_long_pressed(&mut self) {
+        if let Some(press) = &mut self.press {
+            press.long_pressed = true;
+        }
+    }
+}
+
+// Returns the nearest of an entity and its ancestors which is one of the given targets, or null.
+fn nearest(cx: &Context, targets: &FnvHashSet<Entity>, entity: Entity) -> Entity {
+    entity.parent_iter(&cx.tree).find(|entity| targets.contains(entity)).unwrap_or_default()
+}
+
+fn send(cx: &mut Context, target: Entity, message: WindowEvent) {
+    // Gesture events originate from their target so that they are not routed again.
+    if target != Entity::null() {
+        cx.event_queue.push_back(
+            Event::new(message).target(target).origin(target).propagate(Propagation::Up),
+        );
+    }
+}
+
+// Sends a gesture event to a view which recognizes it, and not to its ancestors.
+fn send_direct(cx: &mut Context, target: Entity, message: WindowEvent) {
+    if target != Entity::null() {
+        cx.event_queue.push_back(
+            Event::new(message).target(target).origin(target).propagate(Propagation::Direct),
+        );
+    }
+}
+
+fn cancel_press(cx: &mut Context) {
+    if let Some(press) = cx.gestures.press.take() {
+        if let Some(long_press) = press.long_press {
+            cx.timers.cancel(long_press);
+        }
+
+        if press.panning {
+            send_direct(cx, press.pan_target, WindowEvent::Pan(0.0, 0.0, GesturePhase::Cancelled));
+        }
+    }
+}
+
+/// Stops recognizing a long press or a pan when the emulated left mouse button of a touch is
+/// released without a mouse release, cancelling a pan which is in progress.
+pub(crate) fn gesture_cancel(cx: &mut Context) {
+    cancel_press(cx);
+}
+
+/// Starts recognizing a long press or a pan when the left mouse button is pressed, for the nearest
+/// views to the pressed view which recognize them. Nothing is recognized while the mouse is
+/// captured, or when the pressed view can be dragged.
+pub(crate) fn gesture_mouse_down(cx: &mut Context) {
+    cancel_press(cx);
+
+    if cx.gestures.pinch.is_some() || cx.captured != Entity::null() || cx.drag_drop.is_pending() {
+        return;
+    }
+
+    let pan_target = nearest(cx, &cx.gestures.pan_targets, cx.hovered);
+    let long_press_target = nearest(cx, &cx.gestures.long_press_targets, cx.hovered);
+    if pan_target == Entity::null() && long_press_target == Entity::null() {
+        return;
+    }
+
+    let now = Instant::now();
+    let long_press = if long_press_target != Entity::null() {
+        Some(
+            cx.timers.schedule(
+                now,
+                LONG_PRESS_DURATION,
+                Event::new(WindowEvent::LongPress)
+                    .target(long_press_target)
+                    .origin(long_press_target)
+                    .propagate(Propagation::Direct),
+            ),
+        )
+    } else {
+        None
+    };
+
+    cx.gestures.press = Some(PressState {
+        pan_target,
+        long_press_target,
+        last: (cx.mouse.cursorx, cx.mouse.cursory),
+        long_press,
+        deadline: now + LONG_PRESS_DURATION,
+        panning: false,
+        long_pressed: false,
+    });
+}
+
+/// Starts or continues a pan once the cursor has moved far enough with the left mouse button
+/// pressed. Moving the cursor that far prevents a long press. The gestures are cancelled when a
+/// view other than the views which recognize them captures the mouse, such as a slider which
+/// handles the press.
+pub(crate) fn gesture_mouse_move(cx: &mut Context) {
+    let (cursorx, cursory) = (cx.mouse.cursorx, cx.mouse.cursory);
+    let threshold = cx.style.logical_to_physical(DEFAULT_DRAG_THRESHOLD);
+    let captured = cx.captured;
+
+    let press = match &mut cx.gestures.press {
+        Some(press) => press,
+        None => return,
+    };
+
+    if captured != Entity::null()
+        && captured != press.pan_target
+        && captured != press.long_press_target
+    {
+        cancel_press(cx);
+        return;
+    }
+
+    let (dx, dy) = (cursorx - press.last.0, cursory - press.last.1);
+
+    let phase = if press.panning {
+        GesturePhase::Changed
+    } else if (dx * dx + dy * dy).sqrt() >= threshold {
+        if let Some(long_press) = press.long_press.take() {
+            cx.timers.cancel(long_press);
+        }
+
+        if press.pan_target == Entity::null() || Instant::now() >= press.deadline {
+            return;
+        }
+
+        press.panning = true;
+        GesturePhase::Started
+    } else {
+        return;
+    };
+
+    press.last = (cursorx, cursory);
+    let target = press.pan_target;
+
+    send_direct(cx, target, WindowEvent::Pan(dx, dy, phase));
+}
+
+/// Ends the pan or long press, if any. Returns true if a long press was handled, in which case
+/// the mouse release should not also press the view.
+pub(crate) fn gesture_mouse_up(cx: &mut Context) -> bool {
+    let press = match cx.gestures.press.take() {
+        Some(press) => press,
+        None => return false,
+    };
+
+    if let Some(long_press) = press.long_press {
+        cx.timers.cancel(long_press);
+    }
+
+    if press.panning {
+        send_direct(cx, press.pan_target, WindowEvent::Pan(0.0, 0.0, GesturePhase::Ended));
+    }
+
+    press.long_pressed
+}
+
+// Returns the distance in physical pixels and the counterclockwise angle in degrees from the
+// first touch to the second.
+fn measure(first: &Touch, second: &Touch) -> (f32, f32) {
+    let (dx, dy) = (second.x - first.x, second.y - first.y);
+    // The y axis points down, so the angle is negated to be counterclockwise.
+    ((dx * dx + dy * dy).sqrt(), -dy.atan2(dx).to_degrees())
+}
+
+// Wraps an angle in degrees to the range -180..=180.
+fn wrap_angle(angle: f32) -> f32 {
+    let angle = angle % 360.0;
+    if angle > 180.0 {
+        angle - 360.0
+    } else if angle < -180.0 {
+        angle + 360.0
+    } else {
+        angle
+    }
+}
+
+/// Starts, continues or ends a pinch and rotation gesture after the touches have changed.
+///
+/// A pinch starts when a second touch makes contact and ends when either of its touches is
+/// lifted. While it is in progress the primary touch does not pan or long press.
+pub(crate) fn gesture_touch_update(cx: &mut Context) {
+    let pinch = match &cx.gestures.pinch {
+        Some(pinch) => Some((pinch.target, pinch.ids)),
+        None => None,
+    };
+
+    match pinch {
+        Some((target, (first, second))) => {
+            let (first, second) = match (cx.touches.get(first), cx.touches.get(second)) {
+                (Some(first), Some(second)) => (*first, *second),
+                _ => {
+                    cx.gestures.pinch = None;
+                    send(cx, target, WindowEvent::Magnify(0.0, GesturePhase::Ended));
+                    send(cx, target, WindowEvent::Rotate(0.0, GesturePhase::Ended));
+                    return;
+                }
+            };
+
+            let (distance, angle) = measure(&first, &second);
+
+            if let Some(pinch) = &mut cx.gestures.pinch {
+                let magnify =
+                    if pinch.distance > 0.0 { distance / pinch.distance - 1.0 } else { 0.0 };
+                let rotate = wrap_angle(angle - pinch.angle);
+                pinch.distance = distance;
+                pinch.angle = angle;
+
+                if magnify != 0.0 {
+                    send(cx, target, WindowEvent::Magnify(magnify, GesturePhase::Changed));
+                }
+
+                if rotate != 0.0 {
+                    send(cx, target, WindowEvent::Rotate(rotate, GesturePhase::Changed));
+                }
+            }
+        }
+
+        None => {
+            if cx.touches.touches.len() < 2 {
+                return;
+            }
+
+            let (first, second) = (cx.touches.touches[0], cx.touches.touches[1]);
+
+            cancel_press(cx);
+
+            let target = hit_test(cx, (first.x + second.x) / 2.0, (first.y + second.y) / 2.0);
+            let (distance, angle) = measure(&first, &second);
+            cx.gestures.pinch =
+                Some(PinchState { target, ids: (first.id, second.id), distance, angle });
+
+            send(cx, target, WindowEvent::Magnify(0.0, GesturePhase::Started));
+            send(cx, target, WindowEvent::Rotate(0.0, GesturePhase::Started));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::events::EventManager;
+    use std::cell::Cell;
+    use std::rc::Rc;
+
+    // Counts the presses it receives, and consumes long presses if it handles them.
+    struct PressView {
+        presses: Rc<Cell<usize>>,
+        handle_long_press: bool,
+    }
+
+    impl View for PressView {
+        fn event(&mut self, cx: &mut EventContext, event: &mut Event) {
+            event.map(|window_event, meta| match window_event {
+                WindowEvent::Press { .. } => self.presses.set(self.presses.get() + 1),
+                WindowEvent::LongPress if self.handle_long_press => {
+                    cx.handle_long_press();
+                    meta.consume();
+                }
+                _ => {}
+            });
+        }
+    }
+
+    // Counts the pans it receives.
+    struct PanView {
+        pans: Rc<Cell<usize>>,
+    }
+
+    impl View for PanView {
+        fn event(&mut self, _: &mut EventContext, event: &mut Event) {
+            event.map(|window_event, _| {
+                if let WindowEvent::Pan(..) = window_event {
+                    self.pans.set(self.pans.get() + 1);
+                }
+            });
+        }
+    }
+
+    // Captures the mouse when it is pressed, like a slider.
+    struct CaptureView;
+
+    impl View for CaptureView {
+        fn event(&mut self, cx: &mut EventContext, event: &mut Event) {
+            event.map(|window_event, _| {
+                if let WindowEvent::MouseDown(_) = window_event {
+                    cx.capture();
+                }
+            });
+        }
+    }
+
+    fn flush(cx: &mut Context) {
+        let mut event_manager = EventManager::new();
+        while event_manager.flush_events(cx) {}
+    }
+
+    fn send_mouse(cx: &mut Context, window_event: WindowEvent) {
+        cx.emit_custom(Event::new(window_event).target(Entity::root()).origin(Entity::root()));
+        flush(cx);
+    }
+
+    // Holds the left mouse button over a view for longer than the long press duration, and
+    // returns the number of presses the view received.
+    fn slow_click(handle_long_press: bool) -> usize {
+        let cx = &mut Context::default();
+        let presses = Rc::new(Cell::new(0));
+        cx.hovered =
+            PressView { presses: presses.clone(), handle_long_press }.build(cx, |_| {}).entity;
+        cx.gestures.long_press_targets.insert(cx.hovered);
+
+        send_mouse(cx, WindowEvent::MouseDown(MouseButton::Left));
+
+        // Deliver the long press as if the duration had elapsed.
+        if let Some(press) = &mut cx.gestures.press {
+            press.deadline = Instant::now();
+        }
+        cx.timers.process(Instant::now() + LONG_PRESS_DURATION, &mut cx.event_queue);
+        flush(cx);
+
+        send_mouse(cx, WindowEvent::MouseUp(MouseButton::Left));
+        presses.get()
+    }
+
+    #[test]
+    fn test_slow_click_presses() {
+        assert_eq!(slow_click(false), 1);
+        assert_eq!(slow_click(true), 0);
+    }
+
+    // Drags the cursor from over a view inside a panned view, and returns the number of pans the
+    // panned view received.
+    fn drag_child(capture: bool) -> usize {
+        let cx = &mut Context::default();
+        let pans = Rc::new(Cell::new(0));
+        let mut child = Entity::null();
+        let parent = PanView { pans: pans.clone() }
+            .build(cx, |cx| child = CaptureView.build(cx, |_| {}).entity)
+            .entity;
+        cx.gestures.pan_targets.insert(parent);
+        cx.hovered = if capture { child } else { parent };
+
+        send_mouse(cx, WindowEvent::MouseDown(MouseButton::Left));
+        send_mouse(cx, WindowEvent::MouseMove(DEFAULT_DRAG_THRESHOLD * 4.0, 0.0));
+        send_mouse(cx, WindowEvent::MouseUp(MouseButton::Left));
+        pans.get()
+    }
+
+    #[test]
+    fn test_pan_stops_at_captured_view() {
+        assert!(drag_child(false) > 0);
+        assert_eq!(drag_child(true), 0);
+    }
+
+    fn touch(id: u64, x: f32, y: f32) -> Touch {
+        Touch { id, x, y, pressure: None, tilt: None }
+    }
+
+    #[test]
+    fn test_measure() {
+        let (distance, angle) = measure(&touch(0, 0.0, 0.0), &touch(1, 3.0, -4.0));
+        assert_eq!(distance, 5.0);
+        assert!(angle > 0.0);
+
+        let (_, angle) = measure(&touch(0, 0.0, 0.0), &touch(1, 0.0, 10.0));
+        assert_eq!(angle, -90.0);
+    }
+
+    #[test]
+    fn test_wrap_angle() {
+        assert_eq!(wrap_angle(90.0), 90.0);
+        assert_eq!(wrap_angle(270.0), -90.0);
+        assert_eq!(wrap_angle(-350.0), 10.0);
+    }
+}
@@ -39,7 +39,23 @@ mod event_manager;
 pub use event_manager::EventManager;
 
 mod event;
-pub use event::{Event, EventMeta, Propagation};
+pub use event::{Event, EventMeta, EventPhase, Propagation};
 
 mod event_handler;
 pub use event_handler::ViewHandler;
+
+mod drag;
+pub(crate) use drag::{cancel_drag, drag_mouse_down, drag_mouse_move, drag_mouse_up};
+pub(crate) use drag::{DragDrop, DragSource, DropTarget};
+pub use drag::{DragEvent, DEFAULT_DRAG_THRESHOLD};
+
+mod gesture;
+pub use gesture::LONG_PRESS_DURATION;
+pub(crate) use gesture::{
+    gesture_cancel, gesture_mouse_down, gesture_mouse_move, gesture_mouse_up, gesture_touch_update,
+    Gestures,
+};
+
+mod timer;
+pub(crate) use timer::Timers;
+pub use timer::{Timer, TimerEvent};
@@ -0,0 +1,195 @@
+use std::collections::VecDeque;
+
+use instant::{Duration, Instant};
+
+use crate::prelude::*;
+
+/// A handle to a timer or a scheduled event, which can be used to cancel it.
+///
+/// This type is part of the prelude.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Timer(u64);
+
+/// Events emitted by timers.
+///
+/// This type is part of the prelude.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TimerEvent {
+    /// Emitted directly to the target of a timer each time its interval elapses.
+    Tick(Timer),
+}
+
+struct TimerState {
+    timer: Timer,
+    target: Entity,
+    interval: Duration,
+    repeat: bool,
+    deadline: Instant,
+}
+
+struct ScheduledEvent {
+    timer: Timer,
+    deadline: Instant,
+    event: Event,
+}
+
+/// The active timers and scheduled events of a context.
+#[derive(Default)]
+pub(crate) struct Timers {
+    next_id: u64,
+    timers: Vec<TimerState>,
+    scheduled: Vec<ScheduledEvent>,
+}
+
+impl Timers {
+    fn next_timer(&mut self) -> Timer {
+        self.next_id += 1;
+        Timer(self.next_id)
+    }
+
+    /// Adds a timer which ticks after `interval` has elapsed from `now`.
+    pub fn add(&mut self, now: Instant, interval: Duration, repeat: bool, target: Entity) -> Timer {
+        let timer = self.next_timer();
+        self.timers.push(TimerState { timer, target, interval, repeat, deadline: now + interval });
+        timer
+    }
+
+    /// Schedules an event to be emitted after `delay` has elapsed from `now`.
+    pub fn schedule(&mut self, now: Instant, delay: Duration, event: Event) -> Timer {
+        let timer = self.next_timer();
+        self.scheduled.push(ScheduledEvent { timer, deadline: now + delay, event });
+        timer
+    }
+
+    /// Cancels a timer or scheduled event. Does nothing if it has already finished.
+    pub fn cancel(&mut self, timer: Timer) {
+        self.timers.retain(|state| state.timer != timer);
+        self.scheduled.retain(|scheduled| scheduled.timer != timer);
+    }
+
+    /// Removes the timers which target an entity, and the scheduled events which target or
+    /// originate from it.
+    pub fn remove_entity(&mut self, entity: Entity) {
+        self.timers.retain(|state| state.target != entity);
+        self.scheduled.retain(|scheduled| {
+            scheduled.event.meta.target != entity && scheduled.event.meta.origin != entity
+        });
+    }
+
+    /// Returns the earliest time at which a timer ticks or a scheduled event is due.
+    pub fn next_deadline(&self) -> Option<Instant> {
+        self.timers
+            .iter()
+            .map(|state| state.deadline)
+            .chain(self.scheduled.iter().map(|scheduled| scheduled.deadline))
+            .min()
+    }
+
+    /// Pushes the events of every timer and scheduled event which is due at `now` to the queue.
+    pub fn process(&mut self, now: Instant, event_queue: &mut VecDeque<Event>) {
+        let mut finished = Vec::new();
+
+        for state in self.timers.iter_mut() {
+            if state.deadline <= now {
+                event_queue.push_back(
+                    Event::new(TimerEvent::Tick(state.timer))
+                        .target(state.target)
+                        .origin(state.target)
+                        .propagate(Propagation::Direct),
+                );
+
+                if state.repeat {
+                    // A timer which has fallen behind ticks once rather than catching up.
+                    state.deadline = (state.deadline + state.interval).max(now + state.interval);
+                } else {
+                    finished.push(state.timer);
+                }
+            }
+        }
+
+        self.timers.retain(|state| !finished.contains(&state.timer));
+
+        let (mut due, pending) = std::mem::take(&mut self.scheduled)
+            .into_iter()
+            .partition::<Vec<_>, _>(|scheduled| scheduled.deadline <= now);
+        self.scheduled = pending;
+
+        due.sort_by_key(|scheduled| scheduled.deadline);
+        event_queue.extend(due.into_iter().map(|scheduled| scheduled.event));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ticks(event_queue: &mut VecDeque<Event>) -> Vec<Timer> {
+        event_queue
+            .drain(..)
+            .filter_map(|mut event| event.take::<TimerEvent>())
+            .map(|TimerEvent::Tick(timer)| timer)
+            .collect()
+    }
+
+    #[test]
+    fn test_timers() {
+        let start = Instant::now();
+        let mut timers = Timers::default();
+        let mut event_queue = VecDeque::new();
+
+        let once = timers.add(start, Duration::from_millis(10), false, Entity::root());
+        let repeating = timers.add(start, Duration::from_millis(20), true, Entity::root());
+        assert_eq!(timers.next_deadline(), Some(start + Duration::from_millis(10)));
+
+        timers.process(start + Duration::from_millis(5), &mut event_queue);
+        assert!(ticks(&mut event_queue).is_empty());
+
+        timers.process(start + Duration::from_millis(10), &mut event_queue);
+        assert_eq!(ticks(&mut event_queue), vec![once]);
+
+        timers.process(start + Duration::from_millis(20), &mut event_queue);
+        assert_eq!(ticks(&mut event_queue), vec![repeating]);
+
+        timers.process(start + Duration::from_millis(40), &mut event_queue);
+        assert_eq!(ticks(&mut event_queue), vec![repeating]);
+
+        timers.cancel(repeating);
+        assert_eq!(timers.next_deadline(), None);
+    }
+
+    #[test]
+    fn test_scheduled_events() {
+        let start = Instant::now();
+        let mut timers = Timers::default();
+        let mut event_queue = VecDeque::new();
+
+        timers.schedule(start, Duration::from_millis(20), Event::new(2u32));
+        timers.schedule(start, Duration::from_millis(10), Event::new(1u32));
+        let cancelled = timers.schedule(start, Duration::from_millis(10), Event::new(3u32));
+        timers.cancel(cancelled);
+
+        timers.process(start + Duration::from_millis(30), &mut event_queue);
+        let messages =
+            event_queue.drain(..).filter_map(|mut event| event.take::<u32>()).collect::<Vec<_>>();
+        assert_eq!(messages, vec![1, 2]);
+    }
+
+    #[test]
+    fn test_remove_entity() {
+        let start = Instant::now();
+        let mut timers = Timers::default();
+        let mut event_queue = VecDeque::new();
+        let entity = Entity::new(1, 0);
+
+        timers.add(start, Duration::from_millis(10), false, entity);
+        timers.schedule(start, Duration::from_millis(10), Event::new(1u32).target(entity));
+        timers.schedule(start, Duration::from_millis(10), Event::new(2u32).origin(entity));
+        timers.schedule(start, Duration::from_millis(10), Event::new(3u32));
+
+        timers.remove_entity(entity);
+        timers.process(start + Duration::from_millis(10), &mut event_queue);
+        let messages =
+            event_queue.drain(..).filter_map(|mut event| event.take::<u32>()).collect::<Vec<_>>();
+        assert_eq!(messages, vec![3]);
+    }
+}
@@ -0,0 +1,198 @@
+use cosmic_text::{Attrs, Color as CosmicColor, Metrics, Wrap};
+use femtovg::{Paint, Path};
+use fnv::FnvHashMap;
+use morphorm::Units;
+
+use crate::cache::CachedData;
+use crate::events::ViewHandler;
+use crate::prelude::*;
+use crate::style::{fmt_units, Style};
+use crate::text::{TextConfig, TextContext};
+use vizia_storage::{DrawIterator, TreeIterator};
+
+// Resolves a pixel or percentage child space to physical pixels. Stretch and auto child space
+// depend on the children and are not shown.
+fn resolve_child_space(style: &Style, units: Option<&Units>, size: f32) -> f32 {
+    match units {
+        Some(Units::Pixels(val)) => style.logical_to_physical(*val),
+        Some(Units::Percentage(val)) => size * val / 100.0,
+        _ => 0.0,
+    }
+}
+
+/// Draws the bounds, space, child space, clip region and id of every visible entity on top of the
+/// rendered views.
+///
+/// Space around a view is filled in orange, child space inside a view is filled in green, and the
+/// clip region is outlined in blue when it differs from the bounds.
+pub(crate) fn draw_layout_overlay(
+    canvas: &mut Canvas,
+    tree: &Tree<Entity>,
+    cache: &CachedData,
+    style: &Style,
+    text_context: &mut TextContext,
+    text_config: TextConfig,
+) {
+    canvas.save();
+    canvas.reset_transform();
+    canvas.reset_scissor();
+
+    let bounds_paint = {
+        let mut paint = Paint::color(femtovg::Color::rgb(255, 0, 0));
+        paint.set_line_width(1.0);
+        paint
+    };
+    let clip_paint = {
+        let mut paint = Paint::color(femtovg::Color::rgb(0, 0, 255));
+        paint.set_line_width(1.0);
+        paint
+    };
+    let space_paint = Paint::color(femtovg::Color::rgba(255, 165, 0, 48));
+    let child_space_paint = Paint::color(femtovg::Color::rgba(0, 200, 0, 48));
+
+    let font_size = 10.0 * style.dpi_factor as f32;
+    let attrs = Attrs::new().color(CosmicColor::rgb(255, 0, 0));
+    let attrs = match style.default_font.first() {
+        Some(family) => attrs.family(family.as_family()),
+        None => attrs,
+    };
+
+    for entity in DrawIterator::full(tree) {
+        if entity == Entity::root()
+            || cache.get_visibility(entity) == Visibility::Invisible
+            || cache.get_display(entity) == Display::None
+        {
+            continue;
+        }
+
+        let bounds = cache.get_bounds(entity);
+
+        // Space around the view.
+        let space = cache.space.get(entity).cloned().unwrap_or_default();
+        let mut path = Path::new();
+        path.rect(bounds.x - space.left, bounds.y, space.left.max(0.0), bounds.h);
+        path.rect(bounds.x + bounds.w, bounds.y, space.right.max(0.0), bounds.h);
+        path.rect(bounds.x, bounds.y - space.top, bounds.w, space.top.max(0.0));
+        path.rect(bounds.x, bounds.y + bounds.h, bounds.w, space.bottom.max(0.0));
+        canvas.fill_path(&mut path, &space_paint);
+
+        // Child space inside the view.
+        let child_left = resolve_child_space(style, style.child_left.get(entity), bounds.w);
+        let child_right = resolve_child_space(style, style.child_right.get(entity), bounds.w);
+        let child_top = resolve_child_space(style, style.child_top.get(entity), bounds.h);
+        let child_bottom = resolve_child_space(style, style.child_bottom.get(entity), bounds.h);
+        let mut path = Path::new();
+        path.rect(bounds.x, bounds.y, child_left, bounds.h);
+        path.rect(bounds.x + bounds.w - child_right, bounds.y, child_right, bounds.h);
+        path.rect(bounds.x, bounds.y, bounds.w, child_top);
+        path.rect(bounds.x, bounds.y + bounds.h - child_bottom, bounds.w, child_bottom);
+        canvas.fill_path(&mut path, &child_space_paint);
+
+        let mut path = Path::new();
+        path.rect(bounds.x + 0.5, bounds.y + 0.5, bounds.w - 1.0, bounds.h - 1.0);
+        canvas.stroke_path(&mut path, &bounds_paint);
+
+        let clip_region = cache.get_clip_region(entity);
+        if clip_region != bounds {
+            let mut path = Path::new();
+            path.rect(
+                clip_region.x + 0.5,
+                clip_region.y + 0.5,
+                clip_region.w - 1.0,
+                clip_region.h - 1.0,
+            );
+            canvas.stroke_path(&mut path, &clip_paint);
+        }
+
+        // The id is drawn with a scratch text buffer which isn't owned by any view.
+        text_context.with_buffer(Entity::null(), |buf| {
+            buf.set_wrap(Wrap::None);
+            buf.set_metrics(Metrics::new(font_size as i32, (font_size * 1.25) as i32));
+            buf.set_text(&entity.to_string(), attrs);
+            buf.shape_until_scroll();
+        });
+
+        if let Ok(draw_commands) = text_context.fill_to_cmds(
+            canvas,
+            Entity::root(),
+            Entity::null(),
+            (bounds.x + 2.0, bounds.y + 1.0),
+            (0.0, 0.0),
+            text_config,
+        ) {
+            for (color, cmds) in draw_commands.into_iter() {
+                let paint =
+                    Paint::color(femtovg::Color::rgba(color.r(), color.g(), color.b(), color.a()));
+                canvas.draw_glyph_commands(cmds, &paint, 1.0);
+            }
+        }
+    }
+
+    text_context.clear_buffer(Entity::null());
+
+    canvas.restore();
+}
+
+/// Returns a description of the layout of every entity in the tree, one entity per line.
+///
+/// Each line shows the computed bounds of the entity in physical pixels followed by the layout
+/// properties which have been set on it.
+pub(crate) fn layout_dump(
+    tree: &Tree<Entity>,
+    views: &FnvHashMap<Entity, Box<dyn ViewHandler>>,
+    cache: &CachedData,
+    style: &Style,
+) -> String {
+    let mut result = String::new();
+
+    for entity in TreeIterator::full(tree) {
+        let depth = entity.parent_iter(tree).count() - 1;
+        let name =
+            views.get(&entity).map_or("<None>", |view| view.element().unwrap_or("<Unnamed>"));
+        let bounds = cache.get_bounds(entity);
+
+        result += &format!(
+            "{}{} {} x: {} y: {} w: {} h: {}",
+            "  ".repeat(depth),
+            entity,
+            name,
+            bounds.x,
+            bounds.y,
+            bounds.w,
+            bounds.h
+        );
+
+        if cache.get_display(entity) == Display::None {
+            result += " display: none";
+        }
+
+        let units = [
+            ("left", style.left.get(entity)),
+            ("right", style.right.get(entity)),
+            ("top", style.top.get(entity)),
+            ("bottom", style.bottom.get(entity)),
+            ("width", style.width.get(entity)),
+            ("height", style.height.get(entity)),
+            ("min-width", style.min_width.get(entity)),
+            ("max-width", style.max_width.get(entity)),
+            ("min-height", style.min_height.get(entity)),
+            ("max-height", style.max_height.get(entity)),
+            ("child-left", style.child_left.get(entity)),
+            ("child-right", style.child_right.get(entity)),
+            ("child-top", style.child_top.get(entity)),
+            ("child-bottom", style.child_bottom.get(entity)),
+            ("row-between", style.row_between.get(entity)),
+            ("col-between", style.col_between.get(entity)),
+        ];
+
+        for (property, value) in units {
+            if let Some(value) = value {
+                result += &format!(" {}: {}", property, fmt_units(value));
+            }
+        }
+
+        result.push('\n');
+    }
+
+    result
+}
@@ -0,0 +1,248 @@
+use morphorm::{LayoutType, PositionType};
+
+use crate::cache::CachedData;
+use crate::prelude::*;
+use crate::style::{GridPlacement, Style};
+use vizia_storage::{LayoutChildIterator, LayoutTreeIterator, SparseSet};
+
+/// The placement requested by a child of a grid, where a missing index is placed automatically.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq)]
+pub(crate) struct GridItem {
+    pub row_index: Option<usize>,
+    pub col_index: Option<usize>,
+    pub row_span: usize,
+    pub col_span: usize,
+}
+
+/// Tracks which cells of a grid are occupied, growing the number of rows as needed.
+struct Occupancy {
+    num_cols: usize,
+    cells: Vec<Vec<bool>>,
+}
+
+impl Occupancy {
+    fn is_free(&self, row: usize, col: usize, row_span: usize, col_span: usize) -> bool {
+        (row..row + row_span).all(|r| {
+            (col..col + col_span).all(|c| {
+                self.cells.get(r).and_then(|cells| cells.get(c)).map_or(true, |used| !used)
+            })
+        })
+    }
+
+    fn occupy(&mut self, placement: &GridPlacement) {
+        let rows = placement.row_index + placement.row_span;
+        let cols = self.num_cols.max(placement.col_index + placement.col_span);
+        if self.cells.len() < rows {
+            self.cells.resize(rows, Vec::new());
+        }
+
+        for row in self.cells[placement.row_index..rows].iter_mut() {
+            if row.len() < cols {
+                row.resize(cols, false);
+            }
+
+            for cell in
+                row[placement.col_index..placement.col_index + placement.col_span].iter_mut()
+            {
+                *cell = true;
+            }
+        }
+    }
+}
+
+/// Assigns grid cells to a list of items in source order.
+///
+/// Items with both indices are placed first. The remaining items fill the free cells of the grid
+/// row by row, keeping to a given row or column if one of their indices is set. Rows are added
+/// beyond the end of the grid if the items don't fit.
+pub(crate) fn place_grid_items(num_cols: usize, items: &[GridItem]) -> Vec<GridPlacement> {
+    let num_cols = num_cols.max(1);
+    let mut occupancy = Occupancy { num_cols, cells: Vec::new() };
+    let mut placements = vec![None; items.len()];
+
+    for (index, item) in items.iter().enumerate() {
+        if let (Some(row_index), Some(col_index)) = (item.row_index, item.col_index) {
+            let placement = GridPlacement {
+                row_index,
+                row_span: item.row_span.max(1),
+                col_index,
+                col_span: item.col_span.max(1),
+            };
+            occupancy.occupy(&placement);
+            placements[index] = Some(placement);
+        }
+    }
+
+    // The position after the last automatically placed item.
+    let mut cursor = (0, 0);
+
+    for (index, item) in items.iter().enumerate() {
+        if placements[index].is_some() {
+            continue;
+        }
+
+        let row_span = item.row_span.max(1);
+        let col_span = item.col_span.max(1);
+
+        let (row_index, col_index) = match (item.row_index, item.col_index) {
+            (Some(row), None) => {
+                let col_span = col_span.min(num_cols);
+                let col = (0..=num_cols - col_span)
+                    .find(|col| occupancy.is_free(row, *col, row_span, col_span))
+                    .unwrap_or_default();
+                (row, col)
+            }
+
+            (None, Some(col)) => {
+                let row = (0..).find(|row| occupancy.is_free(*row, col, row_span, col_span));
+                (row.unwrap_or_default(), col)
+            }
+
+            _ => {
+                let col_span = col_span.min(num_cols);
+                let (mut row, mut col) = cursor;
+                while !occupancy.is_free(row, col, row_span, col_span) || col + col_span > num_cols
+                {
+                    col += 1;
+                    if col + col_span > num_cols {
+                        col = 0;
+                        row += 1;
+                    }
+                }
+                cursor = (row, col + col_span);
+                (row, col)
+            }
+        };
+
+        let placement = GridPlacement {
+            row_index,
+            row_span,
+            col_index,
+            col_span: if item.col_index.is_some() { col_span } else { col_span.min(num_cols) },
+        };
+        occupancy.occupy(&placement);
+        placements[index] = Some(placement);
+    }
+
+    placements.into_iter().map(|placement| placement.unwrap_or_default()).collect()
+}
+
+/// Places the children of grids which use template areas or automatic placement.
+///
+/// The placements are stored in the style and read by the morphorm node implementation in place of
+/// the row and column indices, along with the total number of rows needed by each grid.
+pub(crate) fn grid_system(cache: &CachedData, tree: &Tree<Entity>, style: &mut Style) {
+    let mut placements = SparseSet::new();
+    let mut row_counts = SparseSet::new();
+
+    for parent in LayoutTreeIterator::full(tree) {
+        if style.layout_type.get(parent) != Some(&LayoutType::Grid) {
+            continue;
+        }
+
+        let areas = style.grid_template_areas.get(parent);
+        let num_cols = style
+            .grid_cols
+            .get(parent)
+            .map(|cols| cols.len())
+            .or_else(|| areas.map(|areas| areas.num_cols()))
+            .unwrap_or(1);
+
+        let children = LayoutChildIterator::new(tree, parent)
+            .filter(|child| {
+                cache.get_display(*child) != Display::None
+                    && style.position_type.get(*child) != Some(&PositionType::SelfDirected)
+            })
+            .collect::<Vec<_>>();
+
+        let items = children
+            .iter()
+            .map(|child| {
+                let area = style
+                    .grid_area
+                    .get(*child)
+                    .and_then(|name| areas.and_then(|areas| areas.area(name)));
+
+                match area {
+                    Some(area) => GridItem {
+                        row_index: Some(area.row_index),
+                        col_index: Some(area.col_index),
+                        row_span: area.row_span,
+                        col_span: area.col_span,
+                    },
+
+                    None => GridItem {
+                        row_index: style.row_index.get(*child).copied(),
+                        col_index: style.col_index.get(*child).copied(),
+                        row_span: style.row_span.get(*child).copied().unwrap_or(1),
+                        col_span: style.col_span.get(*child).copied().unwrap_or(1),
+                    },
+                }
+            })
+            .collect::<Vec<_>>();
+
+        let mut num_rows = areas.map(|areas| areas.num_rows()).unwrap_or_default();
+
+        for (child, placement) in children.iter().zip(place_grid_items(num_cols, &items)) {
+            num_rows = num_rows.max(placement.row_index + placement.row_span);
+            placements.insert(*child, placement).unwrap();
+        }
+
+        row_counts.insert(parent, num_rows).unwrap();
+    }
+
+    style.grid_placements = placements;
+    style.grid_row_counts = row_counts;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{place_grid_items, GridItem};
+    use crate::style::GridPlacement;
+
+    fn auto(row_span: usize, col_span: usize) -> GridItem {
+        GridItem { row_index: None, col_index: None, row_span, col_span }
+    }
+
+    fn placement(
+        row_index: usize,
+        col_index: usize,
+        row_span: usize,
+        col_span: usize,
+    ) -> GridPlacement {
+        GridPlacement { row_index, row_span, col_index, col_span }
+    }
+
+    #[test]
+    fn test_auto_placement() {
+        let items = [auto(1, 1), auto(1, 1), auto(1, 1)];
+        let placements = place_grid_items(2, &items);
+
+        assert_eq!(
+            placements,
+            vec![placement(0, 0, 1, 1), placement(0, 1, 1, 1), placement(1, 0, 1, 1)]
+        );
+    }
+
+    // Like css, automatic placement never backtracks to fill earlier gaps.
+    #[test]
+    fn test_auto_placement_around_fixed() {
+        let items = [
+            auto(1, 1),
+            GridItem { row_index: Some(0), col_index: Some(1), row_span: 2, col_span: 1 },
+            auto(1, 2),
+            auto(1, 1),
+        ];
+        let placements = place_grid_items(2, &items);
+
+        assert_eq!(
+            placements,
+            vec![
+                placement(0, 0, 1, 1),
+                placement(0, 1, 2, 1),
+                placement(2, 0, 1, 2),
+                placement(3, 0, 1, 1)
+            ]
+        );
+    }
+}
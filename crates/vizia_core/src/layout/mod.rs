@@ -3,12 +3,75 @@
 //!
 //! All layout calculations are handled by the Morphorm crate.
 pub(crate) mod cache;
+pub(crate) mod debug;
+pub(crate) mod grid;
 pub(crate) mod node;
+pub(crate) mod wrap;
 
+use crate::cache::CachedData;
 use crate::prelude::*;
-use crate::style::SystemFlags;
+use crate::style::{Style, SystemFlags};
 pub use morphorm::GeometryChanged;
 use morphorm::{Cache, Hierarchy};
+use vizia_storage::{LayoutTreeIterator, SparseSet};
+
+/// Returns the horizontal positions of all entities if any entity has a right-to-left direction.
+///
+/// The returned positions are used by [`mirror_layout`] to determine whether the position of an
+/// entity has changed once the layout has been mirrored.
+pub(crate) fn snapshot_rtl_positions(
+    cache: &CachedData,
+    tree: &Tree<Entity>,
+    style: &Style,
+) -> Option<SparseSet<f32>> {
+    if !LayoutTreeIterator::full(tree).any(|entity| style.layout_direction(entity).is_rtl()) {
+        return None;
+    }
+
+    let mut positions = SparseSet::new();
+    for entity in LayoutTreeIterator::full(tree) {
+        positions.insert(entity, cache.get_posx(entity)).unwrap();
+    }
+
+    Some(positions)
+}
+
+/// Mirrors the horizontal layout of the children of right-to-left entities.
+///
+/// Morphorm always lays out from left to right, so the offset of each child from the left edge of
+/// a right-to-left parent is reflected to become its offset from the right edge. Walking the tree
+/// from the root down means that every child is placed relative to its already mirrored parent.
+pub(crate) fn mirror_layout(
+    cache: &mut CachedData,
+    tree: &Tree<Entity>,
+    style: &Style,
+    previous: &SparseSet<f32>,
+) {
+    let mut unmirrored = SparseSet::new();
+
+    for entity in LayoutTreeIterator::full(tree) {
+        let posx = cache.get_posx(entity);
+        unmirrored.insert(entity, posx).unwrap();
+
+        if let Some(parent) = tree.get_layout_parent(entity) {
+            let parent_posx = unmirrored.get(parent).copied().unwrap_or_default();
+            let offset = posx - parent_posx;
+
+            let new_posx = if style.layout_direction(parent).is_rtl() {
+                cache.get_posx(parent) + cache.get_width(parent) - offset - cache.get_width(entity)
+            } else {
+                cache.get_posx(parent) + offset
+            };
+
+            cache.set_posx(entity, new_posx);
+        }
+
+        // Morphorm compared against the mirrored position from the previous layout, so recompute
+        // the change flag from the final position.
+        let changed = previous.get(entity).map_or(true, |prev| *prev != cache.get_posx(entity));
+        cache.set_geo_changed(entity, GeometryChanged::POSX_CHANGED, changed);
+    }
+}
 
 pub(crate) fn geometry_changed(cx: &mut Context) {
     if cx.style.system_flags.contains(SystemFlags::RELAYOUT) {
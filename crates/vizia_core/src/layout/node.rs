@@ -13,10 +13,19 @@ impl<'w> Node<'w> for Entity {
     }
 
     fn position_type(&self, store: &Self::Data) -> Option<morphorm::PositionType> {
+        // Children of a wrapping view are placed at the offsets computed by the wrap system.
+        if store.wrap_positions.contains(*self) {
+            return Some(morphorm::PositionType::SelfDirected);
+        }
+
         store.position_type.get(*self).cloned()
     }
 
     fn left(&self, store: &Self::Data) -> Option<morphorm::Units> {
+        if let Some((x, _)) = store.wrap_positions.get(*self) {
+            return Some(Units::Pixels(*x));
+        }
+
         store.left.get(*self).cloned().map(|l| match l {
             Units::Pixels(val) => Units::Pixels(store.logical_to_physical(val)),
             t => t,
@@ -38,6 +47,10 @@ impl<'w> Node<'w> for Entity {
     }
 
     fn right(&self, store: &Self::Data) -> Option<morphorm::Units> {
+        if store.wrap_positions.contains(*self) {
+            return Some(Units::Stretch(1.0));
+        }
+
         store.right.get(*self).cloned().map(|r| match r {
             Units::Pixels(val) => Units::Pixels(store.logical_to_physical(val)),
             t => t,
@@ -59,6 +72,10 @@ impl<'w> Node<'w> for Entity {
     }
 
     fn top(&self, store: &Self::Data) -> Option<morphorm::Units> {
+        if let Some((_, y)) = store.wrap_positions.get(*self) {
+            return Some(Units::Pixels(*y));
+        }
+
         store.top.get(*self).cloned().map(|t| match t {
             Units::Pixels(val) => Units::Pixels(store.logical_to_physical(val)),
             t => t,
@@ -80,6 +97,10 @@ impl<'w> Node<'w> for Entity {
     }
 
     fn bottom(&self, store: &Self::Data) -> Option<morphorm::Units> {
+        if store.wrap_positions.contains(*self) {
+            return Some(Units::Stretch(1.0));
+        }
+
         store.bottom.get(*self).cloned().map(|b| match b {
             Units::Pixels(val) => Units::Pixels(store.logical_to_physical(val)),
             t => t,
@@ -101,6 +122,22 @@ impl<'w> Node<'w> for Entity {
     }
 
     fn width(&self, store: &Self::Data) -> Option<morphorm::Units> {
+        // An auto sized wrapping column is as wide as its lines.
+        if let Some(extent) = store.wrap_extents.get(*self) {
+            if store.layout_type.get(*self) != Some(&morphorm::LayoutType::Row)
+                && store.width.get(*self) == Some(&Units::Auto)
+            {
+                return Some(Units::Pixels(*extent));
+            }
+        }
+
+        // An auto width follows a fixed height when the view has an aspect ratio.
+        if let (Some(Units::Auto), Some(Units::Pixels(height)), Some(ratio)) =
+            (store.width.get(*self), store.height.get(*self), aspect_ratio(store, *self))
+        {
+            return Some(Units::Pixels(store.logical_to_physical(*height * ratio)));
+        }
+
         store.width.get(*self).cloned().map(|w| match w {
             Units::Pixels(val) => Units::Pixels(store.logical_to_physical(val)),
             t => t,
@@ -133,8 +170,13 @@ impl<'w> Node<'w> for Entity {
         &self,
         store: &'_ Self::Data,
         _sublayout: &'_ mut Self::Sublayout,
-        _height: f32,
+        height: f32,
     ) -> Option<f32> {
+        // The width of a view with an aspect ratio is resolved from its computed height.
+        if let Some(ratio) = aspect_ratio(store, *self) {
+            return Some(height * ratio);
+        }
+
         store.content_width.get(*self).cloned().map(|x| (x * store.dpi_factor as f32).ceil())
     }
 
@@ -144,6 +186,12 @@ impl<'w> Node<'w> for Entity {
         sublayout: &'_ mut Self::Sublayout,
         width: f32,
     ) -> Option<f32> {
+        // The height of a view with an aspect ratio is resolved from its computed width, which
+        // takes precedence over the height of its wrapped text.
+        if let Some(ratio) = aspect_ratio(store, *self) {
+            return Some(width / ratio);
+        }
+
         let width = width.ceil();
         if !store.text_wrap.get(*self).copied().unwrap_or(true) {
             return None;
@@ -160,6 +208,22 @@ impl<'w> Node<'w> for Entity {
     }
 
     fn height(&self, store: &Self::Data) -> Option<morphorm::Units> {
+        // An auto sized wrapping row is as tall as its lines.
+        if let Some(extent) = store.wrap_extents.get(*self) {
+            if store.layout_type.get(*self) == Some(&morphorm::LayoutType::Row)
+                && store.height.get(*self) == Some(&Units::Auto)
+            {
+                return Some(Units::Pixels(*extent));
+            }
+        }
+
+        // An auto height follows a fixed width when the view has an aspect ratio.
+        if let (Some(Units::Auto), Some(Units::Pixels(width)), Some(ratio)) =
+            (store.height.get(*self), store.width.get(*self), aspect_ratio(store, *self))
+        {
+            return Some(Units::Pixels(store.logical_to_physical(*width / ratio)));
+        }
+
         store.height.get(*self).cloned().map(|h| match h {
             Units::Pixels(val) => Units::Pixels(store.logical_to_physical(val)),
             t => t,
@@ -209,11 +273,23 @@ impl<'w> Node<'w> for Entity {
     }
 
     fn grid_cols(&self, store: &Self::Data) -> Option<Vec<morphorm::Units>> {
-        store.grid_cols.get(*self).cloned()
+        // Without explicit columns each column of the template areas shares the free space.
+        let cols = store.grid_cols.get(*self).cloned().or_else(|| {
+            store
+                .grid_template_areas
+                .get(*self)
+                .map(|areas| vec![Units::Stretch(1.0); areas.num_cols()])
+        })?;
+
+        Some(grid_tracks(store, cols, 0))
     }
 
     fn grid_rows(&self, store: &Self::Data) -> Option<Vec<morphorm::Units>> {
-        store.grid_rows.get(*self).cloned()
+        // Rows added by automatic placement share the free space with the template rows.
+        let num_rows = store.grid_row_counts.get(*self).copied().unwrap_or_default();
+        let rows = store.grid_rows.get(*self).cloned().or_else(|| (num_rows > 0).then(Vec::new))?;
+
+        Some(grid_tracks(store, rows, num_rows))
     }
 
     fn row_between(&self, store: &Self::Data) -> Option<morphorm::Units> {
@@ -259,18 +335,121 @@ impl<'w> Node<'w> for Entity {
     }
 
     fn row_index(&self, store: &Self::Data) -> Option<usize> {
+        if let Some(placement) = store.grid_placements.get(*self) {
+            return Some(placement.row_index);
+        }
+
         store.row_index.get(*self).cloned()
     }
 
     fn row_span(&self, store: &Self::Data) -> Option<usize> {
+        if let Some(placement) = store.grid_placements.get(*self) {
+            return Some(placement.row_span);
+        }
+
         store.row_span.get(*self).cloned()
     }
 
     fn col_index(&self, store: &Self::Data) -> Option<usize> {
+        if let Some(placement) = store.grid_placements.get(*self) {
+            return Some(placement.col_index);
+        }
+
         store.col_index.get(*self).cloned()
     }
 
     fn col_span(&self, store: &Self::Data) -> Option<usize> {
+        if let Some(placement) = store.grid_placements.get(*self) {
+            return Some(placement.col_span);
+        }
+
         store.col_span.get(*self).cloned()
     }
 }
+
+// Converts the pixel tracks of a grid to physical pixels, adding stretch tracks up to a minimum
+// count.
+fn grid_tracks(store: &Style, mut tracks: Vec<Units>, min_count: usize) -> Vec<Units> {
+    if tracks.len() < min_count {
+        tracks.resize(min_count, Units::Stretch(1.0));
+    }
+
+    tracks
+        .into_iter()
+        .map(|track| match track {
+            Units::Pixels(val) => Units::Pixels(store.logical_to_physical(val)),
+            t => t,
+        })
+        .collect()
+}
+
+// Returns the aspect ratio of a view, ignoring ratios which can't produce a size.
+fn aspect_ratio(store: &Style, entity: Entity) -> Option<f32> {
+    store.aspect_ratio.get(entity).copied().filter(|ratio| *ratio > 0.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::systems::layout_system;
+
+    // Lays out a view in a 400 by 300 window and returns its width and height.
+    fn layout_size(cx: &mut Context, entity: Entity) -> (f32, f32) {
+        cx.style.width.insert(Entity::root(), Units::Pixels(400.0));
+        cx.style.height.insert(Entity::root(), Units::Pixels(300.0));
+        cx.cache.set_width(Entity::root(), 400.0);
+        cx.cache.set_height(Entity::root(), 300.0);
+        cx.style.needs_relayout();
+        layout_system(cx);
+
+        let bounds = cx.cache.get_bounds(entity);
+        (bounds.w, bounds.h)
+    }
+
+    #[test]
+    fn test_aspect_ratio_from_fixed() {
+        let cx = &mut Context::default();
+        let entity = Element::new(cx).width(Pixels(100.0)).height(Auto).aspect_ratio(2.0).entity;
+        assert_eq!(layout_size(cx, entity), (100.0, 50.0));
+
+        let cx = &mut Context::default();
+        let entity = Element::new(cx).width(Auto).height(Pixels(100.0)).aspect_ratio(2.0).entity;
+        assert_eq!(layout_size(cx, entity), (200.0, 100.0));
+    }
+
+    #[test]
+    fn test_aspect_ratio_from_stretch() {
+        let cx = &mut Context::default();
+        let entity = Element::new(cx).width(Stretch(1.0)).height(Auto).aspect_ratio(4.0).entity;
+        let (width, height) = layout_size(cx, entity);
+        assert!(width > 0.0);
+        assert_eq!(height, width / 4.0);
+    }
+
+    // The aspect ratio takes precedence over the height of wrapped text.
+    #[test]
+    fn test_aspect_ratio_overrides_text() {
+        let cx = &mut Context::default();
+        let entity = Label::new(cx, "A label with enough text to wrap onto several lines")
+            .width(Stretch(1.0))
+            .height(Auto)
+            .aspect_ratio(8.0)
+            .entity;
+        let (width, height) = layout_size(cx, entity);
+        assert!(width > 0.0);
+        assert_eq!(height, width / 8.0);
+    }
+
+    // Pixel tracks are logical pixels, like every other pixel length, and are scaled to physical
+    // pixels for layout.
+    #[test]
+    fn test_grid_tracks_scale_pixels() {
+        let mut style = Style::default();
+        style.dpi_factor = 2.0;
+
+        assert_eq!(
+            grid_tracks(&style, vec![Units::Pixels(50.0), Units::Stretch(1.0)], 3),
+            vec![Units::Pixels(100.0), Units::Stretch(1.0), Units::Stretch(1.0)]
+        );
+    }
+}
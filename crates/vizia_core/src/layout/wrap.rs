@@ -0,0 +1,157 @@
+use morphorm::{LayoutType, PositionType, Units};
+
+use crate::cache::CachedData;
+use crate::prelude::*;
+use crate::style::{LayoutWrap, Style};
+use vizia_storage::{LayoutChildIterator, LayoutTreeIterator, SparseSet};
+
+/// Positions a sequence of items along a main axis, starting a new line whenever the next item
+/// would exceed the available space.
+///
+/// Each item is given as a `(main, cross)` size. Returns the `(main, cross)` offset of each item
+/// and the total cross size of all lines.
+pub(crate) fn wrap_lines(
+    items: &[(f32, f32)],
+    available: f32,
+    main_gap: f32,
+    cross_gap: f32,
+) -> (Vec<(f32, f32)>, f32) {
+    let mut offsets = Vec::with_capacity(items.len());
+
+    let mut main = 0.0;
+    let mut cross = 0.0;
+    let mut line_size: f32 = 0.0;
+
+    for (index, (item_main, item_cross)) in items.iter().enumerate() {
+        // The first item of a line is never wrapped, even if it doesn't fit.
+        if index != 0 && main + item_main > available {
+            main = 0.0;
+            cross += line_size + cross_gap;
+            line_size = 0.0;
+        }
+
+        offsets.push((main, cross));
+
+        main += item_main + main_gap;
+        line_size = line_size.max(*item_cross);
+    }
+
+    (offsets, cross + line_size)
+}
+
+fn pixels(style: &Style, units: Option<&Units>) -> f32 {
+    match units {
+        Some(Units::Pixels(val)) => style.logical_to_physical(*val),
+        _ => 0.0,
+    }
+}
+
+/// Computes the positions of the children of views with `layout-wrap: wrap` from the sizes of the
+/// last layout.
+///
+/// The children of a wrapping view are presented to morphorm as self-directed with a fixed offset,
+/// and a wrapping view with an auto cross size is given the size of its lines. Returns true if any
+/// position or size has changed, in which case layout needs to be performed again.
+pub(crate) fn wrap_system(cache: &CachedData, tree: &Tree<Entity>, style: &mut Style) -> bool {
+    let mut positions = SparseSet::new();
+    let mut extents = SparseSet::new();
+    let mut changed = false;
+
+    for parent in LayoutTreeIterator::full(tree) {
+        if style.layout_wrap.get(parent).copied().unwrap_or_default() != LayoutWrap::Wrap {
+            continue;
+        }
+
+        let layout_type = style.layout_type.get(parent).copied().unwrap_or(LayoutType::Column);
+        if layout_type == LayoutType::Grid {
+            continue;
+        }
+
+        let children = LayoutChildIterator::new(tree, parent)
+            .filter(|child| {
+                cache.get_display(*child) != Display::None
+                    && style.position_type.get(*child) != Some(&PositionType::SelfDirected)
+            })
+            .collect::<Vec<_>>();
+
+        let child_left = pixels(style, style.child_left.get(parent));
+        let child_right = pixels(style, style.child_right.get(parent));
+        let child_top = pixels(style, style.child_top.get(parent));
+        let child_bottom = pixels(style, style.child_bottom.get(parent));
+        let row_between = pixels(style, style.row_between.get(parent));
+        let col_between = pixels(style, style.col_between.get(parent));
+
+        // The outer size of each child, including any pixel spacing around it.
+        let spacing = children
+            .iter()
+            .map(|child| {
+                (
+                    pixels(style, style.left.get(*child)),
+                    pixels(style, style.right.get(*child)),
+                    pixels(style, style.top.get(*child)),
+                    pixels(style, style.bottom.get(*child)),
+                )
+            })
+            .collect::<Vec<_>>();
+        let sizes =
+            children.iter().zip(spacing.iter()).map(|(child, (left, right, top, bottom))| {
+                (cache.get_width(*child) + left + right, cache.get_height(*child) + top + bottom)
+            });
+
+        // Rows wrap horizontally with `col-between` between items and `row-between` between lines,
+        // while columns wrap vertically with the gaps swapped.
+        let (offsets, extent) = if layout_type == LayoutType::Row {
+            let available = cache.get_width(parent) - child_left - child_right;
+            let (offsets, extent) =
+                wrap_lines(&sizes.collect::<Vec<_>>(), available, col_between, row_between);
+            (offsets, extent + child_top + child_bottom)
+        } else {
+            let available = cache.get_height(parent) - child_top - child_bottom;
+            let items = sizes.map(|(width, height)| (height, width)).collect::<Vec<_>>();
+            let (offsets, extent) = wrap_lines(&items, available, row_between, col_between);
+            let offsets = offsets.into_iter().map(|(main, cross)| (cross, main)).collect();
+            (offsets, extent + child_left + child_right)
+        };
+
+        for ((child, (left, _, top, _)), (x, y)) in children.iter().zip(spacing).zip(offsets) {
+            let position = (child_left + x + left, child_top + y + top);
+            changed |= style.wrap_positions.get(*child) != Some(&position);
+            positions.insert(*child, position).unwrap();
+        }
+
+        changed |= style.wrap_extents.get(parent) != Some(&extent);
+        extents.insert(parent, extent).unwrap();
+    }
+
+    // Views which no longer wrap leave stale entries behind.
+    changed |= positions.dense.len() != style.wrap_positions.dense.len()
+        || extents.dense.len() != style.wrap_extents.dense.len();
+
+    style.wrap_positions = positions;
+    style.wrap_extents = extents;
+
+    changed
+}
+
+#[cfg(test)]
+mod tests {
+    use super::wrap_lines;
+
+    #[test]
+    fn test_wrap_lines() {
+        let items = [(40.0, 10.0), (40.0, 20.0), (40.0, 10.0)];
+        let (offsets, extent) = wrap_lines(&items, 100.0, 5.0, 2.0);
+
+        assert_eq!(offsets, vec![(0.0, 0.0), (45.0, 0.0), (0.0, 22.0)]);
+        assert_eq!(extent, 32.0);
+    }
+
+    #[test]
+    fn test_wrap_lines_oversized() {
+        let items = [(150.0, 10.0), (20.0, 10.0)];
+        let (offsets, extent) = wrap_lines(&items, 100.0, 0.0, 0.0);
+
+        assert_eq!(offsets, vec![(0.0, 0.0), (0.0, 10.0)]);
+        assert_eq!(extent, 20.0);
+    }
+}
@@ -46,13 +46,16 @@ pub mod prelude {
     };
     pub use super::entity::Entity;
     pub use super::environment::{Environment, EnvironmentEvent};
-    pub use super::events::{Event, Propagation};
+    pub use super::events::{
+        DragEvent, Event, EventPhase, Propagation, Timer, TimerEvent, DEFAULT_DRAG_THRESHOLD,
+        LONG_PRESS_DURATION,
+    };
     pub use super::handle::Handle;
     pub use super::input::{Keymap, KeymapEntry, KeymapEvent};
     pub use super::localization::Localized;
     pub use super::modifiers::{
-        AbilityModifiers, AccessibilityModifiers, ActionModifiers, LayoutModifiers, StyleModifiers,
-        TextModifiers,
+        AbilityModifiers, AccessibilityModifiers, ActionModifiers, ContextMenuModifiers,
+        DragModifiers, LayoutModifiers, StyleModifiers, TextModifiers, TooltipModifiers,
     };
     pub use super::state::{Binding, Data, Lens, LensExt, Model, OrLens, Res, Setter, Wrapper};
     pub use super::view::{Canvas, View};
@@ -61,13 +64,17 @@ pub mod prelude {
     pub use accesskit::{Action, DefaultActionVerb, Live, Role};
     pub use vizia_derive::{Data, Lens, Model, Setter};
     pub use vizia_id::GenerationalId;
-    pub use vizia_input::{Code, Key, KeyChord, Modifiers, MouseButton, MouseButtonState};
+    pub use vizia_input::{
+        Code, GesturePhase, Key, KeyChord, Modifiers, MouseButton, MouseButtonState, Touch,
+        TouchState,
+    };
     pub use vizia_storage::{Tree, TreeExt};
     pub use vizia_window::{CursorIcon, WindowDescription, WindowEvent, WindowSize};
 
     pub use super::style::{
         Abilities, BorderCornerShape, Color, Display, GradientDirection, GradientStop,
-        LinearGradient, Opacity, Overflow, PseudoClass, Visibility,
+        GridTemplateAreas, LayoutDirection, LayoutWrap, LinearGradient, Opacity, Overflow,
+        PseudoClass, Visibility,
     };
 
     pub use cosmic_text::{FamilyOwned, Style as FontStyle, Weight};
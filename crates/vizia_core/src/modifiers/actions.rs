@@ -3,6 +3,7 @@ use crate::{cache::CachedData, prelude::*};
 use std::{
     any::{Any, TypeId},
     marker::PhantomData,
+    path::PathBuf,
 };
 
 pub struct EventHandle<'a, 'b, V> {
@@ -114,6 +115,12 @@ pub(crate) struct ActionsModel<V> {
     pub(crate) on_focus_out: Option<Box<dyn Fn(&mut EventHandle<V>) + Send + Sync>>,
     pub(crate) on_geo_changed:
         Option<Box<dyn Fn(&mut EventHandle<V>, GeometryChanged) + Send + Sync>>,
+    pub(crate) on_file_drop: Option<Box<dyn Fn(&mut EventHandle<V>, PathBuf) + Send + Sync>>,
+    pub(crate) on_pan:
+        Option<Box<dyn Fn(&mut EventHandle<V>, f32, f32, GesturePhase) + Send + Sync>>,
+    pub(crate) on_pinch: Option<Box<dyn Fn(&mut EventHandle<V>, f32, GesturePhase) + Send + Sync>>,
+    pub(crate) on_rotate: Option<Box<dyn Fn(&mut EventHandle<V>, f32, GesturePhase) + Send + Sync>>,
+    pub(crate) on_long_press: Option<Box<dyn Fn(&mut EventHandle<V>) + Send + Sync>>,
 }
 
 impl<V> ActionsModel<V> {
@@ -132,6 +139,11 @@ impl<V> ActionsModel<V> {
             on_focus_in: None,
             on_focus_out: None,
             on_geo_changed: None,
+            on_file_drop: None,
+            on_pan: None,
+            on_pinch: None,
+            on_rotate: None,
+            on_long_press: None,
         }
     }
 }
@@ -190,6 +202,26 @@ impl<V: 'static> Model for ActionsModel<V> {
             ActionsEvent::OnGeoChanged(on_geo_changed) => {
                 self.on_geo_changed = Some(on_geo_changed);
             }
+
+            ActionsEvent::OnFileDrop(on_file_drop) => {
+                self.on_file_drop = Some(on_file_drop);
+            }
+
+            ActionsEvent::OnPan(on_pan) => {
+                self.on_pan = Some(on_pan);
+            }
+
+            ActionsEvent::OnPinch(on_pinch) => {
+                self.on_pinch = Some(on_pinch);
+            }
+
+            ActionsEvent::OnRotate(on_rotate) => {
+                self.on_rotate = Some(on_rotate);
+            }
+
+            ActionsEvent::OnLongPress(on_long_press) => {
+                self.on_long_press = Some(on_long_press);
+            }
         });
 
         event.map(|window_event, meta| match window_event {
@@ -290,6 +322,43 @@ impl<V: 'static> Model for ActionsModel<V> {
                 }
             }
 
+            WindowEvent::FileDropped(path) => {
+                if let Some(action) = &self.on_file_drop {
+                    (action)(&mut EventHandle::<V>::new(cx), path.clone());
+                    meta.consume();
+                }
+            }
+
+            WindowEvent::Pan(x, y, phase) => {
+                if let Some(action) = &self.on_pan {
+                    (action)(&mut EventHandle::<V>::new(cx), *x, *y, *phase);
+                    meta.consume();
+                }
+            }
+
+            WindowEvent::Magnify(delta, phase) => {
+                if let Some(action) = &self.on_pinch {
+                    (action)(&mut EventHandle::<V>::new(cx), *delta, *phase);
+                    meta.consume();
+                }
+            }
+
+            WindowEvent::Rotate(delta, phase) => {
+                if let Some(action) = &self.on_rotate {
+                    (action)(&mut EventHandle::<V>::new(cx), *delta, *phase);
+                    meta.consume();
+                }
+            }
+
+            WindowEvent::LongPress => {
+                if let Some(action) = &self.on_long_press {
+                    if cx.handle_long_press() {
+                        (action)(&mut EventHandle::<V>::new(cx));
+                    }
+                    meta.consume();
+                }
+            }
+
             _ => {}
         });
     }
@@ -309,6 +378,11 @@ pub(crate) enum ActionsEvent<V> {
     OnFocusIn(Box<dyn Fn(&mut EventHandle<V>) + Send + Sync>),
     OnFocusOut(Box<dyn Fn(&mut EventHandle<V>) + Send + Sync>),
     OnGeoChanged(Box<dyn Fn(&mut EventHandle<V>, GeometryChanged) + Send + Sync>),
+    OnFileDrop(Box<dyn Fn(&mut EventHandle<V>, PathBuf) + Send + Sync>),
+    OnPan(Box<dyn Fn(&mut EventHandle<V>, f32, f32, GesturePhase) + Send + Sync>),
+    OnPinch(Box<dyn Fn(&mut EventHandle<V>, f32, GesturePhase) + Send + Sync>),
+    OnRotate(Box<dyn Fn(&mut EventHandle<V>, f32, GesturePhase) + Send + Sync>),
+    OnLongPress(Box<dyn Fn(&mut EventHandle<V>) + Send + Sync>),
 }
 
 /// Modifiers which add an action callback to a view.
@@ -478,6 +552,79 @@ pub trait ActionModifiers<V> {
     fn on_geo_changed<F>(self, action: F) -> Self
     where
         F: 'static + Fn(&mut EventHandle<V>, GeometryChanged) + Send + Sync;
+
+    /// Adds a callback which is performed when a file from another application is dropped onto
+    /// the view. The callback is performed once for each dropped file, and ancestors of the view
+    /// with the same callback do not receive the file.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use vizia_core::prelude::*;
+    /// # let mut cx = &mut Context::default();
+    /// Element::new(cx).on_file_drop(|_, path| println!("File dropped: {}", path.display()));
+    /// ```
+    fn on_file_drop<F>(self, action: F) -> Self
+    where
+        F: 'static + Fn(&mut EventHandle<V>, PathBuf) + Send + Sync;
+
+    /// Adds a callback which is performed when the view receives the [`Pan`](crate::prelude::WindowEvent::Pan) event,
+    /// with the horizontal and vertical movement in physical pixels and the phase of the pan.
+    /// Only the nearest view with this callback to the pressed view receives the pan, and the pan
+    /// is cancelled when another view captures the mouse, such as a slider which is dragged.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use vizia_core::prelude::*;
+    /// # let mut cx = &mut Context::default();
+    /// Element::new(cx).on_pan(|_, x, y, _phase| println!("View panned by: {} {}", x, y));
+    /// ```
+    fn on_pan<F>(self, action: F) -> Self
+    where
+        F: 'static + Fn(&mut EventHandle<V>, f32, f32, GesturePhase) + Send + Sync;
+
+    /// Adds a callback which is performed when the view receives the [`Magnify`](crate::prelude::WindowEvent::Magnify) event,
+    /// with the change in scale and the phase of the pinch.
+    /// Ancestors of the view with the same callback do not receive the pinch.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use vizia_core::prelude::*;
+    /// # let mut cx = &mut Context::default();
+    /// Element::new(cx).on_pinch(|_, delta, _phase| println!("View zoomed by: {}", 1.0 + delta));
+    /// ```
+    fn on_pinch<F>(self, action: F) -> Self
+    where
+        F: 'static + Fn(&mut EventHandle<V>, f32, GesturePhase) + Send + Sync;
+
+    /// Adds a callback which is performed when the view receives the [`Rotate`](crate::prelude::WindowEvent::Rotate) event,
+    /// with the counterclockwise rotation in degrees and the phase of the rotation.
+    /// Ancestors of the view with the same callback do not receive the rotation.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use vizia_core::prelude::*;
+    /// # let mut cx = &mut Context::default();
+    /// Element::new(cx).on_rotate(|_, degrees, _phase| println!("View rotated by: {}", degrees));
+    /// ```
+    fn on_rotate<F>(self, action: F) -> Self
+    where
+        F: 'static + Fn(&mut EventHandle<V>, f32, GesturePhase) + Send + Sync;
+
+    /// Adds a callback which is performed when the left mouse button or a touch is held in place
+    /// on the view for [`LONG_PRESS_DURATION`](crate::prelude::LONG_PRESS_DURATION).
+    /// Only the nearest view with this callback to the pressed view receives the long press, and
+    /// it is ignored when another view has captured the mouse. The view is not pressed when the
+    /// mouse button or touch is then released.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use vizia_core::prelude::*;
+    /// # let mut cx = &mut Context::default();
+    /// Element::new(cx).on_long_press(|_| println!("View was long pressed!"));
+    /// ```
+    fn on_long_press<F>(self, action: F) -> Self
+    where
+        F: 'static + Fn(&mut EventHandle<V>) + Send + Sync;
 }
 
 // If the entity doesn't have an `ActionsModel` then add one to the entity
@@ -689,4 +836,81 @@ impl<'a, V: View> ActionModifiers<V> for Handle<'a, V> {
 
         self
     }
+
+    fn on_file_drop<F>(self, action: F) -> Self
+    where
+        F: 'static + Fn(&mut EventHandle<V>, PathBuf) + Send + Sync,
+    {
+        build_action_model::<V>(self.cx, self.entity);
+
+        self.cx.emit_custom(
+            Event::new(ActionsEvent::OnFileDrop(Box::new(action)))
+                .target(self.entity)
+                .origin(self.entity),
+        );
+
+        self
+    }
+
+    fn on_pan<F>(self, action: F) -> Self
+    where
+        F: 'static + Fn(&mut EventHandle<V>, f32, f32, GesturePhase) + Send + Sync,
+    {
+        build_action_model::<V>(self.cx, self.entity);
+        self.cx.gestures.pan_targets.insert(self.entity);
+
+        self.cx.emit_custom(
+            Event::new(ActionsEvent::OnPan(Box::new(action)))
+                .target(self.entity)
+                .origin(self.entity),
+        );
+
+        self
+    }
+
+    fn on_pinch<F>(self, action: F) -> Self
+    where
+        F: 'static + Fn(&mut EventHandle<V>, f32, GesturePhase) + Send + Sync,
+    {
+        build_action_model::<V>(self.cx, self.entity);
+
+        self.cx.emit_custom(
+            Event::new(ActionsEvent::OnPinch(Box::new(action)))
+                .target(self.entity)
+                .origin(self.entity),
+        );
+
+        self
+    }
+
+    fn on_rotate<F>(self, action: F) -> Self
+    where
+        F: 'static + Fn(&mut EventHandle<V>, f32, GesturePhase) + Send + Sync,
+    {
+        build_action_model::<V>(self.cx, self.entity);
+
+        self.cx.emit_custom(
+            Event::new(ActionsEvent::OnRotate(Box::new(action)))
+                .target(self.entity)
+                .origin(self.entity),
+        );
+
+        self
+    }
+
+    fn on_long_press<F>(self, action: F) -> Self
+    where
+        F: 'static + Fn(&mut EventHandle<V>) + Send + Sync,
+    {
+        build_action_model::<V>(self.cx, self.entity);
+        self.cx.gestures.long_press_targets.insert(self.entity);
+
+        self.cx.emit_custom(
+            Event::new(ActionsEvent::OnLongPress(Box::new(action)))
+                .target(self.entity)
+                .origin(self.entity),
+        );
+
+        self
+    }
 }
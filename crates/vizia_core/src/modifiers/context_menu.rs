@@ -0,0 +1,40 @@
+use super::internal;
+use crate::prelude::*;
+
+/// Modifiers for adding a context menu to a view.
+pub trait ContextMenuModifiers: internal::Modifiable {
+    /// Adds a context menu to the view which opens when the view, or one of its descendants, is
+    /// right clicked or receives the menu key or Shift+F10.
+    ///
+    /// The content is built into a vertical menu stack in the window of the view each time the
+    /// menu opens, so lenses in the content are resolved from the window. It can contain any menu
+    /// entries, including [`MenuButton`]s and nested [`Menu`]s. The entries can be navigated with
+    /// the arrow keys and pressed with enter or space.
+    ///
+    /// Events which are emitted by the content and not handled within the menu, such as those
+    /// emitted by the actions of menu buttons, continue up the tree from the view, so models of the
+    /// view and its ancestors receive them.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use vizia_core::prelude::*;
+    /// # let mut cx = &mut Context::default();
+    /// Label::new(cx, "Right click me!").context_menu(|cx| {
+    ///     MenuButton::new_simple(cx, "Copy", |_| println!("Copy"));
+    ///     MenuButton::new_simple(cx, "Paste", |_| println!("Paste"));
+    /// });
+    /// ```
+    fn context_menu<F>(mut self, content: F) -> Self
+    where
+        F: 'static + Fn(&mut Context),
+    {
+        let entity = self.entity();
+        self.context().with_current(entity, |cx| {
+            ContextMenu::new(cx, content);
+        });
+
+        self
+    }
+}
+
+impl<'a, V> ContextMenuModifiers for Handle<'a, V> {}
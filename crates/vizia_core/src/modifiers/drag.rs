@@ -0,0 +1,123 @@
+use std::any::Any;
+
+use super::internal;
+use crate::events::{DragSource, DropTarget};
+use crate::prelude::*;
+
+/// Modifiers for dragging views and dropping them onto other views.
+pub trait DragModifiers: internal::Modifiable {
+    /// Makes the view draggable with the left mouse button, carrying a copy of the given payload.
+    ///
+    /// A drag starts once the cursor has moved further than the drag threshold of the view, at
+    /// which point the view receives a [`DragEvent::DragStart`] event. Releasing the mouse button
+    /// over a drop target which accepts the payload drops it there, while pressing escape cancels
+    /// the drag. Either way the view then receives a [`DragEvent::DragEnd`] event.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use vizia_core::prelude::*;
+    /// # let mut cx = &mut Context::default();
+    /// Label::new(cx, "Preset 1").draggable(1usize);
+    /// ```
+    fn draggable<T: 'static + Clone + Send>(mut self, payload: T) -> Self {
+        let entity = self.entity();
+        let cx = self.context();
+
+        let payload: Box<dyn Fn() -> Box<dyn Any + Send>> =
+            Box::new(move || Box::new(payload.clone()) as Box<dyn Any + Send>);
+
+        if let Some(source) = cx.drag_drop.sources.get_mut(&entity) {
+            source.payload = payload;
+        } else {
+            cx.drag_drop.sources.insert(
+                entity,
+                DragSource { payload, preview: None, threshold: DEFAULT_DRAG_THRESHOLD },
+            );
+        }
+
+        self
+    }
+
+    /// Sets the distance in logical pixels which the cursor must move before a drag of the view
+    /// starts. Defaults to [`DEFAULT_DRAG_THRESHOLD`].
+    ///
+    /// This modifier has no effect unless the view has been made draggable first.
+    fn drag_threshold(mut self, threshold: f32) -> Self {
+        let entity = self.entity();
+        if let Some(source) = self.context().drag_drop.sources.get_mut(&entity) {
+            source.threshold = threshold;
+        }
+
+        self
+    }
+
+    /// Sets the content of a preview which follows the cursor while the view is dragged.
+    ///
+    /// The content is built into a `drag-preview` element when the drag starts and removed when
+    /// it ends. This modifier has no effect unless the view has been made draggable first.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use vizia_core::prelude::*;
+    /// # let mut cx = &mut Context::default();
+    /// Label::new(cx, "Preset 1")
+    ///     .draggable(1usize)
+    ///     .drag_preview(|cx| {
+    ///         Label::new(cx, "Preset 1");
+    ///     });
+    /// ```
+    fn drag_preview<F>(mut self, content: F) -> Self
+    where
+        F: 'static + Fn(&mut Context),
+    {
+        let entity = self.entity();
+        if let Some(source) = self.context().drag_drop.sources.get_mut(&entity) {
+            source.preview = Some(Box::new(content));
+        }
+
+        self
+    }
+
+    /// Makes the view a drop target for payloads of type `T` for which `accepts` returns true.
+    ///
+    /// While an accepted payload is dragged over the view it matches the `:drag-over`
+    /// pseudo-class and receives [`DragEvent::DragEnter`] and [`DragEvent::DragLeave`] events.
+    /// When the payload is dropped, `on_drop` is called with it and the view receives a
+    /// [`DragEvent::Drop`] event.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use vizia_core::prelude::*;
+    /// # let mut cx = &mut Context::default();
+    /// Element::new(cx).drop_target(
+    ///     |preset: &usize| *preset < 8,
+    ///     |_, preset: usize| println!("Preset {} dropped!", preset),
+    /// );
+    /// ```
+    fn drop_target<T, A, F>(mut self, accepts: A, on_drop: F) -> Self
+    where
+        T: 'static,
+        A: 'static + Fn(&T) -> bool,
+        F: 'static + Fn(&mut EventContext, T),
+    {
+        let entity = self.entity();
+
+        self.context().drag_drop.targets.insert(
+            entity,
+            DropTarget {
+                accepts: Box::new(move |payload| {
+                    payload.downcast_ref::<T>().map_or(false, |payload| (accepts)(payload))
+                }),
+                on_drop: Box::new(move |cx, payload| {
+                    if let Ok(payload) = payload.downcast::<T>() {
+                        (on_drop)(cx, *payload);
+                    }
+                }),
+            },
+        );
+
+        self
+    }
+}
+
+impl<'a, V> DragModifiers for Handle<'a, V> {}
@@ -57,6 +57,52 @@ pub trait LayoutModifiers: internal::Modifiable {
         SystemFlags::RELAYOUT
     );
 
+    modifier!(
+        /// Sets the layout direction of the view.
+        ///
+        /// The direction is inherited by descendants and defaults to the direction of the current locale.
+        ///
+        /// - `LayoutDirection::LeftToRight` - Children of a row are placed from left to right.
+        /// - `LayoutDirection::RightToLeft` - The horizontal layout of the children is mirrored, so the first
+        /// child of a row is placed on the right and left/right spacing is swapped.
+        ///
+        /// # Example
+        /// ```
+        /// # use vizia_core::prelude::*;
+        /// # let cx = &mut Context::default();
+        /// HStack::new(cx, |_| {}).direction(LayoutDirection::RightToLeft);
+        /// ```
+        direction,
+        LayoutDirection,
+        SystemFlags::RELAYOUT | SystemFlags::REDRAW
+    );
+
+    modifier!(
+        /// Sets whether the children of a row or column wrap onto new lines when they don't fit.
+        ///
+        /// Wrapped lines are separated by the `row_between` spacing of a row or the `col_between` spacing
+        /// of a column, while children within a line are separated by the other. A wrapping view with an
+        /// `Auto` height (row) or width (column) is sized to fit its lines.
+        ///
+        /// # Example
+        /// ```
+        /// # use vizia_core::prelude::*;
+        /// # let cx = &mut Context::default();
+        /// HStack::new(cx, |cx| {
+        ///     for tag in ["audio", "midi", "synth", "effect"] {
+        ///         Label::new(cx, tag).width(Pixels(60.0));
+        ///     }
+        /// })
+        /// .layout_wrap(LayoutWrap::Wrap)
+        /// .height(Auto)
+        /// .col_between(Pixels(4.0))
+        /// .row_between(Pixels(4.0));
+        /// ```
+        layout_wrap,
+        LayoutWrap,
+        SystemFlags::RELAYOUT
+    );
+
     modifier!(
         /// Sets the space on the left side of the view.
         ///
@@ -309,6 +355,25 @@ pub trait LayoutModifiers: internal::Modifiable {
         self
     }
 
+    modifier!(
+        /// Sets the ratio of the width to the height of the view.
+        ///
+        /// When the width or the height of the view is `Auto`, it is computed from the other dimension
+        /// during layout and is still limited by the minimum and maximum size constraints. An
+        /// `Auto` height computed from the ratio takes precedence over the height of wrapped text.
+        ///
+        /// # Example
+        /// ```
+        /// # use vizia_core::prelude::*;
+        /// # let cx = &mut Context::default();
+        /// // A square view which fills the height of its parent.
+        /// Element::new(cx).height(Stretch(1.0)).width(Auto).aspect_ratio(1.0);
+        /// ```
+        aspect_ratio,
+        f32,
+        SystemFlags::RELAYOUT
+    );
+
     modifier!(
         /// Sets the minimum left space of the view.
         min_left,
@@ -397,7 +462,7 @@ pub trait LayoutModifiers: internal::Modifiable {
         self
     }
 
-    /// Sets the grid rows of the view.
+    /// Sets the grid rows of the view. `Pixels` rows are in logical pixels.
     fn grid_rows(mut self, rows: Vec<Units>) -> Self {
         let entity = self.entity();
         self.context().style.grid_rows.insert(entity, rows);
@@ -405,7 +470,7 @@ pub trait LayoutModifiers: internal::Modifiable {
         self
     }
 
-    /// Sets the grid columns of the view.
+    /// Sets the grid columns of the view. `Pixels` columns are in logical pixels.
     fn grid_cols(mut self, cols: Vec<Units>) -> Self {
         let entity = self.entity();
         self.context().style.grid_cols.insert(entity, cols);
@@ -417,6 +482,7 @@ pub trait LayoutModifiers: internal::Modifiable {
         /// Sets the grid row index of the view.
         ///
         /// This index relates to the grid rows of the parent view when the parent layout type is set to `Grid`.
+        /// Views without a row or column index are placed into the next free cell in source order.
         row_index,
         usize,
         SystemFlags::RELAYOUT
@@ -433,6 +499,7 @@ pub trait LayoutModifiers: internal::Modifiable {
         /// Sets the grid column index of the view.
         ///
         /// This index relates to the grid columns of the parent view when the parent layout type is set to `Grid`.
+        /// Views without a row or column index are placed into the next free cell in source order.
         col_index,
         usize,
         SystemFlags::RELAYOUT
@@ -445,6 +512,41 @@ pub trait LayoutModifiers: internal::Modifiable {
         usize,
         SystemFlags::RELAYOUT
     );
+
+    /// Sets the named areas of a grid view.
+    ///
+    /// Children are placed into an area with [`grid_area`](crate::prelude::LayoutModifiers::grid_area).
+    /// If the grid rows or columns are not set then each row and column of the template stretches
+    /// to share the available space.
+    ///
+    /// # Example
+    /// ```
+    /// # use vizia_core::prelude::*;
+    /// # let cx = &mut Context::default();
+    /// VStack::new(cx, |cx| {
+    ///     Element::new(cx).grid_area("header");
+    ///     Element::new(cx).grid_area("sidebar");
+    ///     Element::new(cx).grid_area("main");
+    /// })
+    /// .layout_type(LayoutType::Grid)
+    /// .grid_template_areas(["header header", "sidebar main"]);
+    /// ```
+    fn grid_template_areas(mut self, areas: impl Into<GridTemplateAreas>) -> Self {
+        let entity = self.entity();
+        self.context().style.grid_template_areas.insert(entity, areas.into());
+        self.context().needs_relayout();
+        self
+    }
+
+    /// Sets the named area of the parent grid which the view occupies.
+    ///
+    /// The area takes precedence over the row and column indices of the view.
+    fn grid_area(mut self, name: impl Into<String>) -> Self {
+        let entity = self.entity();
+        self.context().style.grid_area.insert(entity, name.into());
+        self.context().needs_relayout();
+        self
+    }
 }
 
 impl<'a, V: View> LayoutModifiers for Handle<'a, V> {}
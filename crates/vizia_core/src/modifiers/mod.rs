@@ -48,6 +48,12 @@ pub use accessibility::*;
 mod actions;
 pub use actions::*;
 
+mod context_menu;
+pub use context_menu::*;
+
+mod drag;
+pub use drag::*;
+
 mod layout;
 pub use layout::*;
 
@@ -57,5 +63,8 @@ pub use style::*;
 mod text;
 pub use text::*;
 
+mod tooltip;
+pub use tooltip::*;
+
 mod abilities;
 pub use abilities::*;
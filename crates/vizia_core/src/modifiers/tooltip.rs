@@ -0,0 +1,59 @@
+use super::internal;
+use crate::prelude::*;
+
+/// Modifiers for adding a tooltip to a view.
+pub trait TooltipModifiers: internal::Modifiable {
+    /// Adds a tooltip to the view which is shown after the cursor has rested over the view for
+    /// [`TOOLTIP_DELAY`], and is hidden when the cursor leaves the view or the view is pressed or
+    /// scrolled.
+    ///
+    /// The content is built into a `tooltip` element in the window of the view each time the
+    /// tooltip is shown, so lenses in the content are resolved from the window. Use
+    /// [`tooltip_text`](TooltipModifiers::tooltip_text) instead for a tooltip which only contains
+    /// text, which is also used as the accessibility description of the view.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use vizia_core::prelude::*;
+    /// # let mut cx = &mut Context::default();
+    /// Button::new(cx, |_| {}, |cx| Label::new(cx, "Save")).tooltip(|cx| {
+    ///     Label::new(cx, "Saves the current document");
+    /// });
+    /// ```
+    fn tooltip<F>(mut self, content: F) -> Self
+    where
+        F: 'static + Fn(&mut Context),
+    {
+        let entity = self.entity();
+        self.context().with_current(entity, |cx| {
+            Tooltip::new(cx, content);
+        });
+
+        self
+    }
+
+    /// Adds a tooltip containing text to the view, and sets the text as the accessibility
+    /// description of the view.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use vizia_core::prelude::*;
+    /// # let mut cx = &mut Context::default();
+    /// Button::new(cx, |_| {}, |cx| Label::new(cx, "Save"))
+    ///     .tooltip_text("Saves the current document");
+    /// ```
+    fn tooltip_text<U: ToString>(mut self, text: impl Res<U>) -> Self {
+        let entity = self.entity();
+        text.set_or_bind(self.context(), entity, |cx, id, text| {
+            cx.style.tooltip.insert(id, text.to_string());
+            cx.style.needs_access_update(id);
+        });
+
+        self.tooltip(move |cx| {
+            let text = cx.style.tooltip.get(entity).cloned().unwrap_or_default();
+            Label::new(cx, &text);
+        })
+    }
+}
+
+impl<'a, V> TooltipModifiers for Handle<'a, V> {}
@@ -301,6 +301,18 @@ impl Data for morphorm::PositionType {
     }
 }
 
+impl Data for LayoutDirection {
+    fn same(&self, other: &Self) -> bool {
+        *self == *other
+    }
+}
+
+impl Data for LayoutWrap {
+    fn same(&self, other: &Self) -> bool {
+        *self == *other
+    }
+}
+
 impl Data for Color {
     fn same(&self, other: &Self) -> bool {
         *self == *other
@@ -1,7 +1,9 @@
 use std::any::TypeId;
+use std::cell::Cell;
 use std::fmt::{Debug, Formatter};
 use std::marker::PhantomData;
 use std::ops::{BitAnd, BitOr, Deref};
+use std::rc::Rc;
 
 use crate::prelude::*;
 
@@ -255,6 +257,100 @@ where
     }
 }
 
+/// A lens to the item of a `Vec` with a particular key, which continues to refer to the same item
+/// when other items are inserted, removed or moved. Used by keyed lists, see
+/// [`List::new_keyed`](crate::views::List::new_keyed).
+pub struct KeyedIndex<T, K> {
+    key: K,
+    // The last known index of the item, which is checked before searching for the key.
+    index: Rc<Cell<usize>>,
+    key_fn: Rc<dyn Fn(&T) -> K>,
+}
+
+impl<T, K: PartialEq> KeyedIndex<T, K> {
+    pub(crate) fn new(key: K, index: Rc<Cell<usize>>, key_fn: Rc<dyn Fn(&T) -> K>) -> Self {
+        Self { key, index, key_fn }
+    }
+
+    /// Returns the key of the item.
+    pub fn key(&self) -> &K {
+        &self.key
+    }
+
+    /// Returns the last known index of the item.
+    pub fn idx(&self) -> usize {
+        self.index.get()
+    }
+}
+
+impl<T, K: Clone> Clone for KeyedIndex<T, K> {
+    fn clone(&self) -> Self {
+        Self { key: self.key.clone(), index: self.index.clone(), key_fn: self.key_fn.clone() }
+    }
+}
+
+impl<T: 'static, K: 'static + Clone + PartialEq> Lens for KeyedIndex<T, K> {
+    type Source = Vec<T>;
+    type Target = T;
+
+    fn view<O, F: FnOnce(Option<&Self::Target>) -> O>(&self, source: &Self::Source, map: F) -> O {
+        let index = self.index.get();
+        if source.get(index).map_or(false, |item| (self.key_fn)(item) == self.key) {
+            return map(source.get(index));
+        }
+
+        match source.iter().position(|item| (self.key_fn)(item) == self.key) {
+            Some(index) => {
+                self.index.set(index);
+                map(source.get(index))
+            }
+
+            None => map(None),
+        }
+    }
+}
+
+/// A lens to a node of a tree, given by the path of indices from the root items of the tree to the
+/// node and a function which returns the children of a node. Used by tree views, see
+/// [`TreeView`](crate::views::TreeView).
+pub struct TreeIndex<T> {
+    path: Vec<usize>,
+    children: Rc<dyn Fn(&T) -> &Vec<T>>,
+}
+
+impl<T> TreeIndex<T> {
+    pub fn new(path: Vec<usize>, children: Rc<dyn Fn(&T) -> &Vec<T>>) -> Self {
+        Self { path, children }
+    }
+
+    /// Returns the path of indices from the root items of the tree to the node.
+    pub fn path(&self) -> &[usize] {
+        &self.path
+    }
+}
+
+impl<T> Clone for TreeIndex<T> {
+    fn clone(&self) -> Self {
+        Self { path: self.path.clone(), children: self.children.clone() }
+    }
+}
+
+impl<T: 'static> Lens for TreeIndex<T> {
+    type Source = Vec<T>;
+    type Target = T;
+
+    fn view<O, F: FnOnce(Option<&Self::Target>) -> O>(&self, source: &Self::Source, map: F) -> O {
+        let mut items = Some(source);
+        let mut node = None;
+        for index in self.path.iter() {
+            node = items.and_then(|items| items.get(*index));
+            items = node.map(|node| (self.children)(node));
+        }
+
+        map(node)
+    }
+}
+
 pub struct StaticLens<T: 'static> {
     data: &'static T,
 }
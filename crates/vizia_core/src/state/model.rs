@@ -109,12 +109,21 @@ pub trait Model: 'static + Sized {
     /// ```
     #[allow(unused_variables)]
     fn event(&mut self, cx: &mut EventContext, event: &mut Event) {}
+
+    /// Receives events travelling down the tree to a descendant of the entity the model is built
+    /// into, before the descendant receives them. Consuming an event here prevents it from reaching
+    /// the target.
+    #[allow(unused_variables)]
+    fn capture_event(&mut self, cx: &mut EventContext, event: &mut Event) {}
 }
 
 pub(crate) trait ModelData: Any {
     #[allow(unused_variables)]
     fn event(&mut self, cx: &mut EventContext, event: &mut Event) {}
 
+    #[allow(unused_variables)]
+    fn capture_event(&mut self, cx: &mut EventContext, event: &mut Event) {}
+
     fn as_any_ref(&self) -> &dyn Any;
 }
 
@@ -129,6 +138,10 @@ impl<T: Model> ModelData for T {
         <T as Model>::event(self, cx, event);
     }
 
+    fn capture_event(&mut self, cx: &mut EventContext, event: &mut Event) {
+        <T as Model>::capture_event(self, cx, event);
+    }
+
     fn as_any_ref(&self) -> &dyn Any {
         self
     }
@@ -49,6 +49,8 @@ impl_res_simple!(f32);
 impl_res_simple!(f64);
 impl_res_simple!(CursorIcon);
 impl_res_simple!(Overflow);
+impl_res_simple!(LayoutDirection);
+impl_res_simple!(LayoutWrap);
 impl_res_simple!(Weight);
 impl_res_simple!(FontStyle);
 impl_res_simple!((u32, u32));
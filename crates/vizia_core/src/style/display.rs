@@ -1,6 +1,7 @@
 use crate::animation::Interpolator;
 use crate::entity::Entity;
 use std::fmt::Formatter;
+use unic_langid::LanguageIdentifier;
 use vizia_id::GenerationalId;
 
 /// Display determines whether an entity will be rendered and acted on by the layout system.
@@ -187,3 +188,121 @@ impl Default for BorderCornerShape {
         BorderCornerShape::Round
     }
 }
+
+/// Determines the direction in which content flows horizontally.
+///
+/// A right-to-left view mirrors the horizontal layout of its children, so the first child of a
+/// row is placed on the right and `left`/`right` spacing is swapped. The direction is inherited
+/// by descendants and defaults to the direction of the current locale.
+///
+/// This type is part of the prelude.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum LayoutDirection {
+    LeftToRight,
+    RightToLeft,
+}
+
+impl LayoutDirection {
+    /// Returns the writing direction used by the script of the given locale.
+    pub fn from_locale(locale: &LanguageIdentifier) -> Self {
+        if let Some(script) = locale.script {
+            return match script.as_str() {
+                "Arab" | "Hebr" | "Thaa" | "Syrc" | "Nkoo" | "Adlm" | "Rohg" => {
+                    LayoutDirection::RightToLeft
+                }
+                _ => LayoutDirection::LeftToRight,
+            };
+        }
+
+        match locale.language.as_str() {
+            "ar" | "arc" | "ckb" | "dv" | "fa" | "he" | "ks" | "ku" | "ps" | "sd" | "ug" | "ur"
+            | "yi" => LayoutDirection::RightToLeft,
+            _ => LayoutDirection::LeftToRight,
+        }
+    }
+
+    /// Returns true if the direction is right-to-left.
+    pub fn is_rtl(&self) -> bool {
+        *self == LayoutDirection::RightToLeft
+    }
+}
+
+impl std::fmt::Display for LayoutDirection {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                LayoutDirection::LeftToRight => "ltr",
+                LayoutDirection::RightToLeft => "rtl",
+            }
+        )
+    }
+}
+
+impl Default for LayoutDirection {
+    fn default() -> Self {
+        LayoutDirection::LeftToRight
+    }
+}
+
+/// Determines whether the children of a row or column flow onto new lines when they don't fit.
+///
+/// This type is part of the prelude.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum LayoutWrap {
+    NoWrap,
+    Wrap,
+}
+
+impl std::fmt::Display for LayoutWrap {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                LayoutWrap::NoWrap => "no-wrap",
+                LayoutWrap::Wrap => "wrap",
+            }
+        )
+    }
+}
+
+impl Default for LayoutWrap {
+    fn default() -> Self {
+        LayoutWrap::NoWrap
+    }
+}
+
+impl From<bool> for LayoutWrap {
+    fn from(val: bool) -> Self {
+        if val {
+            LayoutWrap::Wrap
+        } else {
+            LayoutWrap::NoWrap
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::LayoutDirection;
+    use unic_langid::LanguageIdentifier;
+
+    fn direction(locale: &str) -> LayoutDirection {
+        LayoutDirection::from_locale(&locale.parse::<LanguageIdentifier>().unwrap())
+    }
+
+    #[test]
+    fn test_direction_from_locale() {
+        assert_eq!(direction("en-US"), LayoutDirection::LeftToRight);
+        assert_eq!(direction("ar-EG"), LayoutDirection::RightToLeft);
+        assert_eq!(direction("he"), LayoutDirection::RightToLeft);
+    }
+
+    #[test]
+    fn test_direction_from_locale_script() {
+        assert_eq!(direction("pa-Arab"), LayoutDirection::RightToLeft);
+        assert_eq!(direction("ku-Latn"), LayoutDirection::LeftToRight);
+    }
+}
@@ -0,0 +1,97 @@
+use std::fmt::Formatter;
+
+/// The position and span of a view within the rows and columns of a grid.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq)]
+pub struct GridPlacement {
+    pub row_index: usize,
+    pub row_span: usize,
+    pub col_index: usize,
+    pub col_span: usize,
+}
+
+/// Named areas of a grid, given as rows of cell names.
+///
+/// Each row is a list of names separated by whitespace, with `.` marking an unnamed cell. Views are
+/// placed into an area with the `grid-area` property, which spans every cell with that name.
+///
+/// # Example
+/// ```
+/// # use vizia_core::style::GridTemplateAreas;
+/// let areas = GridTemplateAreas::new(&["header header", "sidebar main"]);
+/// let header = areas.area("header").unwrap();
+/// assert_eq!((header.row_index, header.col_index, header.col_span), (0, 0, 2));
+/// ```
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct GridTemplateAreas {
+    pub rows: Vec<Vec<String>>,
+}
+
+impl GridTemplateAreas {
+    /// Creates template areas from a list of rows of whitespace separated cell names.
+    pub fn new(rows: &[&str]) -> Self {
+        Self {
+            rows: rows
+                .iter()
+                .map(|row| row.split_whitespace().map(|name| name.to_owned()).collect())
+                .collect(),
+        }
+    }
+
+    /// Returns the number of rows of the template.
+    pub fn num_rows(&self) -> usize {
+        self.rows.len()
+    }
+
+    /// Returns the number of columns of the template.
+    pub fn num_cols(&self) -> usize {
+        self.rows.iter().map(|row| row.len()).max().unwrap_or_default()
+    }
+
+    /// Returns the placement covering every cell with the given name.
+    pub fn area(&self, name: &str) -> Option<GridPlacement> {
+        if name == "." {
+            return None;
+        }
+
+        let mut bounds: Option<(usize, usize, usize, usize)> = None;
+
+        for (row, names) in self.rows.iter().enumerate() {
+            for (col, cell) in names.iter().enumerate() {
+                if cell == name {
+                    bounds = Some(match bounds {
+                        Some((top, left, bottom, right)) => {
+                            (top.min(row), left.min(col), bottom.max(row), right.max(col))
+                        }
+                        None => (row, col, row, col),
+                    });
+                }
+            }
+        }
+
+        bounds.map(|(top, left, bottom, right)| GridPlacement {
+            row_index: top,
+            row_span: bottom - top + 1,
+            col_index: left,
+            col_span: right - left + 1,
+        })
+    }
+}
+
+impl std::fmt::Display for GridTemplateAreas {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let rows = self.rows.iter().map(|row| format!("\"{}\"", row.join(" "))).collect::<Vec<_>>();
+        write!(f, "{}", rows.join(" "))
+    }
+}
+
+impl From<&[&str]> for GridTemplateAreas {
+    fn from(rows: &[&str]) -> Self {
+        GridTemplateAreas::new(rows)
+    }
+}
+
+impl<const N: usize> From<[&str; N]> for GridTemplateAreas {
+    fn from(rows: [&str; N]) -> Self {
+        GridTemplateAreas::new(&rows)
+    }
+}
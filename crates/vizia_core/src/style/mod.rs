@@ -38,6 +38,9 @@ pub use property::*;
 mod gradient;
 pub use gradient::*;
 
+mod grid;
+pub use grid::*;
+
 mod shadow;
 use shadow::*;
 
@@ -114,6 +117,9 @@ pub struct Style {
 
     pub default_font: Vec<FamilyOwned>,
 
+    /// The layout direction used by entities which do not inherit or specify a direction.
+    pub default_direction: LayoutDirection,
+
     pub elements: SparseSet<String>,
     pub ids: SparseSet<String>,
     pub classes: SparseSet<HashSet<String>>,
@@ -216,6 +222,12 @@ pub struct Style {
     // Layout Type
     pub layout_type: StyleSet<LayoutType>,
 
+    // Layout Direction
+    pub direction: StyleSet<LayoutDirection>,
+
+    // Layout Wrap
+    pub layout_wrap: StyleSet<LayoutWrap>,
+
     // Position Type
     pub position_type: StyleSet<PositionType>,
 
@@ -236,6 +248,12 @@ pub struct Style {
     pub min_height: AnimatableSet<Units>,
     pub content_width: StyleSet<f32>,
     pub content_height: StyleSet<f32>,
+    pub aspect_ratio: StyleSet<f32>,
+
+    // Computed positions of the children of wrapping views and the cross size of their lines,
+    // in physical pixels.
+    pub(crate) wrap_positions: SparseSet<(f32, f32)>,
+    pub(crate) wrap_extents: SparseSet<f32>,
 
     // Spacing Constraints
     pub min_left: AnimatableSet<Units>,
@@ -258,6 +276,13 @@ pub struct Style {
     pub row_span: StyleSet<usize>,
     pub col_span: StyleSet<usize>,
 
+    pub grid_template_areas: StyleSet<GridTemplateAreas>,
+    pub grid_area: StyleSet<String>,
+
+    // Computed placements of the children of grids and the number of rows of each grid.
+    pub(crate) grid_placements: SparseSet<GridPlacement>,
+    pub(crate) grid_row_counts: SparseSet<usize>,
+
     // Child Spacing
     pub child_left: AnimatableSet<Units>,
     pub child_right: AnimatableSet<Units>,
@@ -301,6 +326,11 @@ impl Style {
         physical / self.dpi_factor as f32
     }
 
+    /// Returns the resolved layout direction of an entity, falling back to the default direction.
+    pub fn layout_direction(&self, entity: Entity) -> LayoutDirection {
+        self.direction.get(entity).copied().unwrap_or(self.default_direction)
+    }
+
     pub fn remove_rules(&mut self) {
         for rule in self.rules.iter() {
             self.rule_manager.destroy(rule.id);
@@ -464,6 +494,10 @@ impl Style {
                         self.min_height.insert_rule(rule_id, value);
                     }
 
+                    Property::AspectRatio(value) => {
+                        self.aspect_ratio.insert_rule(rule_id, value);
+                    }
+
                     // Border
                     Property::BorderWidth(value) => {
                         self.border_width.insert_rule(rule_id, value);
@@ -579,6 +613,47 @@ impl Style {
                         self.layout_type.insert_rule(rule_id, value);
                     }
 
+                    Property::Direction(value) => {
+                        self.direction.insert_rule(rule_id, value);
+                    }
+
+                    Property::LayoutWrap(value) => {
+                        self.layout_wrap.insert_rule(rule_id, value);
+                    }
+
+                    // Grid
+                    Property::GridRows(value) => {
+                        self.grid_rows.insert_rule(rule_id, value);
+                    }
+
+                    Property::GridCols(value) => {
+                        self.grid_cols.insert_rule(rule_id, value);
+                    }
+
+                    Property::RowIndex(value) => {
+                        self.row_index.insert_rule(rule_id, value);
+                    }
+
+                    Property::ColIndex(value) => {
+                        self.col_index.insert_rule(rule_id, value);
+                    }
+
+                    Property::RowSpan(value) => {
+                        self.row_span.insert_rule(rule_id, value);
+                    }
+
+                    Property::ColSpan(value) => {
+                        self.col_span.insert_rule(rule_id, value);
+                    }
+
+                    Property::GridTemplateAreas(value) => {
+                        self.grid_template_areas.insert_rule(rule_id, value);
+                    }
+
+                    Property::GridArea(value) => {
+                        self.grid_area.insert_rule(rule_id, value);
+                    }
+
                     Property::ZIndex(value) => {
                         self.z_order.insert_rule(rule_id, value);
                     }
@@ -1030,6 +1105,7 @@ impl Style {
         self.default_action_verb.remove(entity);
         self.live.remove(entity);
         self.labelled_by.remove(entity);
+        self.tooltip.remove(entity);
         self.hidden.remove(entity);
         self.text_value.remove(entity);
         self.numeric_value.remove(entity);
@@ -1090,6 +1166,10 @@ impl Style {
         self.inner_shadow_color.remove(entity);
 
         self.layout_type.remove(entity);
+        self.direction.remove(entity);
+        self.layout_wrap.remove(entity);
+        self.wrap_positions.remove(entity);
+        self.wrap_extents.remove(entity);
         self.position_type.remove(entity);
 
         // Space
@@ -1119,6 +1199,7 @@ impl Style {
         self.max_height.remove(entity);
         self.content_width.remove(entity);
         self.content_height.remove(entity);
+        self.aspect_ratio.remove(entity);
 
         // Child Space
         self.child_left.remove(entity);
@@ -1135,6 +1216,10 @@ impl Style {
         self.col_span.remove(entity);
         self.row_index.remove(entity);
         self.row_span.remove(entity);
+        self.grid_template_areas.remove(entity);
+        self.grid_area.remove(entity);
+        self.grid_placements.remove(entity);
+        self.grid_row_counts.remove(entity);
 
         // Text and Font
         self.text_wrap.remove(entity);
@@ -1234,6 +1319,8 @@ impl Style {
         self.inner_shadow_color.clear_rules();
 
         self.layout_type.clear_rules();
+        self.direction.clear_rules();
+        self.layout_wrap.clear_rules();
         self.position_type.clear_rules();
 
         // Space
@@ -1263,6 +1350,7 @@ impl Style {
         self.max_height.clear_rules();
         self.content_width.clear_rules();
         self.content_height.clear_rules();
+        self.aspect_ratio.clear_rules();
 
         // Child Space
         self.child_left.clear_rules();
@@ -1279,6 +1367,8 @@ impl Style {
         self.col_span.clear_rules();
         self.row_index.clear_rules();
         self.row_span.clear_rules();
+        self.grid_template_areas.clear_rules();
+        self.grid_area.clear_rules();
 
         // Text and Font
         self.text_wrap.clear_rules();
@@ -276,6 +276,7 @@ fn parse_selectors<'i, 't>(
                     "focus-within" => selector.pseudo_classes.insert(PseudoClass::FOCUS_WITHIN),
                     "focus-visible" => selector.pseudo_classes.insert(PseudoClass::FOCUS_VISIBLE),
                     "root" => selector.pseudo_classes.insert(PseudoClass::ROOT),
+                    "drag-over" => selector.pseudo_classes.insert(PseudoClass::DRAG_OVER),
 
                     _ => {
                         let parse_error = ParseError {
@@ -384,6 +385,8 @@ impl<'i> cssparser::DeclarationParser<'i> for DeclarationParser {
             "max-bottom" => Property::MaxBottom(parse_units(input)?),
 
             "layout-type" => Property::LayoutType(parse_layout_type(input)?),
+            "direction" => Property::Direction(parse_direction(input)?),
+            "layout-wrap" => Property::LayoutWrap(parse_layout_wrap(input)?),
 
             // Size
             "width" => Property::Width(parse_units(input)?),
@@ -394,6 +397,7 @@ impl<'i> cssparser::DeclarationParser<'i> for DeclarationParser {
             "min-height" => Property::MinHeight(parse_units(input)?),
             "max-width" => Property::MaxWidth(parse_units(input)?),
             "max-height" => Property::MaxHeight(parse_units(input)?),
+            "aspect-ratio" => Property::AspectRatio(parse_aspect_ratio(input)?),
 
             "child-space" => Property::ChildSpace(parse_units(input)?),
             "child-left" => Property::ChildLeft(parse_units(input)?),
@@ -402,6 +406,16 @@ impl<'i> cssparser::DeclarationParser<'i> for DeclarationParser {
             "child-bottom" => Property::ChildBottom(parse_units(input)?),
             "row-between" => Property::RowBetween(parse_units(input)?),
             "col-between" => Property::ColBetween(parse_units(input)?),
+
+            // Grid
+            "grid-rows" => Property::GridRows(parse_grid_tracks(input)?),
+            "grid-cols" => Property::GridCols(parse_grid_tracks(input)?),
+            "row-index" => Property::RowIndex(parse_usize(input)?),
+            "col-index" => Property::ColIndex(parse_usize(input)?),
+            "row-span" => Property::RowSpan(parse_usize(input)?),
+            "col-span" => Property::ColSpan(parse_usize(input)?),
+            "grid-template-areas" => Property::GridTemplateAreas(parse_grid_template_areas(input)?),
+            "grid-area" => Property::GridArea(parse_grid_area(input)?),
             "font-size" => Property::FontSize(parse_font_size(input)?),
             "font-family" => Property::FontFamily(input.parse_comma_separated(parse_font_family)?),
             "font-weight" => Property::FontWeight(parse_font_weight(input)?),
@@ -1046,6 +1060,182 @@ fn parse_border_corner_shape<'i, 't>(
     })
 }
 
+fn parse_direction<'i, 't>(
+    input: &mut Parser<'i, 't>,
+) -> Result<LayoutDirection, ParseError<'i, CustomParseError>> {
+    let location = input.current_source_location();
+
+    Ok(match input.next()? {
+        Token::Ident(name) => match name.as_ref() {
+            "ltr" => LayoutDirection::LeftToRight,
+            "rtl" => LayoutDirection::RightToLeft,
+
+            _ => {
+                return Err(CustomParseError::InvalidStringName(name.to_owned().to_string()).into());
+            }
+        },
+
+        t => {
+            let basic_error = BasicParseError {
+                kind: BasicParseErrorKind::UnexpectedToken(t.to_owned()),
+                location,
+            };
+            return Err(basic_error.into());
+        }
+    })
+}
+
+// Parses an aspect ratio given as a single number or as a `width / height` pair.
+fn parse_aspect_ratio<'i, 't>(
+    input: &mut Parser<'i, 't>,
+) -> Result<f32, ParseError<'i, CustomParseError>> {
+    let width = input.expect_number()?;
+    let height = if input.try_parse(|input| input.expect_delim('/')).is_ok() {
+        input.expect_number()?
+    } else {
+        1.0
+    };
+
+    if width > 0.0 && height > 0.0 {
+        Ok(width / height)
+    } else {
+        Err(CustomParseError::InvalidValue(format!("{} / {}", width, height)).into())
+    }
+}
+
+fn parse_usize<'i, 't>(
+    input: &mut Parser<'i, 't>,
+) -> Result<usize, ParseError<'i, CustomParseError>> {
+    let location = input.current_source_location();
+
+    Ok(match input.next()? {
+        Token::Number { int_value: Some(x), .. } if *x >= 0 => *x as usize,
+        t => {
+            let basic_error = BasicParseError {
+                kind: BasicParseErrorKind::UnexpectedToken(t.to_owned()),
+                location,
+            };
+            return Err(basic_error.into());
+        }
+    })
+}
+
+fn parse_grid_track<'i>(
+    token: &Token<'i>,
+    location: SourceLocation,
+) -> Result<Units, ParseError<'i, CustomParseError>> {
+    Ok(match token {
+        Token::Number { value: x, .. } => Units::Pixels(*x),
+        Token::Percentage { unit_value: x, .. } => Units::Percentage(*x * 100.0),
+
+        Token::Dimension { value: v, unit: u, .. } if u == &"px" => Units::Pixels(*v),
+
+        // Fractions of the free space are the same as stretch units.
+        Token::Dimension { value: v, unit: u, .. } if u == &"s" || u == &"fr" => Units::Stretch(*v),
+
+        Token::Ident(name) if name == &"auto" => Units::Auto,
+
+        t => {
+            let basic_error = BasicParseError {
+                kind: BasicParseErrorKind::UnexpectedToken(t.to_owned()),
+                location,
+            };
+            return Err(basic_error.into());
+        }
+    })
+}
+
+// Parses a space separated list of grid tracks, where `repeat(n, tracks)` repeats a list of tracks
+// `n` times.
+fn parse_grid_tracks<'i, 't>(
+    input: &mut Parser<'i, 't>,
+) -> Result<Vec<Units>, ParseError<'i, CustomParseError>> {
+    let mut tracks = Vec::new();
+
+    while !input.is_exhausted() {
+        let location = input.current_source_location();
+
+        match input.next()?.clone() {
+            Token::Function(name) if name.eq_ignore_ascii_case("repeat") => {
+                let (count, repeated) = input.parse_nested_block(
+                    |input| -> Result<_, ParseError<'i, CustomParseError>> {
+                        let count = input.expect_integer()?;
+                        input.expect_comma()?;
+                        Ok((count.max(0) as usize, parse_grid_tracks(input)?))
+                    },
+                )?;
+
+                for _ in 0..count {
+                    tracks.extend_from_slice(&repeated);
+                }
+            }
+
+            t => tracks.push(parse_grid_track(&t, location)?),
+        }
+    }
+
+    Ok(tracks)
+}
+
+fn parse_grid_template_areas<'i, 't>(
+    input: &mut Parser<'i, 't>,
+) -> Result<GridTemplateAreas, ParseError<'i, CustomParseError>> {
+    let mut rows = Vec::new();
+
+    loop {
+        let row = input.expect_string()?;
+        rows.push(row.split_whitespace().map(|name| name.to_owned()).collect());
+
+        if input.is_exhausted() {
+            break;
+        }
+    }
+
+    Ok(GridTemplateAreas { rows })
+}
+
+fn parse_grid_area<'i, 't>(
+    input: &mut Parser<'i, 't>,
+) -> Result<String, ParseError<'i, CustomParseError>> {
+    let location = input.current_source_location();
+
+    Ok(match input.next()? {
+        Token::Ident(name) | Token::QuotedString(name) => name.to_string(),
+        t => {
+            let basic_error = BasicParseError {
+                kind: BasicParseErrorKind::UnexpectedToken(t.to_owned()),
+                location,
+            };
+            return Err(basic_error.into());
+        }
+    })
+}
+
+fn parse_layout_wrap<'i, 't>(
+    input: &mut Parser<'i, 't>,
+) -> Result<LayoutWrap, ParseError<'i, CustomParseError>> {
+    let location = input.current_source_location();
+
+    Ok(match input.next()? {
+        Token::Ident(name) => match name.as_ref() {
+            "wrap" => LayoutWrap::Wrap,
+            "no-wrap" | "nowrap" => LayoutWrap::NoWrap,
+
+            _ => {
+                return Err(CustomParseError::InvalidStringName(name.to_owned().to_string()).into());
+            }
+        },
+
+        t => {
+            let basic_error = BasicParseError {
+                kind: BasicParseErrorKind::UnexpectedToken(t.to_owned()),
+                location,
+            };
+            return Err(basic_error.into());
+        }
+    })
+}
+
 fn parse_layout_type<'i, 't>(
     input: &mut Parser<'i, 't>,
 ) -> Result<LayoutType, ParseError<'i, CustomParseError>> {
@@ -1251,3 +1441,24 @@ pub(crate) fn _parse(s: &str) -> Vec<StyleRule> {
 
     rules.into_iter().filter_map(|rule| rule.ok()).collect()
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn aspect_ratio(value: &str) -> Option<f32> {
+        let mut input = ParserInput::new(value);
+        let mut parser = Parser::new(&mut input);
+        parse_aspect_ratio(&mut parser).ok()
+    }
+
+    #[test]
+    fn test_parse_aspect_ratio() {
+        assert_eq!(aspect_ratio("16 / 9"), Some(16.0 / 9.0));
+        assert_eq!(aspect_ratio("1.5"), Some(1.5));
+        assert_eq!(aspect_ratio("0"), None);
+        assert_eq!(aspect_ratio("-1"), None);
+        assert_eq!(aspect_ratio("4 / 0"), None);
+        assert_eq!(aspect_ratio("4 / -3"), None);
+    }
+}
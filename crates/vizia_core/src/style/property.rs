@@ -24,6 +24,8 @@ pub(crate) enum Property {
     // Positioning
     LayoutType(LayoutType),
     PositionType(PositionType),
+    Direction(LayoutDirection),
+    LayoutWrap(LayoutWrap),
 
     // Position and Size
     Space(Units),
@@ -48,6 +50,7 @@ pub(crate) enum Property {
     MaxHeight(Units),
     MinBottom(Units),
     MaxBottom(Units),
+    AspectRatio(f32),
 
     // Child Spacing
     ChildSpace(Units),
@@ -58,6 +61,16 @@ pub(crate) enum Property {
     RowBetween(Units),
     ColBetween(Units),
 
+    // Grid
+    GridRows(Vec<Units>),
+    GridCols(Vec<Units>),
+    RowIndex(usize),
+    ColIndex(usize),
+    RowSpan(usize),
+    ColSpan(usize),
+    GridTemplateAreas(GridTemplateAreas),
+    GridArea(String),
+
     // Border Radius
     BorderRadius(Units),
     BorderTopLeftRadius(Units),
@@ -132,6 +145,10 @@ pub(crate) fn fmt_units(val: &Units) -> String {
     }
 }
 
+fn fmt_tracks(val: &[Units]) -> String {
+    val.iter().map(fmt_units).collect::<Vec<_>>().join(" ")
+}
+
 fn fmt_layout_type(val: &LayoutType) -> String {
     match val {
         LayoutType::Row => "row",
@@ -175,6 +192,8 @@ impl std::fmt::Display for Property {
             // Positioning
             Property::LayoutType(val) => write!(f, "layout-type: {};", fmt_layout_type(val)),
             Property::PositionType(val) => write!(f, "position-type: {};", fmt_position_type(val)),
+            Property::Direction(val) => write!(f, "direction: {};", val),
+            Property::LayoutWrap(val) => write!(f, "layout-wrap: {};", val),
 
             // Position and Size
             Property::Space(val) => write!(f, "space: {};", fmt_units(val)),
@@ -199,6 +218,7 @@ impl std::fmt::Display for Property {
             Property::MaxHeight(val) => write!(f, "max-height: {};", fmt_units(val)),
             Property::MinBottom(val) => write!(f, "min-bottom: {};", fmt_units(val)),
             Property::MaxBottom(val) => write!(f, "max-bottom: {};", fmt_units(val)),
+            Property::AspectRatio(val) => write!(f, "aspect-ratio: {};", val),
 
             // Child Spacing
             Property::ChildSpace(val) => write!(f, "child-space: {};", fmt_units(val)),
@@ -209,6 +229,16 @@ impl std::fmt::Display for Property {
             Property::RowBetween(val) => write!(f, "row-between: {};", fmt_units(val)),
             Property::ColBetween(val) => write!(f, "col-between: {};", fmt_units(val)),
 
+            // Grid
+            Property::GridRows(val) => write!(f, "grid-rows: {};", fmt_tracks(val)),
+            Property::GridCols(val) => write!(f, "grid-cols: {};", fmt_tracks(val)),
+            Property::RowIndex(val) => write!(f, "row-index: {};", val),
+            Property::ColIndex(val) => write!(f, "col-index: {};", val),
+            Property::RowSpan(val) => write!(f, "row-span: {};", val),
+            Property::ColSpan(val) => write!(f, "col-span: {};", val),
+            Property::GridTemplateAreas(val) => write!(f, "grid-template-areas: {};", val),
+            Property::GridArea(val) => write!(f, "grid-area: {};", val),
+
             // Border
             Property::BorderRadius(val) => write!(f, "border-radius: {};", fmt_units(val)),
             Property::BorderTopLeftRadius(val) => {
@@ -23,6 +23,7 @@ bitflags! {
         const FOCUS_WITHIN = 1<<8;
         const FOCUS_VISIBLE = 1 << 9;
         const ROOT = 1 << 10;
+        const DRAG_OVER = 1 << 11;
     }
 }
 
@@ -64,6 +65,9 @@ impl std::fmt::Display for PseudoClass {
         if self.contains(PseudoClass::ROOT) {
             write!(f, ":root")?;
         }
+        if self.contains(PseudoClass::DRAG_OVER) {
+            write!(f, ":drag-over")?;
+        }
 
         Ok(())
     }
@@ -118,6 +118,10 @@ pub(crate) fn get_access_node(
         node_builder.set_name(name.clone().into_boxed_str());
     }
 
+    if let Some(tooltip) = cx.style.tooltip.get(entity) {
+        node_builder.set_description(tooltip.clone().into_boxed_str());
+    }
+
     if let Some(numeric_value) = cx.style.numeric_value.get(entity) {
         node_builder.set_numeric_value(*numeric_value);
     }
@@ -14,6 +14,13 @@ pub fn clipping_system(cx: &mut Context) {
                 continue;
             }
 
+            // The content of a window is clipped to the window rather than to its parent.
+            if cx.windows.contains_key(&entity) {
+                let bounds = cx.cache.get_bounds(entity);
+                cx.cache.set_clip_region(entity, bounds);
+                continue;
+            }
+
             let parent = cx.tree.get_layout_parent(entity).unwrap();
 
             let parent_clip_region = cx.cache.get_clip_region(parent);
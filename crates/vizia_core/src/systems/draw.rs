@@ -1,33 +1,48 @@
 use crate::context::Context;
+use crate::layout::debug::draw_layout_overlay;
 use crate::prelude::*;
 use vizia_id::GenerationalId;
 use vizia_storage::DrawIterator;
 
-pub fn draw_system(cx: &mut Context) {
-    let canvas = cx.canvases.get_mut(&Entity::root()).unwrap();
-    cx.resource_manager.mark_images_unused();
+/// Draws the content of a window to its canvas.
+pub fn draw_system(cx: &mut Context, window: Entity) {
+    let scale = cx.window_scale(window);
+    // The canvas is taken out of the context so that the window of each entity can be looked up.
+    let mut canvas = match cx.canvases.remove(&window) {
+        Some(canvas) => canvas,
+        None => return,
+    };
+
+    if window == Entity::root() {
+        cx.resource_manager.mark_images_unused();
+    }
 
-    let window_width = cx.cache.get_width(Entity::root());
-    let window_height = cx.cache.get_height(Entity::root());
+    let window_bounds = cx.cache.get_bounds(window);
+    let window_width = (window_bounds.w * scale).round();
+    let window_height = (window_bounds.h * scale).round();
 
     canvas.set_size(window_width as u32, window_height as u32, 1.0);
-    let clear_color =
-        cx.style.background_color.get(Entity::root()).cloned().unwrap_or(Color::white());
+    let clear_color = cx.style.background_color.get(window).cloned().unwrap_or(Color::white());
     canvas.clear_rect(0, 0, window_width as u32, window_height as u32, clear_color.into());
 
+    // The content of a window is laid out relative to its parent, so it is moved to the origin of
+    // the canvas and scaled to the scale factor of the window.
+    canvas.save();
+    canvas.scale(scale, scale);
+    canvas.translate(-window_bounds.x, -window_bounds.y);
+
     let draw_tree = DrawIterator::full(&cx.tree);
 
     for entity in draw_tree {
-        let window_bounds = cx.cache.get_bounds(Entity::root());
-
         // Skip if the entity is invisible or out of bounds
         // Unfortunately we can't skip the subtree because even if a parent is invisible
         // a child might be explicitly set to be visible.
-        if entity == Entity::root()
+        if entity == window
             || cx.cache.get_visibility(entity) == Visibility::Invisible
             || cx.cache.get_display(entity) == Display::None
             || cx.cache.get_opacity(entity) == 0.0
             || !window_bounds.intersects(&cx.cache.get_bounds(entity))
+            || cx.window_of(entity) != window
         {
             continue;
         }
@@ -53,6 +68,7 @@ pub fn draw_system(cx: &mut Context) {
             view.draw(
                 &mut DrawContext {
                     current: cx.current,
+                    window,
                     captured: &cx.captured,
                     focused: &cx.focused,
                     hovered: &cx.hovered,
@@ -68,24 +84,31 @@ pub fn draw_system(cx: &mut Context) {
                     modifiers: &cx.modifiers,
                     mouse: &cx.mouse,
                 },
-                canvas,
+                &mut canvas,
             );
 
             cx.views.insert(entity, view);
         }
 
         canvas.restore();
+    }
 
-        // Uncomment this for debug outlines
-        // TODO - Hook this up to a key in debug mode
-        // let mut path = Path::new();
-        // path.rect(bounds.x, bounds.y, bounds.w, bounds.h);
-        // let mut paint = Paint::color(femtovg::Color::rgb(255, 0, 0));
-        // paint.set_line_width(1.0);
-        // canvas.stroke_path(&mut path, paint);
+    canvas.restore();
+
+    if cx.layout_debug && window == Entity::root() {
+        draw_layout_overlay(
+            &mut canvas,
+            &cx.tree,
+            &cx.cache,
+            &cx.style,
+            &mut cx.text_context,
+            cx.text_config,
+        );
     }
 
     canvas.flush();
 
+    cx.canvases.insert(window, canvas);
+
     //cx.resource_manager.evict_unused_images();
 }
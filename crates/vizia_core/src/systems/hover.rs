@@ -2,6 +2,61 @@ use crate::prelude::*;
 use vizia_id::GenerationalId;
 use vizia_storage::DrawIterator;
 
+// Returns true if an entity in the window under the cursor can be hovered or touched.
+fn is_hittable(cx: &Context, entity: Entity) -> bool {
+    let window = cx.cursor_window;
+    let window_bounds = cx.cache.get_bounds(window);
+
+    // Skip if the entity is invisible or out of bounds
+    // Unfortunately we can't skip the subtree because even if a parent is invisible
+    // a child might be explicitly set to be visible.
+    if entity == window
+        || cx.cache.get_visibility(entity) == Visibility::Invisible
+        || cx.cache.get_display(entity) == Display::None
+        || cx.cache.get_opacity(entity) == 0.0
+        || !window_bounds.contains(&cx.cache.get_bounds(entity))
+        || cx.window_of(entity) != window
+    {
+        return false;
+    }
+
+    // Skip non-hoverable widgets
+    cx.cache.get_hoverability(entity)
+}
+
+// Returns true if a point in physical window coordinates lies within the clipped bounds of an entity.
+fn contains_point(cx: &Context, entity: Entity, x: f32, y: f32) -> bool {
+    let mut transform = cx.cache.get_transform(entity);
+    transform.inverse();
+
+    let (tx, ty) = transform.transform_point(x, y);
+
+    let posx = cx.cache.get_posx(entity);
+    let posy = cx.cache.get_posy(entity);
+    let width = cx.cache.get_width(entity);
+    let height = cx.cache.get_height(entity);
+
+    let clip_region = cx.cache.get_clip_region(entity);
+
+    tx >= posx
+        && tx >= clip_region.x
+        && tx < (posx + width)
+        && tx < (clip_region.x + clip_region.w)
+        && ty >= posy
+        && ty >= clip_region.y
+        && ty < (posy + height)
+        && ty < (clip_region.y + clip_region.h)
+}
+
+// Returns the topmost entity under a point in physical window coordinates within the window under
+// the cursor.
+pub(crate) fn hit_test(cx: &Context, x: f32, y: f32) -> Entity {
+    DrawIterator::full(&cx.tree)
+        .filter(|entity| is_hittable(cx, *entity) && contains_point(cx, *entity, x, y))
+        .last()
+        .unwrap_or(cx.cursor_window)
+}
+
 // Determines the hovered entity based on the mouse cursor position.
 pub fn hover_system(cx: &mut Context) {
     let draw_tree = DrawIterator::full(&cx.tree);
@@ -9,49 +64,14 @@ pub fn hover_system(cx: &mut Context) {
     let cursorx = cx.mouse.cursorx;
     let cursory = cx.mouse.cursory;
 
-    let mut hovered_widget = Entity::root();
+    let mut hovered_widget = cx.cursor_window;
 
     for entity in draw_tree {
-        let window_bounds = cx.cache.get_bounds(Entity::root());
-
-        // Skip if the entity is invisible or out of bounds
-        // Unfortunately we can't skip the subtree because even if a parent is invisible
-        // a child might be explicitly set to be visible.
-        if entity == Entity::root()
-            || cx.cache.get_visibility(entity) == Visibility::Invisible
-            || cx.cache.get_display(entity) == Display::None
-            || cx.cache.get_opacity(entity) == 0.0
-            || !window_bounds.contains(&cx.cache.get_bounds(entity))
-        {
-            continue;
-        }
-
-        // Skip non-hoverable widgets
-        if !cx.cache.get_hoverability(entity) {
+        if !is_hittable(cx, entity) {
             continue;
         }
 
-        let mut transform = cx.cache.get_transform(entity);
-        transform.inverse();
-
-        let (tx, ty) = transform.transform_point(cursorx, cursory);
-
-        let posx = cx.cache.get_posx(entity);
-        let posy = cx.cache.get_posy(entity);
-        let width = cx.cache.get_width(entity);
-        let height = cx.cache.get_height(entity);
-
-        let clip_region = cx.cache.get_clip_region(entity);
-
-        if tx >= posx
-            && tx >= clip_region.x
-            && tx < (posx + width)
-            && tx < (clip_region.x + clip_region.w)
-            && ty >= posy
-            && ty >= clip_region.y
-            && ty < (posy + height)
-            && ty < (clip_region.y + clip_region.h)
-        {
+        if contains_point(cx, entity, cursorx, cursory) {
             hovered_widget = entity;
             if !cx
                 .style
@@ -111,7 +131,8 @@ pub fn hover_system(cx: &mut Context) {
         if !cx.cursor_icon_locked
             && !cx.style.disabled.get(hovered_widget).cloned().unwrap_or_default()
         {
-            cx.emit(WindowEvent::SetCursor(cursor));
+            let window = cx.cursor_window;
+            cx.with_current(window, |cx| cx.emit(WindowEvent::SetCursor(cursor)));
         }
 
         // Set current hovered pseudoclass to true
@@ -1,16 +1,42 @@
 use morphorm::layout;
 
+use crate::layout::grid::grid_system;
+use crate::layout::wrap::wrap_system;
+use crate::layout::{mirror_layout, snapshot_rtl_positions};
 use crate::prelude::*;
 use crate::style::SystemFlags;
 
 use super::text_constraints_system;
 
+// The most times layout is repeated in a frame for the lines of wrapping containers to settle,
+// which stops containers whose lines would otherwise alternate between two arrangements.
+const MAX_WRAP_PASSES: usize = 8;
+
 pub(crate) fn layout_system(cx: &mut Context) {
     text_constraints_system(cx);
 
     if cx.style.system_flags.contains(SystemFlags::RELAYOUT) {
+        let previous_positions = snapshot_rtl_positions(&cx.cache, &cx.tree, &cx.style);
+
+        grid_system(&cx.cache, &cx.tree, &mut cx.style);
+        wrap_system(&cx.cache, &cx.tree, &mut cx.style);
+
         layout(&mut cx.cache, &cx.tree, &cx.style, &mut cx.text_context);
 
+        // Wrapping depends on the sizes computed by layout, so layout again until the lines stop
+        // changing, which takes several passes for nested or auto-sized wrapping containers.
+        for _ in 0..MAX_WRAP_PASSES {
+            if !wrap_system(&cx.cache, &cx.tree, &mut cx.style) {
+                break;
+            }
+
+            layout(&mut cx.cache, &cx.tree, &cx.style, &mut cx.text_context);
+        }
+
+        if let Some(previous_positions) = previous_positions {
+            mirror_layout(&mut cx.cache, &cx.tree, &cx.style, &previous_positions);
+        }
+
         // If layout has changed then reclip, retransform, and redraw
         cx.style.system_flags.set(SystemFlags::RETRANSFORM, true);
         cx.style.system_flags.set(SystemFlags::RECLIP, true);
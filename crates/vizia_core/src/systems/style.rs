@@ -16,6 +16,7 @@ pub fn inline_inheritance_system(cx: &mut Context) {
             cx.style.font_style.inherit_inline(entity, parent);
             cx.style.caret_color.inherit_inline(entity, parent);
             cx.style.selection_color.inherit_inline(entity, parent);
+            cx.style.direction.inherit_inline(entity, parent);
         }
     }
 }
@@ -30,6 +31,7 @@ pub fn shared_inheritance_system(cx: &mut Context) {
             cx.style.font_style.inherit_shared(entity, parent);
             cx.style.caret_color.inherit_shared(entity, parent);
             cx.style.selection_color.inherit_shared(entity, parent);
+            cx.style.direction.inherit_shared(entity, parent);
         }
     }
 }
@@ -278,6 +280,10 @@ fn link_style_data(style: &mut Style, entity: Entity, matched_rules: &Vec<Rule>)
         should_relayout = true;
     }
 
+    if style.aspect_ratio.link(entity, matched_rules) {
+        should_relayout = true;
+    }
+
     // Border
     if style.border_width.link(entity, matched_rules) {
         should_relayout = true;
@@ -335,6 +341,15 @@ fn link_style_data(style: &mut Style, entity: Entity, matched_rules: &Vec<Rule>)
         should_relayout = true;
     }
 
+    if style.direction.link(entity, matched_rules) {
+        should_relayout = true;
+        should_redraw = true;
+    }
+
+    if style.layout_wrap.link(entity, matched_rules) {
+        should_relayout = true;
+    }
+
     if style.position_type.link(entity, matched_rules) {
         should_relayout = true;
     }
@@ -440,6 +455,38 @@ fn link_style_data(style: &mut Style, entity: Entity, matched_rules: &Vec<Rule>)
         should_relayout = true;
     }
 
+    if style.grid_rows.link(entity, matched_rules) {
+        should_relayout = true;
+    }
+
+    if style.grid_cols.link(entity, matched_rules) {
+        should_relayout = true;
+    }
+
+    if style.row_index.link(entity, matched_rules) {
+        should_relayout = true;
+    }
+
+    if style.col_index.link(entity, matched_rules) {
+        should_relayout = true;
+    }
+
+    if style.row_span.link(entity, matched_rules) {
+        should_relayout = true;
+    }
+
+    if style.col_span.link(entity, matched_rules) {
+        should_relayout = true;
+    }
+
+    if style.grid_template_areas.link(entity, matched_rules) {
+        should_relayout = true;
+    }
+
+    if style.grid_area.link(entity, matched_rules) {
+        should_relayout = true;
+    }
+
     if style.cursor.link(entity, matched_rules) {
         should_redraw = true;
     }
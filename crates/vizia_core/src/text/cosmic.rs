@@ -3,8 +3,8 @@ use crate::prelude::Color;
 use crate::style::Style;
 use cosmic_text::{
     fontdb::{Database, Query},
-    Attrs, AttrsList, Buffer, CacheKey, Color as FontColor, Color as CosmicColor, Edit, Editor,
-    Family, FontSystem, Metrics, SubpixelBin, Wrap,
+    Attrs, AttrsList, Buffer, CacheKey, Color as FontColor, Color as CosmicColor, Cursor, Edit,
+    Editor, Family, FontSystem, Metrics, SubpixelBin, Wrap,
 };
 use femtovg::imgref::{Img, ImgRef};
 use femtovg::rgb::RGBA8;
@@ -47,9 +47,11 @@ struct TextContextInternal<'a> {
     font_system: &'a FontSystem,
 
     scale_context: ScaleContext,
-    rendered_glyphs: FnvHashMap<CacheKey, Option<RenderedGlyph>>,
-    glyph_textures: Vec<FontTexture>,
+    // The glyphs rendered to the canvas of each window.
+    glyph_caches: FnvHashMap<Entity, GlyphCache>,
     buffers: HashMap<Entity, Editor<'a>>,
+    // The range of uncommitted IME text in each buffer.
+    preedits: HashMap<Entity, (Cursor, Cursor)>,
 }
 
 impl TextContext {
@@ -61,6 +63,7 @@ impl TextContext {
     pub fn clear_buffer(&mut self, entity: Entity) {
         self.with_int_mut(move |int: &mut TextContextInternal| {
             int.buffers.remove(&entity);
+            int.preedits.remove(&entity);
         });
     }
 
@@ -135,6 +138,7 @@ impl TextContext {
     pub(crate) fn fill_to_cmds<T: Renderer>(
         &mut self,
         canvas: &mut Canvas<T>,
+        window: Entity,
         entity: Entity,
         position: (f32, f32),
         justify: (f32, f32),
@@ -146,6 +150,7 @@ impl TextContext {
 
         self.with_int_mut(move |int: &mut TextContextInternal| {
             let buffer = int.buffers.get_mut(&entity).unwrap().buffer_mut();
+            let cache = int.glyph_caches.entry(window).or_default();
 
             let mut alpha_cmd_map = FnvHashMap::default();
             let mut color_cmd_map = FnvHashMap::default();
@@ -163,7 +168,7 @@ impl TextContext {
                     cache_key.x_bin = subpixel_x;
                     cache_key.y_bin = subpixel_y;
                     // perform cache lookup for rendered glyph
-                    let Some(rendered) = int.rendered_glyphs.entry(cache_key).or_insert_with(|| {
+                    let Some(rendered) = cache.rendered_glyphs.entry(cache_key).or_insert_with(|| {
                         // ...or insert it
 
                         // do the actual rasterization
@@ -192,7 +197,7 @@ impl TextContext {
                             let used_w = rendered.placement.width + GLYPH_PADDING * 2;
                             let used_h = rendered.placement.height + GLYPH_PADDING * 2;
                             let mut found = None;
-                            for (texture_index, glyph_atlas) in int.glyph_textures.iter_mut().enumerate() {
+                            for (texture_index, glyph_atlas) in cache.glyph_textures.iter_mut().enumerate() {
                                 if let Some((x, y)) = glyph_atlas.atlas.add_rect(alloc_w as usize, alloc_h as usize) {
                                     found = Some((texture_index, x, y));
                                     break;
@@ -203,9 +208,9 @@ impl TextContext {
                                 // TODO error handling
                                 let mut atlas = Atlas::new(TEXTURE_SIZE, TEXTURE_SIZE);
                                 let image_id = canvas.create_image(Img::new(vec![RGBA8::new(0,0,0,0); TEXTURE_SIZE * TEXTURE_SIZE], TEXTURE_SIZE, TEXTURE_SIZE).as_ref(), ImageFlags::empty()).unwrap();
-                                let texture_index = int.glyph_textures.len();
+                                let texture_index = cache.glyph_textures.len();
                                 let (x, y) = atlas.add_rect(alloc_w as usize, alloc_h as usize).unwrap();
-                                int.glyph_textures.push(FontTexture {
+                                cache.glyph_textures.push(FontTexture {
                                     atlas,
                                     image_id,
                                 });
@@ -230,7 +235,7 @@ impl TextContext {
                                     }
                                 }
                             }
-                            canvas.update_image::<ImageSource>(int.glyph_textures[texture_index].image_id, ImgRef::new(&src_buf, content_w, content_h).into(), atlas_content_x as usize, atlas_content_y as usize).unwrap();
+                            canvas.update_image::<ImageSource>(cache.glyph_textures[texture_index].image_id, ImgRef::new(&src_buf, content_w, content_h).into(), atlas_content_x as usize, atlas_content_y as usize).unwrap();
 
 
                             RenderedGlyph {
@@ -253,7 +258,7 @@ impl TextContext {
                     };
 
                     let cmd = cmd_map.entry(rendered.texture_index).or_insert_with(|| DrawCommand {
-                        image_id: int.glyph_textures[rendered.texture_index].image_id,
+                        image_id: cache.glyph_textures[rendered.texture_index].image_id,
                         quads: Vec::new(),
                     });
 
@@ -340,6 +345,44 @@ impl TextContext {
         })
     }
 
+    /// Sets the range of the text of an entity which holds uncommitted IME preedit text.
+    pub(crate) fn set_preedit(&mut self, entity: Entity, range: Option<(Cursor, Cursor)>) {
+        self.with_int_mut(move |int: &mut TextContextInternal| {
+            if let Some(range) = range {
+                int.preedits.insert(entity, range);
+            } else {
+                int.preedits.remove(&entity);
+            }
+        });
+    }
+
+    /// Returns the rectangles of the underline beneath the IME preedit text of an entity.
+    pub(crate) fn layout_preedit(
+        &self,
+        entity: Entity,
+        position: (f32, f32),
+        justify: (f32, f32),
+        width: f32,
+    ) -> Vec<(f32, f32, f32, f32)> {
+        self.with_int(move |int: &TextContextInternal| {
+            let mut result = vec![];
+            if let (Some((start, end)), Some(editor)) =
+                (int.preedits.get(&entity), int.buffers.get(&entity))
+            {
+                let buffer = editor.buffer();
+                let total_height = buffer.layout_runs().len() as i32 * buffer.metrics().line_height;
+                for run in buffer.layout_runs() {
+                    if let Some((x, w)) = run.highlight(*start, *end) {
+                        let x = x + position.0 - run.line_w * justify.0;
+                        let y = run.line_y as f32 + position.1 - total_height as f32 * justify.1;
+                        result.push((x, y + width, w, width));
+                    }
+                }
+            }
+            result
+        })
+    }
+
     pub(crate) fn take_buffers(&mut self) -> HashMap<Entity, Vec<String>> {
         self.with_int_mut(move |int: &mut TextContextInternal| {
             // TODO no clone please
@@ -352,6 +395,13 @@ impl TextContext {
         })
     }
 
+    /// Forgets the glyphs rendered to the canvas of a window, which is about to be dropped.
+    pub(crate) fn remove_glyph_cache(&mut self, window: Entity) {
+        self.with_int_mut(|int| {
+            int.glyph_caches.remove(&window);
+        });
+    }
+
     pub(crate) fn into_font_system(self) -> FontSystem {
         self.into_heads().font_system
     }
@@ -364,15 +414,22 @@ impl TextContext {
             int_builder: |font_system| TextContextInternal {
                 font_system,
                 scale_context: Default::default(),
-                rendered_glyphs: FnvHashMap::default(),
-                glyph_textures: vec![],
+                glyph_caches: FnvHashMap::default(),
                 buffers: HashMap::new(),
+                preedits: HashMap::new(),
             },
         }
         .build()
     }
 }
 
+/// The glyphs which have been rendered to the textures of a canvas.
+#[derive(Default)]
+struct GlyphCache {
+    rendered_glyphs: FnvHashMap<CacheKey, Option<RenderedGlyph>>,
+    glyph_textures: Vec<FontTexture>,
+}
+
 pub struct FontTexture {
     atlas: Atlas,
     image_id: ImageId,
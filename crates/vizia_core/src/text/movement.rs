@@ -1,3 +1,8 @@
+use std::ops::Range;
+
+use unicode_bidi::BidiInfo;
+use unicode_segmentation::UnicodeSegmentation;
+
 #[derive(Debug, Clone, Copy)]
 pub enum Direction {
     Left,
@@ -27,3 +32,80 @@ pub enum VerticalMovement {
     DocumentStart,
     DocumentEnd,
 }
+
+/// Returns the byte index of the caret after moving it by one grapheme in visual order.
+///
+/// The `text` is a paragraph, `line` is the byte range of the visual line containing the caret,
+/// and `index` is the current byte index of the caret. Mixed left-to-right and right-to-left runs
+/// are reordered so that `Direction::Left` and `Direction::Right` follow the rendered text rather
+/// than the logical order. Returns `None` if the caret is already at the edge of the line, or if
+/// the direction is not `Left` or `Right`.
+pub fn visual_grapheme_move(
+    text: &str,
+    line: Range<usize>,
+    index: usize,
+    direction: Direction,
+) -> Option<usize> {
+    let bidi_info = BidiInfo::new(text, None);
+    let paragraph =
+        bidi_info.paragraphs.iter().find(|paragraph| paragraph.range.contains(&line.start))?;
+    let (levels, runs) = bidi_info.visual_runs(paragraph, line);
+
+    // The (left, right) caret indices of each grapheme, from left to right.
+    let mut graphemes = Vec::new();
+    for run in runs {
+        let offset = run.start;
+        let indices = text[run.clone()]
+            .grapheme_indices(true)
+            .map(|(start, grapheme)| (offset + start, offset + start + grapheme.len()));
+
+        if levels[offset].is_rtl() {
+            graphemes.extend(indices.rev().map(|(start, end)| (end, start)));
+        } else {
+            graphemes.extend(indices);
+        }
+    }
+
+    let last = graphemes.last().copied()?;
+
+    // The caret can sit at the right edge of the previous grapheme or the left edge of the next
+    // one, which only differ at the boundary between runs of different direction.
+    let boundary = |k: usize| -> (usize, usize) {
+        let before = if k == 0 { graphemes[0].0 } else { graphemes[k - 1].1 };
+        let after = if k == graphemes.len() { last.1 } else { graphemes[k].0 };
+        (before, after)
+    };
+
+    let current = (0..=graphemes.len()).find(|k| {
+        let (before, after) = boundary(*k);
+        before == index || after == index
+    })?;
+
+    match direction {
+        Direction::Left if current > 0 => Some(graphemes[current - 1].0),
+        Direction::Right if current < graphemes.len() => Some(graphemes[current].1),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{visual_grapheme_move, Direction};
+
+    #[test]
+    fn test_visual_move_ltr() {
+        let text = "abc";
+        assert_eq!(visual_grapheme_move(text, 0..3, 1, Direction::Right), Some(2));
+        assert_eq!(visual_grapheme_move(text, 0..3, 1, Direction::Left), Some(0));
+        assert_eq!(visual_grapheme_move(text, 0..3, 3, Direction::Right), None);
+    }
+
+    #[test]
+    fn test_visual_move_rtl() {
+        // Each hebrew letter is two bytes long and is rendered from right to left.
+        let text = "אבג";
+        assert_eq!(visual_grapheme_move(text, 0..6, 0, Direction::Left), Some(2));
+        assert_eq!(visual_grapheme_move(text, 0..6, 2, Direction::Right), Some(0));
+        assert_eq!(visual_grapheme_move(text, 0..6, 6, Direction::Left), None);
+    }
+}
@@ -26,6 +26,11 @@ pub fn is_navigatable(cx: &Context, node: Entity, lock_focus_to: Entity) -> bool
         return false;
     }
 
+    // Skip nodes in other windows
+    if cx.window_of(node) != cx.window_of(lock_focus_to) {
+        return false;
+    }
+
     has_ability(cx, node, Abilities::NAVIGABLE)
 }
 
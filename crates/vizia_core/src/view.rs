@@ -80,6 +80,14 @@ pub trait View: 'static + Sized {
     #[allow(unused_variables)]
     fn event(&mut self, cx: &mut EventContext, event: &mut Event) {}
 
+    /// Receives events travelling down the tree to a descendant, before the descendant receives
+    /// them. Consuming an event here prevents it from reaching the target.
+    ///
+    /// Only events with `Down` or `DownUp` propagation, such as keyboard and mouse input, pass
+    /// through this method.
+    #[allow(unused_variables)]
+    fn capture_event(&mut self, cx: &mut EventContext, event: &mut Event) {}
+
     fn draw(&self, cx: &mut DrawContext, canvas: &mut Canvas) {
         draw_view(cx, canvas);
     }
@@ -100,6 +108,10 @@ where
         <T as View>::event(self, cx, event);
     }
 
+    fn capture_event(&mut self, cx: &mut EventContext, event: &mut Event) {
+        <T as View>::capture_event(self, cx, event);
+    }
+
     fn draw(&self, cx: &mut DrawContext, canvas: &mut Canvas) {
         <T as View>::draw(self, cx, canvas);
     }
@@ -117,7 +129,7 @@ where
     }
 }
 
-fn draw_view(cx: &mut DrawContext, canvas: &mut Canvas) {
+pub(crate) fn draw_view(cx: &mut DrawContext, canvas: &mut Canvas) {
     let bounds = cx.bounds();
 
     //Skip widgets with no width or no height
@@ -547,8 +559,12 @@ fn draw_view(cx: &mut DrawContext, canvas: &mut Canvas) {
         let mut box_w = bounds.w - border_width * 2.0;
         let mut box_h = bounds.h - border_width * 2.0;
 
-        let child_left = cx.child_left().unwrap_or_default();
-        let child_right = cx.child_right().unwrap_or_default();
+        let mut child_left = cx.child_left().unwrap_or_default();
+        let mut child_right = cx.child_right().unwrap_or_default();
+        // Content is aligned from the right edge in a right-to-left view.
+        if cx.layout_direction().is_rtl() {
+            std::mem::swap(&mut child_left, &mut child_right);
+        }
         let child_top = cx.child_top().unwrap_or_default();
         let child_bottom = cx.child_bottom().unwrap_or_default();
 
@@ -610,6 +626,7 @@ fn draw_view(cx: &mut DrawContext, canvas: &mut Canvas) {
             cx.draw_highlights(canvas, (origin_x, origin_y), (justify_x, justify_y));
             cx.draw_caret(canvas, (origin_x, origin_y), (justify_x, justify_y), 1.0);
             cx.draw_text(canvas, (origin_x, origin_y), (justify_x, justify_y));
+            cx.draw_preedit(canvas, (origin_x, origin_y), (justify_x, justify_y), 1.0);
         }
     }
 }
@@ -0,0 +1,189 @@
+use std::any::Any;
+
+use morphorm::PositionType;
+use vizia_input::Code;
+use vizia_storage::TreeIterator;
+
+use crate::context::InternalEvent;
+use crate::prelude::*;
+use crate::tree::is_navigatable;
+
+/// Events which close the dialog containing the view which emits them.
+///
+/// The result type must match the result type of the callback which the dialog was opened with,
+/// otherwise the event is ignored.
+///
+/// This type is part of the prelude.
+#[derive(Debug, Clone, PartialEq)]
+pub enum DialogEvent<R> {
+    /// Closes the dialog and calls its result callback with a result.
+    Close(R),
+    /// Closes the dialog and calls its result callback without a result, as pressing escape does.
+    Cancel,
+}
+
+/// A modal dialog, which is opened with [`Context::open_dialog`] or [`EventContext::open_dialog`].
+///
+/// A dialog covers the window of the view which opened it with a `dialog` element, which dims
+/// the rest of the window and stops it from being clicked, and displays its content in a
+/// `dialog_box` element in the middle of the window. Keyboard focus moves into the dialog and is
+/// kept there until the dialog closes, after which the view which was focused before is focused
+/// again. Dialogs can be opened from within other dialogs, in which case the most recent dialog is
+/// displayed above the others and closes first.
+///
+/// The content of a dialog closes it by emitting a [`DialogEvent`], and pressing escape closes
+/// it without a result. The result callback is then called in the context of the view which
+/// opened the dialog, so that the events it emits propagate from that view.
+///
+/// The `dialog_box` has the `dialog` accessibility role, and is named by the label built by
+/// [`Dialog::title`].
+pub struct Dialog {
+    // The view which opened the dialog.
+    opener: Entity,
+    // Takes the result out of a close event with the result type of the dialog. Returns `None` if
+    // the event is not a close event of that type.
+    take_result: fn(&mut Event) -> Option<Option<Box<dyn Any>>>,
+    on_result: Option<Box<dyn FnOnce(&mut EventContext, Option<Box<dyn Any>>)>>,
+}
+
+impl Dialog {
+    // Builds a dialog into the window of the view which opened it.
+    pub(crate) fn open<R, F, C>(cx: &mut Context, opener: Entity, content: F, on_result: C)
+    where
+        R: 'static + Send,
+        F: FnOnce(&mut Context),
+        C: 'static + FnOnce(&mut EventContext, Option<R>),
+    {
+        let on_result: Box<dyn FnOnce(&mut EventContext, Option<Box<dyn Any>>)> =
+            Box::new(move |cx, result| {
+                let result = result.and_then(|result| result.downcast::<R>().ok());
+                (on_result)(cx, result.map(|result| *result));
+            });
+
+        let window = cx.window_of(opener);
+        cx.with_current(window, |cx| {
+            Self { opener, take_result: take_result::<R>, on_result: Some(on_result) }
+                .build(cx, |cx| {
+                    // The role is set before the content is built so that the title can find the
+                    // dialog box.
+                    let dialog_box =
+                        VStack::new(cx, |_| {}).class("dialog_box").role(Role::Dialog).entity;
+                    cx.with_current(dialog_box, content);
+
+                    // Focus is kept within the dialog box, and the previous focus is restored from
+                    // the focus stack when the focused view is removed with the dialog.
+                    cx.tree.set_lock_focus_within(dialog_box, true);
+                    cx.focus_stack.push(cx.focused);
+                    let focus = TreeIterator::subtree(&cx.tree, dialog_box)
+                        .find(|entity| is_navigatable(cx, *entity, dialog_box))
+                        .unwrap_or(dialog_box);
+                    cx.with_current(focus, |cx| cx.focus());
+                })
+                .position_type(PositionType::SelfDirected)
+                .z_order(100);
+        });
+    }
+
+    /// Builds a label with the title of a dialog, which names the dialog for assistive
+    /// technologies. Must be called from the content of the dialog.
+    ///
+    /// # Example
+    /// ```
+    /// # use vizia_core::prelude::*;
+    /// # let cx = &mut Context::default();
+    /// cx.open_dialog(
+    ///     |cx| {
+    ///         Dialog::title(cx, "Save changes?");
+    ///         Button::new(cx, |cx| cx.emit(DialogEvent::Close(true)), |cx| {
+    ///             Label::new(cx, "Save")
+    ///         });
+    ///     },
+    ///     |_, save: Option<bool>| println!("Save: {:?}", save),
+    /// );
+    /// ```
+    pub fn title<'a, T>(cx: &'a mut Context, text: impl Res<T> + Clone) -> Handle<'a, Label>
+    where
+        T: ToString,
+    {
+        let dialog_box = cx
+            .current()
+            .parent_iter(&cx.tree)
+            .find(|entity| cx.style.roles.get(*entity) == Some(&Role::Dialog));
+
+        let handle = Label::new(cx, text).class("dialog_title");
+        if let Some(dialog_box) = dialog_box {
+            handle.cx.style.labelled_by.insert(dialog_box, handle.entity).unwrap();
+            handle.cx.style.needs_access_update(dialog_box);
+        }
+
+        handle
+    }
+
+    // Removes the dialog and calls the result callback in the context of the view which opened
+    // the dialog, or of the window if that view has been removed.
+    fn close(&mut self, cx: &mut EventContext, result: Option<Box<dyn Any>>) {
+        let on_result = match self.on_result.take() {
+            Some(on_result) => on_result,
+            None => return,
+        };
+
+        let dialog = cx.current();
+        cx.event_queue.push_back(
+            Event::new(InternalEvent::Remove(dialog))
+                .target(dialog)
+                .origin(dialog)
+                .propagate(Propagation::Direct),
+        );
+
+        if self.opener == Entity::root() || cx.tree.get_parent(self.opener).is_some() {
+            cx.current = self.opener;
+        } else {
+            cx.current = cx.tree.get_parent(dialog).unwrap_or(Entity::root());
+        }
+
+        (on_result)(cx, result);
+        cx.current = dialog;
+    }
+}
+
+impl View for Dialog {
+    fn element(&self) -> Option<&'static str> {
+        Some("dialog")
+    }
+
+    fn event(&mut self, cx: &mut EventContext, event: &mut Event) {
+        if let Some(result) = (self.take_result)(event) {
+            self.close(cx, result);
+            return;
+        }
+
+        let mut cancel = false;
+        event.map(|window_event, meta| match window_event {
+            WindowEvent::KeyDown(Code::Escape, _) => {
+                cancel = true;
+                meta.consume();
+            }
+
+            // Clicks on the dimmed background are not passed on to the window.
+            WindowEvent::MouseDown(_) | WindowEvent::MouseUp(_) => {
+                if meta.target == cx.current() {
+                    meta.consume();
+                }
+            }
+
+            _ => {}
+        });
+
+        if cancel {
+            self.close(cx, None);
+        }
+    }
+}
+
+// Takes the result out of a dialog event with a result type, consuming the event.
+fn take_result<R: 'static + Send>(event: &mut Event) -> Option<Option<Box<dyn Any>>> {
+    event.take::<DialogEvent<R>>().map(|dialog_event| match dialog_event {
+        DialogEvent::Close(result) => Some(Box::new(result) as Box<dyn Any>),
+        DialogEvent::Cancel => None,
+    })
+}
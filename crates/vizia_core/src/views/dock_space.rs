@@ -0,0 +1,644 @@
+use std::rc::Rc;
+
+use morphorm::PositionType;
+
+use super::tab_view::TabDrag;
+use crate::fonts::icons_names::{DOWN, LEFT, PLUS, RIGHT, UP};
+use crate::prelude::*;
+
+// The smallest inner width and height of the window of a floating panel in logical pixels.
+const MIN_WINDOW_SIZE: f32 = 200.0;
+
+/// An area of a [`DockSpace`] in which panels can be docked.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Data)]
+pub enum DockArea {
+    Left,
+    Right,
+    Bottom,
+    Center,
+}
+
+impl DockArea {
+    /// All of the areas of a dock space.
+    pub const ALL: [DockArea; 4] =
+        [DockArea::Left, DockArea::Right, DockArea::Bottom, DockArea::Center];
+}
+
+/// The panels docked in an area of a [`DockSpace`], which are shown as tabs.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, PartialEq, Data)]
+pub struct DockZone {
+    /// The names of the panels in the order of their tabs.
+    pub panels: Vec<String>,
+    /// The name of the panel whose tab is active, or an empty string if the zone has no panels.
+    pub active: String,
+    /// The width of the left and right zones, or the height of the bottom zone, in logical
+    /// pixels. The center zone fills the remaining space, so its size is not used.
+    pub size: f32,
+}
+
+impl DockZone {
+    /// Creates a new zone with the names of its panels, the first of which is active, and a size
+    /// in logical pixels.
+    pub fn new(panels: &[&str], size: f32) -> Self {
+        Self {
+            panels: panels.iter().map(|panel| panel.to_string()).collect(),
+            active: panels.first().map(|panel| panel.to_string()).unwrap_or_default(),
+            size,
+        }
+    }
+
+    // Removes a panel from the zone, activating the panel which takes its place if it was active.
+    // Returns true if the zone contained the panel.
+    fn remove(&mut self, panel: &str) -> bool {
+        let index = match self.panels.iter().position(|name| name == panel) {
+            Some(index) => index,
+            None => return false,
+        };
+
+        self.panels.remove(index);
+        if self.active == panel {
+            self.active =
+                self.panels.get(index).or_else(|| self.panels.last()).cloned().unwrap_or_default();
+        }
+
+        true
+    }
+}
+
+/// A panel of a [`DockSpace`] which is displayed in a separate window.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, PartialEq, Data)]
+pub struct FloatingPanel {
+    /// The name of the panel.
+    pub panel: String,
+    /// The area which the panel is docked in again when its window is closed.
+    pub area: DockArea,
+    /// The inner width of the window in logical pixels.
+    pub width: u32,
+    /// The inner height of the window in logical pixels.
+    pub height: u32,
+}
+
+/// A description of the panels of a [`DockSpace`], which can be serialized with the `serde`
+/// feature so that the layout can be saved and restored.
+///
+/// Panels are identified by unique names, which are also the titles of their tabs and windows.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, PartialEq, Data)]
+pub struct DockLayout {
+    pub left: DockZone,
+    pub right: DockZone,
+    pub bottom: DockZone,
+    pub center: DockZone,
+    pub floating: Vec<FloatingPanel>,
+}
+
+impl Default for DockLayout {
+    fn default() -> Self {
+        Self {
+            left: DockZone::new(&[], 200.0),
+            right: DockZone::new(&[], 200.0),
+            bottom: DockZone::new(&[], 150.0),
+            center: DockZone::new(&[], 0.0),
+            floating: Vec::new(),
+        }
+    }
+}
+
+impl DockLayout {
+    /// Returns the zone of an area.
+    pub fn zone(&self, area: DockArea) -> &DockZone {
+        match area {
+            DockArea::Left => &self.left,
+            DockArea::Right => &self.right,
+            DockArea::Bottom => &self.bottom,
+            DockArea::Center => &self.center,
+        }
+    }
+
+    /// Returns a mutable reference to the zone of an area.
+    pub fn zone_mut(&mut self, area: DockArea) -> &mut DockZone {
+        match area {
+            DockArea::Left => &mut self.left,
+            DockArea::Right => &mut self.right,
+            DockArea::Bottom => &mut self.bottom,
+            DockArea::Center => &mut self.center,
+        }
+    }
+
+    /// Returns the area which a panel is docked in, or `None` if the panel is floating or is not
+    /// part of the layout.
+    pub fn area_of(&self, panel: &str) -> Option<DockArea> {
+        DockArea::ALL
+            .into_iter()
+            .find(|area| self.zone(*area).panels.iter().any(|name| name == panel))
+    }
+
+    /// Removes a panel from the layout, whether it is docked or floating.
+    pub fn remove(&mut self, panel: &str) {
+        for area in DockArea::ALL {
+            self.zone_mut(area).remove(panel);
+        }
+
+        self.floating.retain(|floating| floating.panel != panel);
+    }
+
+    /// Docks a panel as the last tab of an area and makes it active, moving it from where it was
+    /// docked or closing its window if it was floating.
+    pub fn dock(&mut self, panel: &str, area: DockArea) {
+        self.remove(panel);
+
+        let zone = self.zone_mut(area);
+        zone.panels.push(panel.to_string());
+        zone.active = panel.to_string();
+    }
+
+    /// Moves a docked panel into a window with an inner size in logical pixels.
+    pub fn float(&mut self, panel: &str, width: u32, height: u32) {
+        if let Some(area) = self.area_of(panel) {
+            self.zone_mut(area).remove(panel);
+            self.floating.push(FloatingPanel { panel: panel.to_string(), area, width, height });
+        }
+    }
+
+    /// Docks a floating panel in the area it was floated from.
+    pub fn restore(&mut self, panel: &str) {
+        if let Some(floating) = self.floating.iter().find(|floating| floating.panel == panel) {
+            let area = floating.area;
+            self.dock(panel, area);
+        }
+    }
+
+    /// Moves the tab of a panel in an area from one position to another.
+    pub fn move_tab(&mut self, area: DockArea, from: usize, to: usize) {
+        let zone = self.zone_mut(area);
+        if from < zone.panels.len() && to < zone.panels.len() {
+            let panel = zone.panels.remove(from);
+            zone.panels.insert(to, panel);
+        }
+    }
+
+    // Returns the size of the zone of an area, which is zero if the zone has no panels.
+    fn visible_size(&self, area: DockArea) -> f32 {
+        let zone = self.zone(area);
+        if zone.panels.is_empty() {
+            0.0
+        } else {
+            zone.size
+        }
+    }
+
+    // Sets the size of the zone of an area, unless it has no panels and so is hidden.
+    fn set_size(&mut self, area: DockArea, size: f32) {
+        let zone = self.zone_mut(area);
+        if !zone.panels.is_empty() {
+            zone.size = size;
+        }
+    }
+}
+
+enum DockEvent {
+    // A tab was dragged over the target of an area, or away from it.
+    Preview(Option<DockArea>),
+    // The tab of a panel in a tab view was dropped onto the target of an area.
+    Drop(Entity, String, DockArea),
+    // The tab of a panel in an area was pressed.
+    Select(DockArea, String),
+    // The tab of a panel in an area was dragged onto another of its tabs.
+    Move(DockArea, usize, usize),
+    // The float button of the tab of a panel was pressed.
+    Float(String),
+    // The window of a floating panel was closed.
+    Restore(String),
+    // The handles of the left and right zones, or of the bottom zone, were moved.
+    ResizeSides(Vec<f32>),
+    ResizeBottom(Vec<f32>),
+}
+
+// A target which a tab can be dropped onto to dock its panel in an area.
+struct DockTarget {
+    area: DockArea,
+}
+
+impl View for DockTarget {
+    fn event(&mut self, cx: &mut EventContext, event: &mut Event) {
+        event.map(|drag_event, _| match drag_event {
+            DragEvent::DragEnter => cx.emit(DockEvent::Preview(Some(self.area))),
+            DragEvent::DragLeave => cx.emit(DockEvent::Preview(None)),
+            _ => {}
+        });
+    }
+}
+
+/// A view which arranges named panels as tabs in left, right, bottom and center areas, which can
+/// be resized, rearranged and floated into separate windows.
+///
+/// The arrangement of the panels is given by a lens to a [`DockLayout`]. The dock space does not
+/// change the layout itself, but calls the [`on_change`](Handle::on_change) callback with the
+/// changed layout, which should be set in the bound data. With the `serde` feature the layout can
+/// then be saved with the project and restored later.
+///
+/// The content of the panels is built by a template from their names. The left, right and bottom
+/// areas are separated from the center by the handles of a [`HSplit`] and a [`VSplit`], and are
+/// hidden when they have no panels. While the tab of a panel is dragged, targets for each area are
+/// shown in the middle of the dock space, and dropping the tab onto one of them docks the panel
+/// in that area. A `dock_preview` element shows where the panel will be docked while the tab is
+/// over a target. Pressing the float button of a tab moves the panel into a [`Window`], and
+/// closing the window docks the panel where it was before.
+///
+/// # Example
+/// ```
+/// # use vizia_core::prelude::*;
+/// # let cx = &mut Context::default();
+/// # #[derive(Lens)]
+/// # pub struct AppData {
+/// #     layout: DockLayout,
+/// # }
+/// # pub enum AppEvent {
+/// #     SetLayout(DockLayout),
+/// # }
+/// # impl Model for AppData {}
+/// # AppData { layout: DockLayout::default() }.build(cx);
+/// DockSpace::new(cx, AppData::layout, |cx, panel| {
+///     Label::new(cx, panel);
+/// })
+/// .on_change(|cx, layout| cx.emit(AppEvent::SetLayout(layout)));
+/// ```
+#[derive(Lens)]
+pub struct DockSpace<L: Lens<Target = DockLayout>> {
+    #[lens(ignore)]
+    lens: L,
+    // The tab views of the areas.
+    #[lens(ignore)]
+    zones: Vec<(DockArea, Entity)>,
+    // Whether the tab of a panel of the dock space is being dragged, and the area of the target
+    // which it is over.
+    dragging: bool,
+    preview: Option<DockArea>,
+    on_change: Option<Box<dyn Fn(&mut EventContext, DockLayout)>>,
+}
+
+impl<L> DockSpace<L>
+where
+    L: Lens<Target = DockLayout>,
+    <L as Lens>::Source: 'static,
+{
+    /// Creates a new dock space with a lens to its layout and a template for constructing the
+    /// content of a panel from its name.
+    pub fn new<F>(cx: &mut Context, lens: L, content: F) -> Handle<Self>
+    where
+        F: 'static + Fn(&mut Context, &str),
+        <L as Lens>::Source: Model,
+    {
+        let content: Rc<dyn Fn(&mut Context, &str)> = Rc::new(content);
+
+        Self {
+            lens: lens.clone(),
+            zones: Vec::new(),
+            dragging: false,
+            preview: None,
+            on_change: None,
+        }
+        .build(cx, move |cx| {
+            let dock = cx.current();
+
+            // Drag events are only sent to the dragged tab, so the dock space listens for them
+            // to show the targets while one of its tabs is dragged.
+            cx.add_listener(|dock: &mut Self, cx, event| {
+                event.map(|drag_event, meta| match drag_event {
+                    DragEvent::DragStart => {
+                        if dock.is_tab(cx, meta.target) {
+                            dock.dragging = true;
+                        }
+                    }
+
+                    // The tab may have been removed by the drop, so any drag which ends
+                    // hides the targets.
+                    DragEvent::DragEnd(_) => {
+                        dock.dragging = false;
+                        dock.preview = None;
+                    }
+
+                    _ => {}
+                });
+            });
+
+            let sides = lens.clone().map(|layout| {
+                vec![layout.visible_size(DockArea::Left), layout.visible_size(DockArea::Right)]
+            });
+            let bottom = lens.clone().map(|layout| vec![layout.visible_size(DockArea::Bottom)]);
+
+            let (left_lens, center_lens, bottom_lens, right_lens) =
+                (lens.clone(), lens.clone(), lens.clone(), lens.clone());
+            let (left_content, center_content, bottom_content, right_content) =
+                (content.clone(), content.clone(), content.clone(), content.clone());
+            HSplit::new(
+                cx,
+                sides,
+                vec![
+                    SplitPane::new(move |cx| {
+                        Self::build_zone(cx, dock, left_lens, left_content, DockArea::Left);
+                    }),
+                    SplitPane::new(move |cx| {
+                        VSplit::new(
+                            cx,
+                            bottom,
+                            vec![
+                                SplitPane::new(move |cx| {
+                                    let area = DockArea::Center;
+                                    Self::build_zone(cx, dock, center_lens, center_content, area);
+                                })
+                                .stretch(),
+                                SplitPane::new(move |cx| {
+                                    let area = DockArea::Bottom;
+                                    Self::build_zone(cx, dock, bottom_lens, bottom_content, area);
+                                }),
+                            ],
+                        )
+                        .on_resize(|cx, sizes| cx.emit(DockEvent::ResizeBottom(sizes.to_vec())));
+                    })
+                    .stretch(),
+                    SplitPane::new(move |cx| {
+                        Self::build_zone(cx, dock, right_lens, right_content, DockArea::Right);
+                    }),
+                ],
+            )
+            .on_resize(|cx, sizes| cx.emit(DockEvent::ResizeSides(sizes.to_vec())));
+
+            Element::new(cx)
+                .class("dock_preview")
+                .hoverable(false)
+                .position_type(PositionType::SelfDirected)
+                .left(Self::preview.map(|preview| Percentage(preview_bounds(*preview).0)))
+                .top(Self::preview.map(|preview| Percentage(preview_bounds(*preview).1)))
+                .width(Self::preview.map(|preview| Percentage(preview_bounds(*preview).2)))
+                .height(Self::preview.map(|preview| Percentage(preview_bounds(*preview).3)))
+                .display(Self::preview.map(|preview| preview.is_some()));
+
+            HStack::new(cx, |cx| {
+                Self::build_target(cx, DockArea::Left, LEFT);
+                VStack::new(cx, |cx| {
+                    Self::build_target(cx, DockArea::Center, PLUS);
+                    Self::build_target(cx, DockArea::Bottom, DOWN);
+                });
+                Self::build_target(cx, DockArea::Right, RIGHT);
+            })
+            .class("dock_targets")
+            .position_type(PositionType::SelfDirected)
+            .display(Self::dragging);
+
+            // The windows of the floating panels are part of the dock space so that they can
+            // send events to it, but take no space in it.
+            List::new_keyed(
+                cx,
+                lens.map(|layout| layout.floating.clone()),
+                |floating| floating.panel.clone(),
+                move |cx, floating| {
+                    let floating = floating.get(cx);
+                    let (panel, name) = (floating.panel.clone(), floating.panel.clone());
+                    let content = content.clone();
+                    Window::new(cx, move |cx| (content)(cx, &panel))
+                        .title(floating.panel.as_str())
+                        .inner_size((floating.width, floating.height))
+                        .on_close(move |cx| cx.emit_to(dock, DockEvent::Restore(name.clone())));
+                },
+            )
+            .position_type(PositionType::SelfDirected)
+            .size(Pixels(0.0));
+        })
+    }
+
+    // Builds the tab view of an area and adds it to the zones of the dock space.
+    fn build_zone(
+        cx: &mut Context,
+        dock: Entity,
+        lens: L,
+        content: Rc<dyn Fn(&mut Context, &str)>,
+        area: DockArea,
+    ) where
+        <L as Lens>::Source: Model,
+    {
+        let zone = TabView::new(
+            cx,
+            lens.clone().map(move |layout| layout.zone(area).panels.clone()),
+            lens.map(move |layout| layout.zone(area).active.clone()),
+            |panel| panel.clone(),
+            |cx, panel| {
+                let name = panel.get(cx);
+                Label::new(cx, panel);
+                Label::new(cx, UP)
+                    .class("icon")
+                    .class("float_button")
+                    .on_press(move |cx| cx.emit(DockEvent::Float(name.clone())));
+            },
+            move |cx, panel| (content)(cx, &panel.get(cx)),
+        )
+        .on_select(move |cx, panel| cx.emit(DockEvent::Select(area, panel)))
+        .on_move(move |cx, from, to| cx.emit(DockEvent::Move(area, from, to)))
+        .class("dock_zone")
+        .entity;
+
+        if let Some(view) = cx.views.get_mut(&dock).and_then(|view| view.downcast_mut::<Self>()) {
+            view.zones.push((area, zone));
+        }
+    }
+
+    // Builds the target which docks a dropped tab in an area.
+    fn build_target(cx: &mut Context, area: DockArea, icon: &'static str) {
+        DockTarget { area }
+            .build(cx, |cx| {
+                Label::new(cx, icon).class("icon");
+            })
+            .class("dock_target")
+            .drop_target(
+                |_: &TabDrag<String>| true,
+                move |cx, drag: TabDrag<String>| {
+                    cx.emit(DockEvent::Drop(drag.tab_view, drag.key, area));
+                },
+            );
+    }
+
+    // Returns true if an entity is in the tab bar of one of the tab views of the dock space,
+    // rather than in the content of a panel.
+    fn is_tab(&self, cx: &EventContext, entity: Entity) -> bool {
+        for ancestor in entity.parent_iter(cx.tree) {
+            if self.zones.iter().any(|(_, zone)| *zone == ancestor) {
+                return true;
+            }
+
+            if cx.style.classes.get(ancestor).map_or(false, |classes| classes.contains("tab_panel"))
+            {
+                return false;
+            }
+        }
+
+        false
+    }
+}
+
+impl<L> View for DockSpace<L>
+where
+    L: Lens<Target = DockLayout>,
+    <L as Lens>::Source: 'static,
+{
+    fn element(&self) -> Option<&'static str> {
+        Some("dockspace")
+    }
+
+    fn event(&mut self, cx: &mut EventContext, event: &mut Event) {
+        event.map(|dock_event, meta| {
+            meta.consume();
+
+            let mut layout = self.lens.get(cx);
+            match dock_event {
+                DockEvent::Preview(area) => {
+                    self.preview = *area;
+                    return;
+                }
+
+                DockEvent::Drop(tab_view, panel, area) => {
+                    self.dragging = false;
+                    self.preview = None;
+                    if !self.zones.iter().any(|(_, zone)| zone == tab_view) {
+                        return;
+                    }
+
+                    layout.dock(panel, *area);
+                }
+
+                DockEvent::Select(area, panel) => layout.zone_mut(*area).active = panel.clone(),
+
+                DockEvent::Move(area, from, to) => layout.move_tab(*area, *from, *to),
+
+                DockEvent::Float(panel) => {
+                    // The window has the size of the area which the panel was docked in.
+                    let zone = layout.area_of(panel).and_then(|area| {
+                        self.zones.iter().find(|(zone_area, _)| *zone_area == area)
+                    });
+                    let (width, height) = match zone {
+                        Some((_, zone)) => (
+                            cx.style.physical_to_logical(cx.cache.get_width(*zone)),
+                            cx.style.physical_to_logical(cx.cache.get_height(*zone)),
+                        ),
+                        None => return,
+                    };
+
+                    layout.float(
+                        panel,
+                        width.max(MIN_WINDOW_SIZE) as u32,
+                        height.max(MIN_WINDOW_SIZE) as u32,
+                    );
+                }
+
+                DockEvent::Restore(panel) => layout.restore(panel),
+
+                DockEvent::ResizeSides(sizes) => {
+                    if let [left, right] = sizes.as_slice() {
+                        layout.set_size(DockArea::Left, *left);
+                        layout.set_size(DockArea::Right, *right);
+                    }
+                }
+
+                DockEvent::ResizeBottom(sizes) => {
+                    if let [bottom] = sizes.as_slice() {
+                        layout.set_size(DockArea::Bottom, *bottom);
+                    }
+                }
+            }
+
+            if let Some(callback) = &self.on_change {
+                (callback)(cx, layout);
+            }
+        });
+    }
+}
+
+impl<L> Handle<'_, DockSpace<L>>
+where
+    L: Lens<Target = DockLayout>,
+{
+    /// Sets the callback which is called with the changed layout when the panels are resized,
+    /// rearranged, floated or docked.
+    pub fn on_change<F>(self, callback: F) -> Self
+    where
+        F: 'static + Fn(&mut EventContext, DockLayout),
+    {
+        self.modify(|dock| dock.on_change = Some(Box::new(callback)))
+    }
+}
+
+// Returns the left, top, width and height of the preview of docking a panel in an area, as
+// percentages of the size of the dock space.
+fn preview_bounds(area: Option<DockArea>) -> (f32, f32, f32, f32) {
+    match area {
+        Some(DockArea::Left) => (0.0, 0.0, 25.0, 100.0),
+        Some(DockArea::Right) => (75.0, 0.0, 25.0, 100.0),
+        Some(DockArea::Bottom) => (0.0, 75.0, 100.0, 25.0),
+        Some(DockArea::Center) => (25.0, 0.0, 50.0, 75.0),
+        None => (0.0, 0.0, 0.0, 0.0),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn layout() -> DockLayout {
+        DockLayout {
+            left: DockZone::new(&["Browser"], 200.0),
+            bottom: DockZone::new(&["Mixer", "Console"], 150.0),
+            center: DockZone::new(&["Arrangement"], 0.0),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_dock() {
+        let mut layout = layout();
+        layout.dock("Mixer", DockArea::Right);
+        assert_eq!(layout.bottom.panels, vec!["Console"]);
+        assert_eq!(layout.bottom.active, "Console");
+        assert_eq!(layout.right.panels, vec!["Mixer"]);
+        assert_eq!(layout.right.active, "Mixer");
+        assert_eq!(layout.area_of("Mixer"), Some(DockArea::Right));
+
+        layout.dock("Browser", DockArea::Bottom);
+        assert!(layout.left.panels.is_empty());
+        assert_eq!(layout.left.active, "");
+        assert_eq!(layout.bottom.panels, vec!["Console", "Browser"]);
+        assert_eq!(layout.visible_size(DockArea::Left), 0.0);
+    }
+
+    #[test]
+    fn test_float_and_restore() {
+        let mut layout = layout();
+        layout.float("Console", 300, 200);
+        assert_eq!(layout.bottom.panels, vec!["Mixer"]);
+        assert_eq!(layout.area_of("Console"), None);
+        assert_eq!(
+            layout.floating,
+            vec![FloatingPanel {
+                panel: String::from("Console"),
+                area: DockArea::Bottom,
+                width: 300,
+                height: 200
+            }]
+        );
+
+        layout.restore("Console");
+        assert!(layout.floating.is_empty());
+        assert_eq!(layout.bottom.panels, vec!["Mixer", "Console"]);
+        assert_eq!(layout.bottom.active, "Console");
+    }
+
+    #[test]
+    fn test_move_tab() {
+        let mut layout = layout();
+        layout.move_tab(DockArea::Bottom, 1, 0);
+        assert_eq!(layout.bottom.panels, vec!["Console", "Mixer"]);
+        layout.move_tab(DockArea::Bottom, 0, 5);
+        assert_eq!(layout.bottom.panels, vec!["Console", "Mixer"]);
+    }
+}
@@ -158,7 +158,8 @@ impl Dropdown {
                     (content)(cx);
                 })
                 .on_blur(|cx| cx.emit(PopupEvent::Close))
-                .top(Percentage(100.0))
+                .placement(Placement::BottomStart)
+                .match_trigger_width(true)
                 .height(Auto);
             })
             .size(Auto)
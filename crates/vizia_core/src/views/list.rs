@@ -1,7 +1,13 @@
 use crate::prelude::*;
-use crate::state::{Index, Then};
+use crate::state::{Index, KeyedIndex, Then};
+use instant::Duration;
+use std::cell::{Cell, RefCell};
+use std::collections::HashMap;
+use std::hash::Hash;
 use std::marker::PhantomData;
+use std::rc::Rc;
 use vizia_input::Code;
+use vizia_storage::ChildIterator;
 
 /// A view for creating a list of items from a binding to a Vec<T>
 pub struct List<L, T: 'static>
@@ -12,6 +18,8 @@ where
     increment_callback: Option<Box<dyn Fn(&mut EventContext)>>,
     decrement_callback: Option<Box<dyn Fn(&mut EventContext)>>,
     clear_callback: Option<Box<dyn Fn(&mut EventContext)>>,
+    enter_animation: Option<Animation>,
+    exit_animation: Option<(Animation, Duration)>,
 }
 
 impl<L: 'static + Lens<Target = Vec<T>>, T: Clone> List<L, T> {
@@ -27,6 +35,8 @@ impl<L: 'static + Lens<Target = Vec<T>>, T: Clone> List<L, T> {
             increment_callback: None,
             decrement_callback: None,
             clear_callback: None,
+            enter_animation: None,
+            exit_animation: None,
         }
         .build(cx, move |cx| {
             //let list_lens = lens.clone();
@@ -43,6 +53,207 @@ impl<L: 'static + Lens<Target = Vec<T>>, T: Clone> List<L, T> {
             });
         })
     }
+
+    /// Creates a new keyed List view with a binding to the given lens, a function which returns a
+    /// unique key for each item, and a template for constructing the list items.
+    ///
+    /// Unlike [`List::new`], which rebuilds every item when the length of the list changes, the
+    /// items of a keyed list are matched to the views built for them by key. When items are
+    /// inserted, removed or moved only the views of inserted items are built, the views of removed
+    /// items are removed, and the views of moved items are moved, so that views which remain in
+    /// the list keep their focus and local state. The lens passed to the template continues to
+    /// refer to the same item when it moves.
+    ///
+    /// # Example
+    /// ```ignore
+    /// List::new_keyed(cx, AppData::todos, |todo| todo.id, |cx, todo| {
+    ///     Textbox::new(cx, todo.then(Todo::text));
+    /// });
+    /// ```
+    pub fn new_keyed<K, G, F>(cx: &mut Context, lens: L, key: G, item: F) -> Handle<Self>
+    where
+        K: 'static + Data + Eq + Hash,
+        G: 'static + Fn(&T) -> K,
+        F: 'static + Fn(&mut Context, Then<L, KeyedIndex<T, K>>),
+        <L as Lens>::Source: Model,
+    {
+        let key: Rc<dyn Fn(&T) -> K> = Rc::new(key);
+        List {
+            p: PhantomData::default(),
+            increment_callback: None,
+            decrement_callback: None,
+            clear_callback: None,
+            enter_animation: None,
+            exit_animation: None,
+        }
+        .build(cx, move |cx| {
+            let list = cx.current();
+            let rows = RefCell::new(KeyedRows { rows: Vec::new(), built: false });
+            let key_of = key.clone();
+            let keys =
+                lens.clone().map(move |items| items.iter().map(|t| key_of(t)).collect::<Vec<K>>());
+            // Bind to the keys of the list data
+            Binding::new(cx, keys, move |cx, keys| {
+                let keys = keys.get_fallible(cx).unwrap_or_default();
+                let build = |cx: &mut Context, k: &K, index: Rc<Cell<usize>>| {
+                    let ptr = lens.clone().then(KeyedIndex::new(k.clone(), index, key.clone()));
+                    (item)(cx, ptr);
+                };
+                let (enter, exit) = cx
+                    .views
+                    .get(&list)
+                    .and_then(|view| view.downcast_ref::<List<L, T>>())
+                    .map_or((None, None), |view| (view.enter_animation, view.exit_animation));
+                rows.borrow_mut().update(cx, list, &keys, enter, exit, build);
+            });
+        })
+    }
+}
+
+// The views built for the items of a keyed list, in the order of the items.
+struct KeyedRows<K> {
+    rows: Vec<KeyedRow<K>>,
+    // Whether the initial rows have been built, after which new rows play the enter animation.
+    built: bool,
+}
+
+struct KeyedRow<K> {
+    key: K,
+    // The views built by the item template, which are moved together.
+    entities: Vec<Entity>,
+    index: Rc<Cell<usize>>,
+}
+
+impl<K: Clone + Eq + Hash> KeyedRows<K> {
+    // Builds rows for new keys, removes the rows of keys which are no longer in the list, and moves
+    // the remaining rows into the order of the keys. Must be called from the binding on the keys,
+    // which is the first child of the list.
+    fn update<F>(
+        &mut self,
+        cx: &mut Context,
+        list: Entity,
+        keys: &[K],
+        enter: Option<Animation>,
+        exit: Option<(Animation, Duration)>,
+        build: F,
+    ) where
+        F: Fn(&mut Context, &K, Rc<Cell<usize>>),
+    {
+        let binding = cx.current();
+
+        let mut old = HashMap::with_capacity(self.rows.len());
+        let mut removed = Vec::new();
+        for (position, mut row) in self.rows.drain(..).enumerate() {
+            // Entities of a row can be removed by something else, such as a window being closed.
+            row.entities.retain(|&entity| cx.entity_manager.is_alive(entity));
+            if let Some((_, duplicate)) = old.insert(row.key.clone(), (position, row)) {
+                removed.push(duplicate);
+            }
+        }
+
+        // New rows are built after the last child of the list and then moved into place.
+        let mut last = ChildIterator::new(&cx.tree, list).last().unwrap_or(binding);
+        let mut rows = Vec::with_capacity(keys.len());
+        let mut kept = Vec::with_capacity(keys.len());
+        let mut positions = Vec::new();
+        for (index, key) in keys.iter().enumerate() {
+            if let Some((position, row)) = old.remove(key) {
+                row.index.set(index);
+                rows.push(row);
+                kept.push(true);
+                positions.push(position);
+                continue;
+            }
+
+            let index = Rc::new(Cell::new(index));
+            cx.with_current(list, |cx| build(cx, key, index.clone()));
+
+            let mut entities = Vec::new();
+            while let Some(entity) = cx.tree.get_next_sibling(last) {
+                entities.push(entity);
+                last = entity;
+            }
+
+            if let Some(animation) = enter.filter(|_| self.built) {
+                for &entity in entities.iter() {
+                    cx.with_current(entity, |cx| EventContext::new(cx).play_animation(animation));
+                }
+            }
+
+            rows.push(KeyedRow { key: key.clone(), entities, index });
+            kept.push(false);
+        }
+
+        removed.extend(old.into_values().map(|(_, row)| row));
+        for row in removed {
+            for entity in row.entities {
+                if let Some((animation, duration)) = exit {
+                    // The item of the row no longer exists, so the row is kept as it is until the
+                    // animation has finished.
+                    cx.unbind(entity);
+                    cx.with_current(entity, |cx| EventContext::new(cx).play_animation(animation));
+                    cx.remove_after(entity, duration);
+                } else {
+                    cx.remove(entity);
+                }
+            }
+        }
+
+        // Rows in the longest run which is still in order keep their place, and the others are
+        // moved after the row before them, which keeps the number of moves to a minimum.
+        let mut in_place = longest_increasing_subsequence(&positions).into_iter();
+        let mut prev = binding;
+        let mut moved = false;
+        for (row, kept) in rows.iter().zip(kept) {
+            let stays = kept && in_place.next() == Some(true);
+            for &entity in row.entities.iter() {
+                if !stays && cx.tree.get_next_sibling(prev) != Some(entity) {
+                    cx.tree.set_next_sibling(prev, entity).expect("Failed to move list item");
+                    moved = true;
+                }
+
+                prev = entity;
+            }
+        }
+
+        if moved {
+            cx.style.needs_restyle();
+            cx.style.needs_relayout();
+            cx.style.needs_redraw();
+            cx.style.needs_access_update(list);
+        }
+
+        self.rows = rows;
+        self.built = true;
+    }
+}
+
+// Returns which of the values are part of a longest strictly increasing subsequence.
+fn longest_increasing_subsequence(values: &[usize]) -> Vec<bool> {
+    // The index of the smallest value which ends an increasing subsequence of each length.
+    let mut tails: Vec<usize> = Vec::new();
+    let mut prev = vec![None; values.len()];
+    for (i, value) in values.iter().enumerate() {
+        let len = tails.partition_point(|&j| values[j] < *value);
+        if len > 0 {
+            prev[i] = Some(tails[len - 1]);
+        }
+
+        if len == tails.len() {
+            tails.push(i);
+        } else {
+            tails[len] = i;
+        }
+    }
+
+    let mut result = vec![false; values.len()];
+    let mut next = tails.last().copied();
+    while let Some(i) = next {
+        result[i] = true;
+        next = prev[i];
+    }
+
+    result
 }
 
 impl<L: 'static + Lens<Target = Vec<T>>, T> View for List<L, T> {
@@ -127,4 +338,48 @@ impl<L: Lens<Target = Vec<T>>, T: Data> Handle<'_, List<L, T>> {
 
         self
     }
+
+    /// Sets an animation which is played on the views of items which are inserted into a keyed
+    /// list, see [`List::new_keyed`].
+    pub fn enter_animation(self, animation: Animation) -> Self {
+        if let Some(list) =
+            self.cx.views.get_mut(&self.entity).and_then(|f| f.downcast_mut::<List<L, T>>())
+        {
+            list.enter_animation = Some(animation);
+        }
+
+        self
+    }
+
+    /// Sets an animation which is played on the views of items which are removed from a keyed
+    /// list, see [`List::new_keyed`]. The views are removed once the duration has elapsed, and no
+    /// longer update while the animation plays.
+    pub fn exit_animation(self, animation: Animation, duration: Duration) -> Self {
+        if let Some(list) =
+            self.cx.views.get_mut(&self.entity).and_then(|f| f.downcast_mut::<List<L, T>>())
+        {
+            list.exit_animation = Some((animation, duration));
+        }
+
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_longest_increasing_subsequence() {
+        assert!(longest_increasing_subsequence(&[]).is_empty());
+        assert_eq!(longest_increasing_subsequence(&[0, 1, 2]), vec![true, true, true]);
+        // Moving the first item to the end only moves that item.
+        assert_eq!(longest_increasing_subsequence(&[1, 2, 3, 0]), vec![true, true, true, false]);
+        // Moving the last item to the start only moves that item.
+        assert_eq!(longest_increasing_subsequence(&[3, 0, 1, 2]), vec![false, true, true, true]);
+        assert_eq!(
+            longest_increasing_subsequence(&[4, 0, 3, 1, 2]),
+            vec![false, true, false, true, true]
+        );
+    }
 }
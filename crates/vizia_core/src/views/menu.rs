@@ -1,7 +1,12 @@
-use std::cell::RefCell;
+use std::cell::{Cell, RefCell};
 
-use crate::fonts::{icons_names::CHECK, material_names::RIGHT};
+use super::popup::{place_main, set_position};
+use crate::fonts::{
+    icons_names::CHECK,
+    material_names::{LEFT, RIGHT},
+};
 use crate::prelude::*;
+use crate::view::draw_view;
 use vizia_storage::TreeExt;
 
 /// A helper function which sets up the necessary attributes on a view to be a menu entry.
@@ -20,8 +25,8 @@ where
     F2: 'static + Fn(&mut Context),
 {
     if let Some(data) = handle.cx.data::<MenuData>() {
-        let i = *data.counter.borrow();
-        *data.counter.borrow_mut() += 1;
+        let i = data.entries.borrow().len();
+        data.entries.borrow_mut().push(handle.entity);
         handle
             .navigable(true)
             .bind(MenuData::selected, move |handle, selected| {
@@ -48,7 +53,8 @@ where
 #[derive(Lens, Default)]
 struct MenuData {
     selected: Option<usize>,
-    counter: RefCell<usize>,
+    // The menu entries of the stack in the order they were built.
+    entries: RefCell<Vec<Entity>>,
 }
 
 struct MenuControllerData {
@@ -207,6 +213,115 @@ impl View for MenuStack {
     fn element(&self) -> Option<&'static str> {
         Some("menustack")
     }
+
+    fn event(&mut self, cx: &mut EventContext, event: &mut Event) {
+        event.map(|window_event, meta| {
+            // Only the menu stack with keyboard focus is navigated with the keyboard.
+            let code = match window_event {
+                WindowEvent::KeyDown(code, _) if meta.target == cx.current() => *code,
+                _ => return,
+            };
+
+            let (selected, entries) = match cx.data::<MenuData>() {
+                Some(data) => (data.selected, data.entries.borrow().clone()),
+                None => return,
+            };
+
+            // Submenus open towards the end of the line.
+            let (open_key, close_key) = if cx.style.layout_direction(cx.current()).is_rtl() {
+                (Code::ArrowLeft, Code::ArrowRight)
+            } else {
+                (Code::ArrowRight, Code::ArrowLeft)
+            };
+
+            match code {
+                Code::ArrowDown | Code::ArrowUp if !entries.is_empty() => {
+                    let count = entries.len();
+                    let next = match (selected, code) {
+                        (Some(index), Code::ArrowDown) => (index + 1) % count,
+                        (Some(index), _) => (index + count - 1) % count,
+                        (None, Code::ArrowDown) => 0,
+                        (None, _) => count - 1,
+                    };
+
+                    cx.emit(MenuEvent::SetSelected(Some(next)));
+                    meta.consume();
+                }
+
+                Code::Enter | Code::NumpadEnter | Code::Space => {
+                    if let Some(&entry) = selected.and_then(|index| entries.get(index)) {
+                        if let Some(submenu) = submenu_stack(cx, entry) {
+                            open_submenu(cx, submenu);
+                        } else {
+                            cx.emit_to(entry, WindowEvent::PressDown { mouse: false });
+                        }
+
+                        meta.consume();
+                    }
+                }
+
+                _ if code == open_key => {
+                    if let Some(submenu) = selected
+                        .and_then(|index| entries.get(index))
+                        .and_then(|entry| submenu_stack(cx, *entry))
+                    {
+                        open_submenu(cx, submenu);
+                        meta.consume();
+                    }
+                }
+
+                _ if code == close_key => {
+                    // Return to the menu stack containing the submenu of this stack.
+                    let parent_stack = cx
+                        .tree
+                        .get_parent(cx.current())
+                        .filter(|parent| is_view::<Menu>(cx, *parent))
+                        .and_then(|menu| {
+                            menu.parent_iter(cx.tree)
+                                .find(|entity| is_view::<MenuStack>(cx, *entity))
+                        });
+
+                    if let Some(parent_stack) = parent_stack {
+                        cx.emit(MenuEvent::SetSelected(None));
+                        focus_entity(cx, parent_stack);
+                        meta.consume();
+                    }
+                }
+
+                Code::Escape => {
+                    cx.emit(MenuEvent::Close);
+                    meta.consume();
+                }
+
+                _ => {}
+            }
+        });
+    }
+}
+
+fn is_view<V: View>(cx: &EventContext, entity: Entity) -> bool {
+    cx.views.get(&entity).map_or(false, |view| view.downcast_ref::<V>().is_some())
+}
+
+fn focus_entity(cx: &mut EventContext, entity: Entity) {
+    let current = cx.current;
+    cx.current = entity;
+    cx.focus_with_visibility(true);
+    cx.current = current;
+}
+
+// Returns the menu stack of a submenu if the entry is a submenu.
+fn submenu_stack(cx: &EventContext, entry: Entity) -> Option<Entity> {
+    if !is_view::<Menu>(cx, entry) {
+        return None;
+    }
+
+    entry.child_iter(cx.tree).find(|child| is_view::<MenuStack>(cx, *child))
+}
+
+fn open_submenu(cx: &mut EventContext, submenu: Entity) {
+    focus_entity(cx, submenu);
+    cx.emit_to(submenu, MenuEvent::SetSelected(Some(0)));
 }
 
 /// A button containing a menu when you click/hover it.
@@ -224,7 +339,7 @@ impl Menu {
         let result = Self {}.build(cx, move |cx| {
             HStack::new(cx, move |cx| {
                 label(cx);
-                Label::new(cx, RIGHT).class("menu_arrow");
+                MenuArrow::new(cx).class("menu_arrow");
             });
             MenuStack::new_vertical(cx, items);
         });
@@ -250,6 +365,39 @@ impl View for Menu {
     }
 }
 
+// The arrow of a menu which opens a submenu. Submenus open towards the end of the line, so the
+// arrow points left when the resolved layout direction of the menu is right-to-left, whether it
+// comes from the locale or from an explicit direction.
+struct MenuArrow {
+    // Whether the arrow currently points left.
+    rtl: Cell<bool>,
+}
+
+impl MenuArrow {
+    fn new(cx: &mut Context) -> Handle<Self> {
+        Self { rtl: Cell::new(false) }.build(cx, |_| {}).text(RIGHT)
+    }
+}
+
+impl View for MenuArrow {
+    fn element(&self) -> Option<&'static str> {
+        Some("label")
+    }
+
+    // The direction is resolved by the style system, and a change of direction redraws the menu,
+    // so the glyph is chosen when the arrow is drawn. Both glyphs have the same size, so the
+    // layout of the arrow does not change.
+    fn draw(&self, cx: &mut DrawContext, canvas: &mut Canvas) {
+        let rtl = cx.layout_direction().is_rtl();
+        if rtl != self.rtl.get() {
+            self.rtl.set(rtl);
+            cx.text_context.set_text(cx.current, if rtl { LEFT } else { RIGHT });
+        }
+
+        draw_view(cx, canvas);
+    }
+}
+
 /// A MenuButton is an entry in a menu that can be clicked to perform some action. It has various
 /// constructors depending on whether you want to make this button show a check icon conditionally.
 pub struct MenuButton {
@@ -350,3 +498,199 @@ impl View for MenuButton {
         });
     }
 }
+
+pub(crate) enum ContextMenuEvent {
+    // Opens the context menu at a position in physical pixels.
+    Open(f32, f32),
+}
+
+/// A popup menu which opens at the cursor when a view is right clicked, or below the view when the
+/// menu key or Shift+F10 is pressed while it has keyboard focus.
+///
+/// A context menu is added to a view with the `context_menu` modifier. The menu is kept inside
+/// the window bounds, and closes when an entry is pressed, when the user clicks outside of it, or
+/// when escape is pressed. The menu is built into the window of the view, so that it is not
+/// clipped by the ancestors of the view, and is removed with the view. Events which are emitted by
+/// the entries and not handled within the menu continue up the tree from the view.
+#[derive(Lens)]
+pub struct ContextMenu {
+    is_open: bool,
+    // The position at which the menu was opened, in physical pixels.
+    anchor: (f32, f32),
+    previous_focus: Entity,
+    // The view which the menu is built on.
+    target: Entity,
+}
+
+impl ContextMenu {
+    // Builds a context menu for the current view into the window of the view, so that the menu is
+    // not clipped by the ancestors of the view.
+    pub(crate) fn new<F>(cx: &mut Context, content: F)
+    where
+        F: 'static + Fn(&mut Context),
+    {
+        let target = cx.current();
+        let window = cx.window_of(target);
+        let mut menu = Entity::null();
+        cx.with_current(window, |cx| {
+            let context_menu =
+                Self { is_open: false, anchor: (0.0, 0.0), previous_focus: Entity::null(), target };
+            menu = context_menu
+                .build(cx, move |cx| {
+                    Binding::new(cx, ContextMenu::is_open, move |cx, is_open| {
+                        if is_open.get(cx) {
+                            MenuController::new(cx, true, |cx| {
+                                MenuStack::new_vertical(cx, |cx| (content)(cx))
+                                    .role(Role::Menu)
+                                    .on_build(|cx| cx.focus());
+                            });
+                        }
+                    });
+
+                    // Clicks outside of the menu are captured by its menu controller, so a
+                    // listener is used to close the menu.
+                    cx.add_listener(|context_menu: &mut ContextMenu, cx, event| {
+                        event.map(|window_event, _| {
+                            if let WindowEvent::MouseDown(_) = window_event {
+                                if !cx.hovered().is_descendant_of(cx.tree, cx.current()) {
+                                    context_menu.close(cx);
+                                }
+                            }
+                        });
+                    });
+                })
+                .position_type(PositionType::SelfDirected)
+                .size(Auto)
+                .z_order(100)
+                .entity;
+        });
+
+        cx.add_dependent(target, menu);
+        ContextMenuTrigger { menu }.build(cx);
+    }
+
+    fn close(&mut self, cx: &mut EventContext) {
+        if !self.is_open {
+            return;
+        }
+
+        self.is_open = false;
+
+        // Return keyboard focus to where it was before the menu was opened.
+        if cx.focused().is_descendant_of(cx.tree, cx.current()) {
+            let current = cx.current;
+            cx.current = if self.previous_focus != Entity::null() {
+                self.previous_focus
+            } else {
+                Entity::root()
+            };
+            cx.focus();
+            cx.current = current;
+        }
+    }
+
+    // Positions the menu below its anchor and after it in the layout direction of the view,
+    // moving it to the other side of the anchor when it does not fit within the window and there
+    // is more room on that side.
+    fn place(&self, cx: &mut EventContext) {
+        let menu = cx.current();
+        let window = cx.cache.get_bounds(cx.window_of(menu));
+        let bounds = cx.cache.get_bounds(menu);
+        let after = !cx.style.layout_direction(self.target).is_rtl();
+
+        let (x, y) = self.anchor;
+        let x = place_main(after, 0.0, x, x, bounds.w, window.x, window.right());
+        let y = place_main(true, 0.0, y, y, bounds.h, window.y, window.bottom());
+        set_position(cx, menu, x, y, bounds.w);
+    }
+
+    // Continues an event which was emitted within the menu and reached it without being handled
+    // from the view which the menu is built on, as if the view had emitted it.
+    fn forward(&self, cx: &mut EventContext, event: &mut Event) {
+        // Menu and window events belong to the menu, and are not continued from the view.
+        let forwarded = event.message.as_ref().map_or(false, |message| {
+            !message.as_ref().is::<MenuEvent>() && !message.as_ref().is::<WindowEvent>()
+        });
+
+        if !forwarded
+            || event.meta.phase() != EventPhase::Bubble
+            || !event.meta.origin.is_descendant_of(cx.tree, cx.current())
+        {
+            return;
+        }
+
+        let forwarded = Event { meta: Default::default(), message: event.message.take() }
+            .target(self.target)
+            .origin(self.target);
+        event.meta.consume();
+        cx.emit_custom(forwarded);
+    }
+}
+
+impl View for ContextMenu {
+    fn element(&self) -> Option<&'static str> {
+        Some("contextmenu")
+    }
+
+    fn event(&mut self, cx: &mut EventContext, event: &mut Event) {
+        event.map(|context_menu_event, meta| match context_menu_event {
+            ContextMenuEvent::Open(x, y) => {
+                if !self.is_open {
+                    self.previous_focus = cx.focused();
+                }
+
+                self.is_open = true;
+                self.anchor = (*x, *y);
+                self.place(cx);
+                meta.consume();
+            }
+        });
+
+        event.map(|menu_event, _| {
+            if let MenuEvent::Close = menu_event {
+                self.close(cx);
+            }
+        });
+
+        event.map(|window_event, meta| {
+            if let WindowEvent::GeometryChanged(_) = window_event {
+                if meta.target == cx.current() && self.is_open {
+                    self.place(cx);
+                }
+            }
+        });
+
+        self.forward(cx, event);
+    }
+}
+
+// Opens a context menu when the view which it is built on, or a descendant, is right clicked or
+// receives the menu key or Shift+F10.
+struct ContextMenuTrigger {
+    menu: Entity,
+}
+
+impl Model for ContextMenuTrigger {
+    fn event(&mut self, cx: &mut EventContext, event: &mut Event) {
+        event.map(|window_event, meta| match window_event {
+            WindowEvent::MouseDown(MouseButton::Right) => {
+                let (x, y) = (cx.mouse.cursorx, cx.mouse.cursory);
+                cx.emit_to(self.menu, ContextMenuEvent::Open(x, y));
+                meta.consume();
+            }
+
+            WindowEvent::KeyDown(code, _) => {
+                let pressed = *code == Code::ContextMenu
+                    || (*code == Code::F10 && *cx.modifiers == Modifiers::SHIFT);
+
+                if pressed {
+                    let bounds = cx.cache.get_bounds(cx.current());
+                    cx.emit_to(self.menu, ContextMenuEvent::Open(bounds.x, bounds.bottom()));
+                    meta.consume();
+                }
+            }
+
+            _ => {}
+        });
+    }
+}
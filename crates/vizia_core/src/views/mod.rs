@@ -4,6 +4,8 @@
 
 mod button;
 mod checkbox;
+mod dialog;
+mod dock_space;
 mod dropdown;
 mod element;
 mod image;
@@ -12,32 +14,52 @@ mod label;
 mod list;
 mod menu;
 pub mod normalized_map;
+mod notification;
 mod popup;
 mod radio_buttons;
 mod scrollbar;
 mod scrollview;
 mod slider;
+mod split;
 mod stack;
+mod tab_view;
 mod table;
 mod textbox;
+mod tooltip;
+mod tree_view;
+mod virtual_list;
+mod window;
 
 pub use self::image::Image;
 pub use button::Button;
 pub use checkbox::Checkbox;
+pub use dialog::{Dialog, DialogEvent};
+pub use dock_space::{DockArea, DockLayout, DockSpace, DockZone, FloatingPanel};
 pub use dropdown::Dropdown;
 pub use element::Element;
 pub use knob::{ArcTrack, Knob, KnobMode, TickKnob, Ticks};
 pub use label::Label;
 pub use list::List;
-pub use menu::{setup_menu_entry, Menu, MenuButton, MenuController, MenuEvent, MenuStack};
-pub use popup::{Popup, PopupData, PopupEvent};
+pub use menu::{
+    setup_menu_entry, ContextMenu, Menu, MenuButton, MenuController, MenuEvent, MenuStack,
+};
+pub use notification::{
+    Notification, NotificationEvent, Notifications, Severity, NOTIFICATION_TIMEOUT,
+};
+pub use popup::{Placement, Popup, PopupData, PopupEvent};
 pub use radio_buttons::RadioButton;
 pub use scrollbar::Scrollbar;
 pub use scrollview::{ScrollData, ScrollEvent, ScrollView};
 pub use slider::Slider;
+pub use split::{HSplit, Split, SplitPane, VSplit};
 pub use stack::{HStack, VStack, ZStack};
-pub use table::{Table, TableColumn};
+pub use tab_view::TabView;
+pub use table::{SelectionMode, SortDirection, Table, TableColumn, TableEvent};
 pub use textbox::{TextEvent, Textbox};
+pub use tooltip::{Tooltip, TOOLTIP_DELAY};
+pub use tree_view::{TreeDropPosition, TreeView, TreeViewEvent};
+pub use virtual_list::{VirtualList, VirtualListEvent};
+pub use window::Window;
 
 use crate::prelude::*;
 
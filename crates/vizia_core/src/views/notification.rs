@@ -0,0 +1,328 @@
+use std::sync::Arc;
+
+use instant::{Duration, Instant};
+use morphorm::PositionType;
+
+use crate::fonts::icons_names::CANCEL;
+use crate::prelude::*;
+
+/// The time for which a notification is shown before it is dismissed, unless it is changed with
+/// [`Notification::timeout`].
+pub const NOTIFICATION_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// The severity of a notification, which is added to its toast as a class so that it can be
+/// styled.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Data)]
+pub enum Severity {
+    /// Adds the `info` class.
+    Info,
+    /// Adds the `success` class.
+    Success,
+    /// Adds the `warning` class.
+    Warning,
+    /// Adds the `error` class.
+    Error,
+}
+
+impl Severity {
+    fn class_name(&self) -> &'static str {
+        match self {
+            Severity::Info => "info",
+            Severity::Success => "success",
+            Severity::Warning => "warning",
+            Severity::Error => "error",
+        }
+    }
+}
+
+impl Default for Severity {
+    fn default() -> Self {
+        Severity::Info
+    }
+}
+
+/// A transient message which is shown in a toast when it is sent with
+/// [`NotificationEvent::Show`].
+///
+/// # Example
+/// ```
+/// # use vizia_core::prelude::*;
+/// # let cx = &mut Context::default();
+/// cx.emit(NotificationEvent::Show(
+///     Notification::new("Export failed")
+///         .severity(Severity::Error)
+///         .timeout(None)
+///         .action("Retry", |_| println!("Retrying export")),
+/// ));
+/// ```
+#[derive(Clone)]
+pub struct Notification {
+    message: String,
+    severity: Severity,
+    timeout: Option<Duration>,
+    actions: Vec<(String, Arc<dyn Fn(&mut EventContext) + Send + Sync>)>,
+}
+
+impl Notification {
+    /// Creates a notification with a message, which has the `Info` severity and is dismissed after
+    /// [`NOTIFICATION_TIMEOUT`].
+    pub fn new(message: impl ToString) -> Self {
+        Self {
+            message: message.to_string(),
+            severity: Severity::default(),
+            timeout: Some(NOTIFICATION_TIMEOUT),
+            actions: Vec::new(),
+        }
+    }
+
+    /// Sets the severity of the notification.
+    pub fn severity(mut self, severity: Severity) -> Self {
+        self.severity = severity;
+        self
+    }
+
+    /// Sets the time for which the notification is shown, or `None` to show it until it is
+    /// dismissed.
+    pub fn timeout(mut self, timeout: Option<Duration>) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// Adds a button to the notification, which calls the callback and dismisses the notification
+    /// when it is pressed.
+    pub fn action<F>(mut self, label: impl ToString, callback: F) -> Self
+    where
+        F: 'static + Fn(&mut EventContext) + Send + Sync,
+    {
+        self.actions.push((label.to_string(), Arc::new(callback)));
+        self
+    }
+}
+
+/// Events which are handled by the [`Notifications`] of the window of the view which emits them.
+pub enum NotificationEvent {
+    /// Shows a notification.
+    Show(Notification),
+    /// Dismisses all of the notifications which are shown.
+    DismissAll,
+}
+
+// Dismisses a toast when its dismiss button or one of its action buttons is pressed.
+enum ToastEvent {
+    Dismiss(u64),
+}
+
+#[derive(Clone)]
+struct Toast {
+    id: u64,
+    notification: Notification,
+}
+
+// The timeout of a toast, which is paused while the cursor is over the notifications.
+struct Timeout {
+    id: u64,
+    remaining: Duration,
+    // The timer which dismisses the toast and the time at which it was started, if it is running.
+    running: Option<(Timer, Instant)>,
+}
+
+#[derive(Lens)]
+struct NotificationData {
+    toasts: Vec<Toast>,
+    #[lens(ignore)]
+    timeouts: Vec<Timeout>,
+    next_id: u64,
+    paused: bool,
+}
+
+impl NotificationData {
+    fn show(&mut self, cx: &mut EventContext, notification: Notification) {
+        let id = self.next_id;
+        self.next_id += 1;
+
+        if let Some(timeout) = notification.timeout {
+            let running = if self.paused {
+                None
+            } else {
+                Some((cx.add_timer(timeout, false, cx.current()), Instant::now()))
+            };
+            self.timeouts.push(Timeout { id, remaining: timeout, running });
+        }
+
+        self.toasts.push(Toast { id, notification });
+    }
+
+    fn dismiss(&mut self, cx: &mut EventContext, id: u64) {
+        if let Some(index) = self.timeouts.iter().position(|timeout| timeout.id == id) {
+            if let Some((timer, _)) = self.timeouts.remove(index).running {
+                cx.cancel_timer(timer);
+            }
+        }
+
+        self.toasts.retain(|toast| toast.id != id);
+    }
+
+    fn set_paused(&mut self, cx: &mut EventContext, paused: bool) {
+        if self.paused == paused {
+            return;
+        }
+
+        self.paused = paused;
+        for timeout in self.timeouts.iter_mut() {
+            if paused {
+                if let Some((timer, started)) = timeout.running.take() {
+                    cx.cancel_timer(timer);
+                    timeout.remaining = timeout.remaining.saturating_sub(started.elapsed());
+                }
+            } else if timeout.running.is_none() {
+                let timer = cx.add_timer(timeout.remaining, false, cx.current());
+                timeout.running = Some((timer, Instant::now()));
+            }
+        }
+    }
+}
+
+impl Model for NotificationData {
+    fn event(&mut self, cx: &mut EventContext, event: &mut Event) {
+        event.map(|notification_event, meta| {
+            match notification_event {
+                NotificationEvent::Show(notification) => {
+                    self.show(cx, notification.clone());
+                }
+
+                NotificationEvent::DismissAll => {
+                    let ids = self.toasts.iter().map(|toast| toast.id).collect::<Vec<_>>();
+                    for id in ids {
+                        self.dismiss(cx, id);
+                    }
+                }
+            }
+
+            meta.consume();
+        });
+
+        event.map(|toast_event, meta| {
+            let ToastEvent::Dismiss(id) = toast_event;
+            self.dismiss(cx, *id);
+            meta.consume();
+        });
+
+        event.map(|timer_event, meta| {
+            let TimerEvent::Tick(timer) = timer_event;
+            let id = self
+                .timeouts
+                .iter()
+                .find(|timeout| matches!(timeout.running, Some((running, _)) if running == *timer))
+                .map(|timeout| timeout.id);
+            if let Some(id) = id {
+                self.dismiss(cx, id);
+                meta.consume();
+            }
+        });
+
+        event.map(|window_event, meta| match window_event {
+            WindowEvent::MouseOver if meta.target == cx.current() => self.set_paused(cx, true),
+            WindowEvent::MouseOut if meta.target == cx.current() => self.set_paused(cx, false),
+            _ => {}
+        });
+    }
+}
+
+/// A stack of toasts which shows the notifications sent by the views of its window.
+///
+/// Notifications are shown by emitting [`NotificationEvent::Show`] from any view in the same
+/// window as the `Notifications`, which should be built once as the last child of the window.
+/// Each notification is shown in a `toast` element with the class of its severity, and is
+/// dismissed when its timeout elapses, when one of its buttons is pressed, or when its dismiss
+/// button is pressed. Timeouts are paused while the cursor is over the notifications.
+///
+/// The stack is placed in the bottom right corner of the window by default, which can be changed
+/// by styling the `notifications` element. It is a polite live region, so that assistive
+/// technologies announce new notifications without interrupting the user.
+///
+/// # Example
+/// ```
+/// # use vizia_core::prelude::*;
+/// # let cx = &mut Context::default();
+/// Button::new(
+///     cx,
+///     |cx| cx.emit(NotificationEvent::Show(Notification::new("Preset saved"))),
+///     |cx| Label::new(cx, "Save preset"),
+/// );
+///
+/// Notifications::new(cx);
+/// ```
+pub struct Notifications {}
+
+impl Notifications {
+    /// Creates a stack of toasts for the notifications sent by the views of the window.
+    pub fn new(cx: &mut Context) -> Handle<Self> {
+        Self {}
+            .build(cx, |cx| {
+                NotificationData {
+                    toasts: Vec::new(),
+                    timeouts: Vec::new(),
+                    next_id: 0,
+                    paused: false,
+                }
+                .build(cx);
+
+                // Notification events propagate up from the view which emits them, so they are
+                // redirected to the notifications of the same window.
+                cx.add_listener(|_: &mut Self, cx, event| {
+                    let notifications = cx.current();
+                    if event.meta.target == notifications
+                        || cx.window_of(event.meta.origin) != cx.window_of(notifications)
+                    {
+                        return;
+                    }
+
+                    if let Some(notification_event) = event.take::<NotificationEvent>() {
+                        cx.emit_to(notifications, notification_event);
+                    }
+                });
+
+                List::new_keyed(cx, NotificationData::toasts, |toast| toast.id, build_toast);
+            })
+            .live(Live::Polite)
+            .position_type(PositionType::SelfDirected)
+            .z_order(120)
+    }
+}
+
+impl View for Notifications {
+    fn element(&self) -> Option<&'static str> {
+        Some("notifications")
+    }
+}
+
+fn build_toast(cx: &mut Context, toast: impl Lens<Target = Toast>) {
+    let Toast { id, notification } = toast.get(cx);
+    let message = notification.message;
+    HStack::new(cx, |cx| {
+        Label::new(cx, message.as_str()).class("message");
+
+        for (label, callback) in notification.actions {
+            Button::new(
+                cx,
+                move |cx| {
+                    (callback)(cx);
+                    cx.emit(ToastEvent::Dismiss(id));
+                },
+                |cx| Label::new(cx, label.as_str()),
+            )
+            .class("action");
+        }
+
+        Label::new(cx, CANCEL)
+            .class("icon")
+            .class("dismiss_button")
+            .role(Role::Button)
+            .name("Dismiss")
+            .on_press(move |cx| cx.emit(ToastEvent::Dismiss(id)));
+    })
+    .class("toast")
+    .class(notification.severity.class_name())
+    .role(Role::Status)
+    .name(message.as_str());
+}
@@ -1,5 +1,6 @@
 use morphorm::PositionType;
 
+use crate::cache::BoundingBox;
 use crate::prelude::*;
 
 #[derive(Debug, Default, Data, Lens, Clone)]
@@ -35,8 +36,66 @@ pub enum PopupEvent {
     Switch,
 }
 
+/// Where a popup is placed relative to its trigger, which is the parent view of the popup.
+///
+/// The first part of the name is the side of the trigger on which the popup is placed, and the
+/// second part is the edge of the trigger which the popup is aligned to, with no second part
+/// centering the popup on the trigger. For example, `BottomStart` places the popup below the
+/// trigger with their left edges aligned.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Placement {
+    Top,
+    TopStart,
+    TopEnd,
+    Bottom,
+    BottomStart,
+    BottomEnd,
+    Left,
+    LeftStart,
+    LeftEnd,
+    Right,
+    RightStart,
+    RightEnd,
+}
+
+// The edge of the trigger along the cross axis which a popup is aligned to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Align {
+    Start,
+    Center,
+    End,
+}
+
+impl Placement {
+    // Returns whether the popup is placed above or below the trigger, whether it is placed after
+    // the trigger along the main axis, and its alignment along the cross axis.
+    fn parts(&self) -> (bool, bool, Align) {
+        match self {
+            Placement::Top => (true, false, Align::Center),
+            Placement::TopStart => (true, false, Align::Start),
+            Placement::TopEnd => (true, false, Align::End),
+            Placement::Bottom => (true, true, Align::Center),
+            Placement::BottomStart => (true, true, Align::Start),
+            Placement::BottomEnd => (true, true, Align::End),
+            Placement::Left => (false, false, Align::Center),
+            Placement::LeftStart => (false, false, Align::Start),
+            Placement::LeftEnd => (false, false, Align::End),
+            Placement::Right => (false, true, Align::Center),
+            Placement::RightStart => (false, true, Align::Start),
+            Placement::RightEnd => (false, true, Align::End),
+        }
+    }
+}
+
+// Positions the popup once it has been opened.
+struct PlacePopup;
+
 pub struct Popup<L> {
     lens: L,
+    placement: Option<Placement>,
+    // The gap between the popup and its trigger, in logical pixels.
+    offset: f32,
+    match_trigger_width: bool,
 }
 
 impl<L> Popup<L>
@@ -47,10 +106,12 @@ where
     where
         F: 'static + Fn(&mut Context),
     {
-        Self { lens: lens.clone() }
+        Self { lens: lens.clone(), placement: None, offset: 0.0, match_trigger_width: false }
             .build(cx, |cx| {
+                let popup = cx.current();
                 Binding::new(cx, lens.clone(), move |cx, lens| {
                     if lens.get(cx) {
+                        cx.emit_to(popup, PlacePopup);
                         if capture_focus {
                             VStack::new(cx, &content).lock_focus_to_within();
                         } else {
@@ -104,6 +165,69 @@ where
     }
 }
 
+impl<'a, L> Handle<'a, Popup<L>>
+where
+    L: Lens,
+{
+    /// Places the popup next to its trigger, which is the parent view of the popup, instead of
+    /// where its style places it.
+    ///
+    /// The popup is flipped to the opposite side of the trigger when it does not fit within the
+    /// window on the side given by the placement and there is more room on the opposite side, and
+    /// is shifted to keep it within the window.
+    ///
+    /// # Example
+    /// ```
+    /// # use vizia_core::prelude::*;
+    /// # let cx = &mut Context::default();
+    /// # PopupData::default().build(cx);
+    /// Popup::new(cx, PopupData::is_open, false, |cx| {
+    ///     Label::new(cx, "Popup");
+    /// })
+    /// .placement(Placement::BottomStart)
+    /// .offset(4.0);
+    /// ```
+    pub fn placement(self, placement: Placement) -> Self {
+        self.modify(|popup| popup.placement = Some(placement))
+    }
+
+    /// Sets the gap between a placed popup and its trigger, in logical pixels.
+    pub fn offset(self, offset: f32) -> Self {
+        self.modify(|popup| popup.offset = offset)
+    }
+
+    /// Sets whether a placed popup has the same width as its trigger.
+    pub fn match_trigger_width(self, flag: bool) -> Self {
+        self.modify(|popup| popup.match_trigger_width = flag)
+    }
+}
+
+impl<L> Popup<L> {
+    // Places the popup next to its trigger and within the window.
+    fn place(&self, cx: &mut EventContext) {
+        let placement = match self.placement {
+            Some(placement) => placement,
+            None => return,
+        };
+
+        let popup = cx.current();
+        let trigger = cx.tree.get_parent(popup).unwrap_or(Entity::root());
+        let trigger_bounds = cx.cache.get_bounds(trigger);
+        let window_bounds = cx.cache.get_bounds(cx.window_of(popup));
+        let mut bounds = cx.cache.get_bounds(popup);
+
+        if self.match_trigger_width {
+            let width = cx.style.physical_to_logical(trigger_bounds.w);
+            cx.style.width.insert(popup, Pixels(width));
+            bounds.w = trigger_bounds.w;
+        }
+
+        let offset = cx.style.logical_to_physical(self.offset);
+        let (x, y) = place(placement, offset, trigger_bounds, (bounds.w, bounds.h), window_bounds);
+        set_position(cx, popup, x, y, bounds.w);
+    }
+}
+
 impl<L> View for Popup<L>
 where
     L: Lens,
@@ -112,4 +236,168 @@ where
     fn element(&self) -> Option<&'static str> {
         Some("popup")
     }
+
+    fn event(&mut self, cx: &mut EventContext, event: &mut Event) {
+        event.map(|_: &PlacePopup, meta| {
+            self.place(cx);
+            meta.consume();
+        });
+
+        event.map(|window_event, meta| {
+            if let WindowEvent::GeometryChanged(_) = window_event {
+                if meta.target == cx.current() {
+                    self.place(cx);
+                }
+            }
+        });
+    }
+}
+
+// Moves a self-directed popup with a width to a position in physical pixels. The layout of the
+// children of a right-to-left parent is mirrored, so the left offset of the popup is then taken
+// from the right edge of its parent.
+pub(crate) fn set_position(cx: &mut EventContext, popup: Entity, x: f32, y: f32, width: f32) {
+    let parent = cx.tree.get_layout_parent(popup).unwrap_or(Entity::root());
+    let parent_bounds = cx.cache.get_bounds(parent);
+    let left = if cx.style.layout_direction(parent).is_rtl() {
+        parent_bounds.right() - x - width
+    } else {
+        x - parent_bounds.x
+    };
+
+    let left = cx.style.physical_to_logical(left);
+    let top = cx.style.physical_to_logical(y - parent_bounds.y);
+    cx.style.left.insert(popup, Pixels(left));
+    cx.style.top.insert(popup, Pixels(top));
+    cx.needs_relayout();
+}
+
+// Returns the position of a popup with a size which is placed next to a trigger, flipping it to
+// the opposite side of the trigger when it does not fit within the bounds and there is more room
+// on the opposite side, and shifting it to keep it within the bounds.
+pub(crate) fn place(
+    placement: Placement,
+    offset: f32,
+    trigger: BoundingBox,
+    size: (f32, f32),
+    bounds: BoundingBox,
+) -> (f32, f32) {
+    let (vertical, after, align) = placement.parts();
+    if vertical {
+        let x = place_cross(align, trigger.x, trigger.w, size.0, bounds.x, bounds.right());
+        let y = place_main(
+            after,
+            offset,
+            trigger.y,
+            trigger.bottom(),
+            size.1,
+            bounds.y,
+            bounds.bottom(),
+        );
+        (x, y)
+    } else {
+        let x =
+            place_main(after, offset, trigger.x, trigger.right(), size.0, bounds.x, bounds.right());
+        let y = place_cross(align, trigger.y, trigger.h, size.1, bounds.y, bounds.bottom());
+        (x, y)
+    }
+}
+
+// Places a popup before or after a trigger along one axis.
+pub(crate) fn place_main(
+    after: bool,
+    offset: f32,
+    start: f32,
+    end: f32,
+    size: f32,
+    bounds_start: f32,
+    bounds_end: f32,
+) -> f32 {
+    let before_position = start - offset - size;
+    let after_position = end + offset;
+    let room_before = start - bounds_start;
+    let room_after = bounds_end - end;
+
+    let after = if after {
+        after_position + size <= bounds_end || room_after >= room_before
+    } else {
+        before_position < bounds_start && room_after > room_before
+    };
+
+    let position = if after { after_position } else { before_position };
+    shift(position, size, bounds_start, bounds_end)
+}
+
+// Aligns a popup to a trigger along one axis.
+fn place_cross(
+    align: Align,
+    start: f32,
+    length: f32,
+    size: f32,
+    bounds_start: f32,
+    bounds_end: f32,
+) -> f32 {
+    let position = match align {
+        Align::Start => start,
+        Align::Center => start + (length - size) / 2.0,
+        Align::End => start + length - size,
+    };
+
+    shift(position, size, bounds_start, bounds_end)
+}
+
+// Shifts a popup along one axis to keep it within the bounds, keeping its start within the bounds
+// when it is larger than the bounds.
+fn shift(position: f32, size: f32, bounds_start: f32, bounds_end: f32) -> f32 {
+    position.min(bounds_end - size).max(bounds_start)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const WINDOW: BoundingBox = BoundingBox { x: 0.0, y: 0.0, w: 400.0, h: 300.0 };
+
+    #[test]
+    fn test_place() {
+        let trigger = BoundingBox { x: 100.0, y: 100.0, w: 80.0, h: 20.0 };
+
+        assert_eq!(
+            place(Placement::BottomStart, 4.0, trigger, (60.0, 50.0), WINDOW),
+            (100.0, 124.0)
+        );
+        assert_eq!(place(Placement::Bottom, 0.0, trigger, (60.0, 50.0), WINDOW), (110.0, 120.0));
+        assert_eq!(place(Placement::TopEnd, 0.0, trigger, (60.0, 50.0), WINDOW), (120.0, 50.0));
+        assert_eq!(
+            place(Placement::RightStart, 0.0, trigger, (60.0, 50.0), WINDOW),
+            (180.0, 100.0)
+        );
+        assert_eq!(place(Placement::LeftEnd, 0.0, trigger, (60.0, 50.0), WINDOW), (40.0, 70.0));
+    }
+
+    #[test]
+    fn test_place_flips_and_shifts() {
+        // There is no room below the trigger, so the popup flips above it.
+        let trigger = BoundingBox { x: 100.0, y: 260.0, w: 80.0, h: 20.0 };
+        assert_eq!(
+            place(Placement::BottomStart, 0.0, trigger, (60.0, 50.0), WINDOW),
+            (100.0, 210.0)
+        );
+
+        // There is no room above the trigger either, but there is more room below it, so the
+        // popup stays below and is shifted up into the window.
+        let trigger = BoundingBox { x: 100.0, y: 40.0, w: 80.0, h: 20.0 };
+        assert_eq!(place(Placement::Bottom, 0.0, trigger, (60.0, 260.0), WINDOW), (110.0, 40.0));
+        assert_eq!(place(Placement::Top, 0.0, trigger, (60.0, 260.0), WINDOW), (110.0, 40.0));
+
+        // The popup is shifted left to stay within the right edge of the window.
+        let trigger = BoundingBox { x: 360.0, y: 100.0, w: 40.0, h: 20.0 };
+        assert_eq!(
+            place(Placement::BottomStart, 0.0, trigger, (100.0, 50.0), WINDOW),
+            (300.0, 120.0)
+        );
+
+        // A popup which is wider than the window keeps its start within the window.
+        assert_eq!(place(Placement::Bottom, 0.0, trigger, (500.0, 50.0), WINDOW), (0.0, 120.0));
+    }
 }
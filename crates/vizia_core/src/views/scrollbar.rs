@@ -80,6 +80,14 @@ impl<L1: Lens<Target = f32>> Scrollbar<L1> {
         // delta is moving within the negative space of the thumb: (1 - ratio) * container
         let (size, thumb_size) = self.container_and_thumb_size(cx);
         let negative_space = size - thumb_size;
+        // The thumb of a right-to-left horizontal scrollbar starts on the right.
+        let physical_delta = if self.orientation == Orientation::Horizontal
+            && cx.style.layout_direction(cx.current()).is_rtl()
+        {
+            -physical_delta
+        } else {
+            physical_delta
+        };
         if negative_space == 0.0 {
             value_ref
         } else {
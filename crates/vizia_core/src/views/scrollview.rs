@@ -190,6 +190,9 @@ impl<L: Lens<Target = ScrollData>> View for ScrollView<L> {
                 let (x, y) =
                     if cx.modifiers.contains(Modifiers::SHIFT) { (-*y, -*x) } else { (-*x, -*y) };
 
+                // Content of a right-to-left scrollview is scrolled from the right edge.
+                let x = if cx.style.layout_direction(cx.current()).is_rtl() { -x } else { x };
+
                 // what percentage of the negative space does this cross?
                 let data = self.data.get(cx);
                 if x != 0.0 {
@@ -255,9 +255,18 @@ impl<L: Lens<Target = f32>> View for Slider<L> {
                 let posx = cx.cache.get_posx(current);
                 let posy = cx.cache.get_posy(current);
 
+                let rtl = cx.style.layout_direction(current).is_rtl();
+
                 let mut dx = match self.internal.orientation {
                     Orientation::Horizontal => {
-                        (cx.mouse.left.pos_down.0 - posx - thumb_size / 2.0) / (width - thumb_size)
+                        let dx = (cx.mouse.left.pos_down.0 - posx - thumb_size / 2.0)
+                            / (width - thumb_size);
+                        // The minimum value is on the right of a right-to-left slider.
+                        if rtl {
+                            1.0 - dx
+                        } else {
+                            dx
+                        }
                     }
 
                     Orientation::Vertical => {
@@ -299,9 +308,16 @@ impl<L: Lens<Target = f32>> View for Slider<L> {
                     let posx = cx.cache.get_posx(current);
                     let posy = cx.cache.get_posy(current);
 
+                    let rtl = cx.style.layout_direction(current).is_rtl();
+
                     let mut dx = match self.internal.orientation {
                         Orientation::Horizontal => {
-                            (*x - posx - thumb_size / 2.0) / (width - thumb_size)
+                            let dx = (*x - posx - thumb_size / 2.0) / (width - thumb_size);
+                            if rtl {
+                                1.0 - dx
+                            } else {
+                                dx
+                            }
                         }
 
                         Orientation::Vertical => {
@@ -322,23 +338,23 @@ impl<L: Lens<Target = f32>> View for Slider<L> {
                 }
             }
 
-            WindowEvent::KeyDown(Code::ArrowUp | Code::ArrowRight, _) => {
-                let min = self.internal.range.start;
-                let max = self.internal.range.end;
-                let step = self.internal.step;
-                let mut val = self.lens.get(cx) + step;
-                val = step * (val / step).ceil();
-                val = val.clamp(min, max);
-                if let Some(callback) = &self.on_changing {
-                    (callback)(cx, val);
-                }
-            }
+            WindowEvent::KeyDown(
+                code @ (Code::ArrowUp | Code::ArrowDown | Code::ArrowLeft | Code::ArrowRight),
+                _,
+            ) => {
+                // The horizontal arrow keys follow the visual direction of the slider.
+                let increment = match code {
+                    Code::ArrowUp => true,
+                    Code::ArrowDown => false,
+                    Code::ArrowRight => !cx.style.layout_direction(cx.current()).is_rtl(),
+                    _ => cx.style.layout_direction(cx.current()).is_rtl(),
+                };
 
-            WindowEvent::KeyDown(Code::ArrowDown | Code::ArrowLeft, _) => {
                 let min = self.internal.range.start;
                 let max = self.internal.range.end;
                 let step = self.internal.step;
-                let mut val = self.lens.get(cx) - step;
+                let mut val =
+                    if increment { self.lens.get(cx) + step } else { self.lens.get(cx) - step };
                 val = step * (val / step).ceil();
                 val = val.clamp(min, max);
                 if let Some(callback) = &self.on_changing {
@@ -0,0 +1,453 @@
+use std::marker::PhantomData;
+
+use vizia_input::Code;
+
+use crate::prelude::*;
+use crate::views::Orientation;
+
+// The distance in logical pixels which a focused handle moves a pane with each arrow key press.
+const KEYBOARD_STEP: f32 = 10.0;
+
+/// A pane of an [`HSplit`] or a [`VSplit`], which describes the size of the pane and builds its
+/// content.
+pub struct SplitPane {
+    content: Box<dyn FnOnce(&mut Context)>,
+    size: f32,
+    min_size: f32,
+    max_size: f32,
+    collapsible: bool,
+    stretch: bool,
+}
+
+impl SplitPane {
+    /// Creates a new pane with a template for constructing its content.
+    pub fn new<F>(content: F) -> Self
+    where
+        F: 'static + FnOnce(&mut Context),
+    {
+        Self {
+            content: Box::new(content),
+            size: 200.0,
+            min_size: 0.0,
+            max_size: f32::INFINITY,
+            collapsible: true,
+            stretch: false,
+        }
+    }
+
+    /// Sets the size of the pane in logical pixels, which is used until the pane is resized.
+    /// Defaults to 200 pixels.
+    pub fn size(mut self, size: f32) -> Self {
+        self.size = size;
+        self
+    }
+
+    /// Sets the size in logical pixels below which the pane cannot be resized. Defaults to 0
+    /// pixels.
+    pub fn min_size(mut self, min_size: f32) -> Self {
+        self.min_size = min_size;
+        self
+    }
+
+    /// Sets the size in logical pixels above which the pane cannot be resized. Defaults to no
+    /// limit.
+    pub fn max_size(mut self, max_size: f32) -> Self {
+        self.max_size = max_size;
+        self
+    }
+
+    /// Sets whether the pane can be collapsed by double clicking the handle next to it on the side
+    /// of the filling pane, or by pressing enter while the handle is focused. Defaults to true.
+    pub fn collapsible(mut self, collapsible: bool) -> Self {
+        self.collapsible = collapsible;
+        self
+    }
+
+    /// Makes the pane fill the space left by the other panes, instead of the last pane.
+    pub fn stretch(mut self) -> Self {
+        self.stretch = true;
+        self
+    }
+}
+
+/// A view which arranges panes side by side, with a handle between each pair of panes which can
+/// be dragged to resize them.
+///
+/// See [`Split`] for how the panes are sized and resized.
+pub struct HSplit {}
+
+impl HSplit {
+    /// Creates a new horizontal split with a binding to the sizes of the panes in logical pixels,
+    /// and the panes to display from left to right.
+    pub fn new<L>(cx: &mut Context, sizes: L, panes: Vec<SplitPane>) -> Handle<Split<L>>
+    where
+        L: Lens<Target = Vec<f32>>,
+        <L as Lens>::Source: 'static,
+    {
+        Split::build_split(cx, Orientation::Horizontal, sizes, panes)
+    }
+}
+
+/// A view which arranges panes above one another, with a handle between each pair of panes which
+/// can be dragged to resize them.
+///
+/// See [`Split`] for how the panes are sized and resized.
+pub struct VSplit {}
+
+impl VSplit {
+    /// Creates a new vertical split with a binding to the sizes of the panes in logical pixels,
+    /// and the panes to display from top to bottom.
+    pub fn new<L>(cx: &mut Context, sizes: L, panes: Vec<SplitPane>) -> Handle<Split<L>>
+    where
+        L: Lens<Target = Vec<f32>>,
+        <L as Lens>::Source: 'static,
+    {
+        Split::build_split(cx, Orientation::Vertical, sizes, panes)
+    }
+}
+
+/// A view which arranges panes along an orientation with draggable handles between them, created
+/// with [`HSplit::new`] or [`VSplit::new`].
+///
+/// The sizes of the panes are given by a binding to a `Vec<f32>`, with the size of each pane
+/// in logical pixels except the pane which fills the remaining space. This is the last pane unless
+/// another pane is made to [`stretch`](SplitPane::stretch). Panes without a size in the binding
+/// have the size they were created with. When a handle is dragged, or moved
+/// with the arrow keys while it has keyboard focus, the callback set with
+/// [`on_resize`](Handle::on_resize) is called with the new sizes, which should be set in the
+/// bound data. This keeps the sizes in the data of the application, where they can be saved.
+///
+/// Moving a handle resizes the panes on either side of it by opposite amounts, within the minimum
+/// and maximum sizes of both panes. Double clicking a handle, or pressing enter while it is
+/// focused, collapses the pane on the side of the handle away from the filling pane to a size of
+/// zero, and does so again to restore the pane.
+///
+/// The handles are `split_handle` elements with the `col-resize` or `row-resize` cursor, and the
+/// panes are `split_pane` elements.
+///
+/// # Example
+/// ```
+/// # use vizia_core::prelude::*;
+/// # let cx = &mut Context::default();
+/// # #[derive(Lens)]
+/// # pub struct AppData {
+/// #     sizes: Vec<f32>,
+/// # }
+/// # pub enum AppEvent {
+/// #     SetSizes(Vec<f32>),
+/// # }
+/// # impl Model for AppData {}
+/// # AppData { sizes: Vec::new() }.build(cx);
+/// HSplit::new(
+///     cx,
+///     AppData::sizes,
+///     vec![
+///         SplitPane::new(|cx| {
+///             Label::new(cx, "Sidebar");
+///         })
+///         .min_size(100.0),
+///         SplitPane::new(|cx| {
+///             Label::new(cx, "Editor");
+///         }),
+///     ],
+/// )
+/// .on_resize(|cx, sizes| cx.emit(AppEvent::SetSizes(sizes.to_vec())));
+/// ```
+pub struct Split<L> {
+    orientation: Orientation,
+    // The index of the pane which fills the remaining space.
+    fill: usize,
+    // The minimum and maximum size of each pane, and whether it can be collapsed.
+    limits: Vec<(f32, f32)>,
+    collapsible: Vec<bool>,
+    panes: Vec<Entity>,
+    handles: Vec<Entity>,
+    // The size of each collapsed pane before it was collapsed.
+    restore: Vec<Option<f32>>,
+    // The handle being dragged, the position of the cursor in physical pixels when the drag
+    // started, and the sizes of the panes at that time.
+    drag: Option<(usize, f32, Vec<f32>)>,
+    on_resize: Option<Box<dyn Fn(&mut EventContext, &[f32])>>,
+    p: PhantomData<L>,
+}
+
+impl<L> Split<L>
+where
+    L: Lens<Target = Vec<f32>>,
+    <L as Lens>::Source: 'static,
+{
+    fn build_split(
+        cx: &mut Context,
+        orientation: Orientation,
+        sizes: L,
+        panes: Vec<SplitPane>,
+    ) -> Handle<Self> {
+        let len = panes.len();
+        let horizontal = orientation == Orientation::Horizontal;
+        let fill = panes.iter().position(|pane| pane.stretch).unwrap_or(len.saturating_sub(1));
+        let defaults: Vec<f32> = panes.iter().map(|pane| pane.size).collect();
+
+        Self {
+            orientation,
+            fill,
+            limits: panes.iter().map(|pane| (pane.min_size, pane.max_size)).collect(),
+            collapsible: panes.iter().map(|pane| pane.collapsible).collect(),
+            panes: Vec::new(),
+            handles: Vec::new(),
+            restore: vec![None; len],
+            drag: None,
+            on_resize: None,
+            p: PhantomData::default(),
+        }
+        .build(cx, move |cx| {
+            let split = cx.current();
+            let mut entities = Vec::new();
+            let mut handles = Vec::new();
+            for (index, pane) in panes.into_iter().enumerate() {
+                // The filling pane has no size in the binding, so the sizes of the panes after
+                // it are one place earlier.
+                let slot = if index < fill { index } else { index.saturating_sub(1) };
+                let default = pane.size;
+                let size = sizes
+                    .clone()
+                    .map(move |sizes| Pixels(sizes.get(slot).copied().unwrap_or(default).max(0.0)));
+
+                let handle = VStack::new(cx, pane.content).class("split_pane");
+                let handle = if index == fill {
+                    handle.width(Stretch(1.0)).height(Stretch(1.0))
+                } else if horizontal {
+                    handle.width(size).height(Stretch(1.0))
+                } else {
+                    handle.width(Stretch(1.0)).height(size)
+                };
+                entities.push(handle.entity);
+
+                if index + 1 < len {
+                    // The value of a handle is the size of the pane which it collapses.
+                    let default = defaults[if index < fill { index } else { index + 1 }];
+                    let handle = Element::new(cx)
+                        .class("split_handle")
+                        .role(Role::Splitter)
+                        .numeric_value(
+                            sizes
+                                .clone()
+                                .map(move |sizes| sizes.get(index).copied().unwrap_or(default)),
+                        )
+                        .navigable(true);
+                    let handle = if horizontal {
+                        handle.cursor(CursorIcon::ColResize).height(Stretch(1.0))
+                    } else {
+                        handle.cursor(CursorIcon::RowResize).width(Stretch(1.0))
+                    };
+                    handles.push(handle.entity);
+                }
+            }
+
+            if let Some(view) =
+                cx.views.get_mut(&split).and_then(|view| view.downcast_mut::<Self>())
+            {
+                view.panes = entities;
+                view.handles = handles;
+            }
+        })
+    }
+
+    // Returns the laid out sizes of the panes in logical pixels.
+    fn pane_sizes(&self, cx: &EventContext) -> Vec<f32> {
+        self.panes
+            .iter()
+            .map(|pane| {
+                let size = match self.orientation {
+                    Orientation::Horizontal => cx.cache.get_width(*pane),
+                    Orientation::Vertical => cx.cache.get_height(*pane),
+                };
+                cx.style.physical_to_logical(size)
+            })
+            .collect()
+    }
+
+    // Returns the position of the cursor along the orientation of the split in physical pixels.
+    fn cursor(&self, cx: &EventContext) -> f32 {
+        match self.orientation {
+            Orientation::Horizontal => cx.mouse.cursorx,
+            Orientation::Vertical => cx.mouse.cursory,
+        }
+    }
+
+    // Calls the resize callback with the sizes of all of the panes except the filling pane.
+    fn set_sizes(&self, cx: &mut EventContext, sizes: &[f32]) {
+        if let Some(callback) = &self.on_resize {
+            let mut sizes = sizes.to_vec();
+            if self.fill < sizes.len() {
+                sizes.remove(self.fill);
+            }
+
+            (callback)(cx, &sizes);
+        }
+    }
+
+    // Moves a handle by an amount in logical pixels, resizing the panes on either side of it.
+    fn move_handle(&mut self, cx: &mut EventContext, handle: usize, delta: f32) {
+        let sizes = resize_panes(&self.pane_sizes(cx), &self.limits, handle, delta);
+        self.restore[handle] = None;
+        self.set_sizes(cx, &sizes);
+    }
+
+    // Collapses the pane on the side of a handle away from the filling pane, or restores it if it
+    // is collapsed.
+    fn toggle_collapse(&mut self, cx: &mut EventContext, handle: usize) {
+        let (pane, other) =
+            if handle < self.fill { (handle, handle + 1) } else { (handle + 1, handle) };
+        if !self.collapsible[pane] {
+            return;
+        }
+
+        let mut sizes = self.pane_sizes(cx);
+        match self.restore[handle].take() {
+            Some(size) => {
+                // Moving the handle forwards grows the pane before it and shrinks the pane after.
+                let delta = if pane == handle { size - sizes[pane] } else { sizes[pane] - size };
+                sizes = resize_panes(&sizes, &self.limits, handle, delta);
+            }
+            None => {
+                self.restore[handle] = Some(sizes[pane]);
+                sizes[other] += sizes[pane];
+                sizes[pane] = 0.0;
+            }
+        }
+
+        self.set_sizes(cx, &sizes);
+    }
+}
+
+impl<L> View for Split<L>
+where
+    L: Lens<Target = Vec<f32>>,
+    <L as Lens>::Source: 'static,
+{
+    fn element(&self) -> Option<&'static str> {
+        match self.orientation {
+            Orientation::Horizontal => Some("hsplit"),
+            Orientation::Vertical => Some("vsplit"),
+        }
+    }
+
+    fn event(&mut self, cx: &mut EventContext, event: &mut Event) {
+        event.map(|window_event, meta| {
+            let handle = self.handles.iter().position(|handle| *handle == meta.target);
+            match window_event {
+                WindowEvent::MouseDown(MouseButton::Left) => {
+                    if let Some(handle) = handle {
+                        self.drag = Some((handle, self.cursor(cx), self.pane_sizes(cx)));
+                        cx.capture();
+                        meta.consume();
+                    }
+                }
+
+                WindowEvent::MouseMove(_, _) => {
+                    if let Some((handle, start, sizes)) = &self.drag {
+                        let delta = cx.style.physical_to_logical(self.cursor(cx) - *start);
+                        let sizes = resize_panes(sizes, &self.limits, *handle, delta);
+                        self.restore[*handle] = None;
+                        self.set_sizes(cx, &sizes);
+                    }
+                }
+
+                WindowEvent::MouseUp(MouseButton::Left) => {
+                    if self.drag.take().is_some() {
+                        cx.release();
+                        meta.consume();
+                    }
+                }
+
+                WindowEvent::MouseDoubleClick(MouseButton::Left) => {
+                    if let Some(handle) = handle {
+                        self.toggle_collapse(cx, handle);
+                        meta.consume();
+                    }
+                }
+
+                WindowEvent::KeyDown(code, _) => {
+                    let handle = match handle {
+                        Some(handle) => handle,
+                        None => return,
+                    };
+
+                    let delta = match (self.orientation, code) {
+                        (Orientation::Horizontal, Code::ArrowLeft)
+                        | (Orientation::Vertical, Code::ArrowUp) => -KEYBOARD_STEP,
+                        (Orientation::Horizontal, Code::ArrowRight)
+                        | (Orientation::Vertical, Code::ArrowDown) => KEYBOARD_STEP,
+                        (_, Code::Enter) => {
+                            self.toggle_collapse(cx, handle);
+                            meta.consume();
+                            return;
+                        }
+                        _ => return,
+                    };
+
+                    self.move_handle(cx, handle, delta);
+                    meta.consume();
+                }
+
+                _ => {}
+            }
+        });
+    }
+}
+
+impl<L> Handle<'_, Split<L>>
+where
+    L: Lens<Target = Vec<f32>>,
+{
+    /// Sets the callback which is called when the panes are resized, with the sizes of all of the
+    /// panes except the filling pane in logical pixels, in the same order as the sizes lens. The
+    /// filling pane is the last pane unless another pane was made to fill with
+    /// [`SplitPane::stretch`].
+    pub fn on_resize<F>(self, callback: F) -> Self
+    where
+        F: 'static + Fn(&mut EventContext, &[f32]),
+    {
+        self.modify(|split| split.on_resize = Some(Box::new(callback)))
+    }
+}
+
+// Resizes the pane before a handle by an amount, and the pane after it by the opposite amount,
+// limiting the amount so that both panes stay within their minimum and maximum sizes.
+fn resize_panes(sizes: &[f32], limits: &[(f32, f32)], handle: usize, delta: f32) -> Vec<f32> {
+    let mut sizes = sizes.to_vec();
+    if handle + 1 >= sizes.len() {
+        return sizes;
+    }
+
+    let (before, after) = (sizes[handle], sizes[handle + 1]);
+    let (before_min, before_max) = limits[handle];
+    let (after_min, after_max) = limits[handle + 1];
+    let lowest = (before_min - before).max(after - after_max);
+    let highest = (before_max - before).min(after - after_min);
+    let delta = if lowest <= highest { delta.clamp(lowest, highest) } else { 0.0 };
+
+    sizes[handle] += delta;
+    sizes[handle + 1] -= delta;
+    sizes
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_resize_panes() {
+        let limits = [(50.0, 300.0), (0.0, f32::INFINITY), (100.0, f32::INFINITY)];
+        let sizes = [100.0, 200.0, 300.0];
+        assert_eq!(resize_panes(&sizes, &limits, 0, 50.0), vec![150.0, 150.0, 300.0]);
+        assert_eq!(resize_panes(&sizes, &limits, 0, -80.0), vec![50.0, 250.0, 300.0]);
+        assert_eq!(resize_panes(&sizes, &limits, 0, 500.0), vec![300.0, 0.0, 300.0]);
+        assert_eq!(resize_panes(&sizes, &limits, 1, 500.0), vec![100.0, 400.0, 100.0]);
+        assert_eq!(resize_panes(&sizes, &limits, 2, 10.0), sizes.to_vec());
+
+        // A collapsed pane below its minimum size is restored to at least its minimum size.
+        let sizes = [0.0, 300.0, 300.0];
+        assert_eq!(resize_panes(&sizes, &limits, 0, 10.0), vec![50.0, 250.0, 300.0]);
+    }
+}
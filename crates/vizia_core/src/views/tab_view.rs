@@ -0,0 +1,449 @@
+use std::hash::Hash;
+use std::rc::Rc;
+
+use morphorm::LayoutType;
+use vizia_input::Code;
+
+use super::scrollview::SCROLL_SENSITIVITY;
+use crate::fonts::icons_names::CANCEL;
+use crate::prelude::*;
+use crate::state::{KeyedIndex, Then};
+
+enum TabEvent<K> {
+    // The tab with a key was pressed.
+    Select(K),
+    // The close button of the tab with a key was pressed.
+    Close(K),
+    // The tab with the first key was dropped onto the tab with the second key, after it if true.
+    Drop(K, K, bool),
+    // The tab bar was laid out with a width in physical pixels.
+    BarResize(f32),
+    // The tabs were laid out with a width in physical pixels.
+    TabsResize(f32),
+}
+
+// The payload of a dragged tab, which can only be dropped onto the tabs of the same tab view. Dock
+// spaces accept the tabs of their own tab views too.
+#[derive(Clone)]
+pub(crate) struct TabDrag<K> {
+    pub tab_view: Entity,
+    pub key: K,
+}
+
+/// A view for displaying a binding to a list of tabs, with a bar of tab headers above the content
+/// of the active tab.
+///
+/// Tabs are identified by a unique key, and the active tab is given by a lens to its key. The
+/// content of each tab is built once and kept when another tab is active, with the `tab_panel`
+/// elements of the inactive tabs hidden, so that the content keeps its local state. The tab view
+/// does not change the active tab or the tabs itself, but calls callbacks so that the data can be
+/// changed:
+///
+/// - [`on_select`](Handle::on_select) when a tab is pressed, or with Ctrl+Tab and Ctrl+Shift+Tab
+///   while the tab view contains the keyboard focus, or with the left and right arrow keys while
+///   the tab view itself has keyboard focus.
+/// - [`on_close`](Handle::on_close) when the close button of a tab is pressed. Tabs only have close
+///   buttons when this callback is set.
+/// - [`on_move`](Handle::on_move) when a tab is dragged onto another tab.
+///
+/// The bar of tab headers can be scrolled with the mouse wheel when the tabs do not fit. The
+/// active tab has the `checked` pseudo-class.
+///
+/// # Example
+/// ```
+/// # use vizia_core::prelude::*;
+/// # let cx = &mut Context::default();
+/// # #[derive(Clone, Data, Lens)]
+/// # pub struct Document {
+/// #     id: u32,
+/// #     title: String,
+/// # }
+/// # #[derive(Lens)]
+/// # pub struct AppData {
+/// #     documents: Vec<Document>,
+/// #     active: u32,
+/// # }
+/// # impl Model for AppData {}
+/// # AppData { documents: Vec::new(), active: 0 }.build(cx);
+/// TabView::new(
+///     cx,
+///     AppData::documents,
+///     AppData::active,
+///     |document| document.id,
+///     |cx, document| {
+///         Label::new(cx, document.then(Document::title));
+///     },
+///     |cx, document| {
+///         Label::new(cx, document.then(Document::title));
+///     },
+/// )
+/// .on_select(|_, id| println!("Selected document {}", id));
+/// ```
+#[derive(Lens)]
+pub struct TabView<L: Lens<Target = Vec<T>>, T: 'static, K: 'static, A: Lens<Target = K>> {
+    #[lens(ignore)]
+    lens: L,
+    #[lens(ignore)]
+    active: A,
+    #[lens(ignore)]
+    key: Rc<dyn Fn(&T) -> K>,
+    #[lens(ignore)]
+    bar: Entity,
+    closable: bool,
+    // The horizontal scroll offset of the tabs, the width of the tab bar and the width of the
+    // tabs, in logical pixels.
+    scroll_x: f32,
+    bar_width: f32,
+    tabs_width: f32,
+    on_select: Option<Box<dyn Fn(&mut EventContext, K)>>,
+    on_close: Option<Box<dyn Fn(&mut EventContext, K)>>,
+    on_move: Option<Box<dyn Fn(&mut EventContext, usize, usize)>>,
+}
+
+impl<L, T, K, A> TabView<L, T, K, A>
+where
+    L: 'static + Lens<Target = Vec<T>>,
+    T: Clone,
+    K: 'static + Data + Eq + Hash + Send + Sync,
+    A: Lens<Target = K>,
+    <L as Lens>::Source: 'static,
+    <A as Lens>::Source: 'static,
+{
+    /// Creates a new tab view with a binding to the given lens, a lens to the key of the active
+    /// tab, a function which returns the unique key of a tab, and templates for constructing the
+    /// header and the content of a tab.
+    pub fn new<G, H, F>(
+        cx: &mut Context,
+        lens: L,
+        active: A,
+        key: G,
+        header: H,
+        content: F,
+    ) -> Handle<Self>
+    where
+        G: 'static + Fn(&T) -> K,
+        H: 'static + Fn(&mut Context, Then<L, KeyedIndex<T, K>>),
+        F: 'static + Fn(&mut Context, Then<L, KeyedIndex<T, K>>),
+        <L as Lens>::Source: Model,
+    {
+        let key: Rc<dyn Fn(&T) -> K> = Rc::new(key);
+
+        Self {
+            lens: lens.clone(),
+            active: active.clone(),
+            key: key.clone(),
+            bar: Entity::null(),
+            closable: false,
+            scroll_x: 0.0,
+            bar_width: 0.0,
+            tabs_width: 0.0,
+            on_select: None,
+            on_close: None,
+            on_move: None,
+        }
+        .build(cx, move |cx| {
+            let tab_view = cx.current();
+
+            let header_lens = lens.clone();
+            let header_key = key.clone();
+            let header_active = active.clone();
+            let bar = HStack::new(cx, move |cx| {
+                let key_of = header_key.clone();
+                List::new_keyed(
+                    cx,
+                    header_lens,
+                    move |tab| key_of(tab),
+                    move |cx, tab| {
+                        let key = (header_key)(&tab.get(cx));
+                        let (select_key, close_key, drag_key, drop_key, active_key) =
+                            (key.clone(), key.clone(), key.clone(), key.clone(), key);
+                        HStack::new(cx, |cx| {
+                            (header)(cx, tab);
+                            Label::new(cx, CANCEL)
+                                .class("icon")
+                                .class("close_button")
+                                .display(Self::closable)
+                                .on_press(move |cx| cx.emit(TabEvent::Close(close_key.clone())));
+                        })
+                        .class("tab")
+                        .role(Role::Tab)
+                        .checked(header_active.clone().map(move |active| *active == active_key))
+                        .on_press_down(move |cx| cx.emit(TabEvent::Select(select_key.clone())))
+                        .draggable(TabDrag { tab_view, key: drag_key })
+                        .drop_target(
+                            move |drag: &TabDrag<K>| drag.tab_view == tab_view,
+                            move |cx, drag: TabDrag<K>| {
+                                let bounds = cx.cache.get_bounds(cx.current());
+                                let after = cx.mouse.cursorx > bounds.x + bounds.w / 2.0;
+                                cx.emit(TabEvent::Drop(drag.key, drop_key.clone(), after));
+                            },
+                        );
+                    },
+                )
+                .layout_type(LayoutType::Row)
+                .left(Self::scroll_x.map(|scroll_x| Pixels(-*scroll_x)))
+                .on_geo_changed(|cx, _| {
+                    let width = cx.cache().get_width(cx.current());
+                    cx.emit(TabEvent::<K>::TabsResize(width));
+                });
+            })
+            .class("tab_bar")
+            .role(Role::TabList)
+            .on_geo_changed(|cx, _| {
+                let width = cx.cache().get_width(cx.current());
+                cx.emit(TabEvent::<K>::BarResize(width));
+            })
+            .entity;
+
+            if let Some(view) =
+                cx.views.get_mut(&tab_view).and_then(|view| view.downcast_mut::<Self>())
+            {
+                view.bar = bar;
+            }
+
+            let content_key = key.clone();
+            List::new_keyed(
+                cx,
+                lens,
+                move |tab| key(tab),
+                move |cx, tab| {
+                    let key = (content_key)(&tab.get(cx));
+                    VStack::new(cx, |cx| (content)(cx, tab))
+                        .class("tab_panel")
+                        .role(Role::TabPanel)
+                        .display(active.clone().map(move |active| *active == key));
+                },
+            )
+            .class("tab_panels");
+        })
+        .navigable(true)
+    }
+}
+
+impl<L, T, K, A> TabView<L, T, K, A>
+where
+    L: Lens<Target = Vec<T>>,
+    K: PartialEq,
+    A: Lens<Target = K>,
+    <L as Lens>::Source: 'static,
+    <A as Lens>::Source: 'static,
+{
+    // Returns the keys of the tabs, and the position of the active tab among them.
+    fn tabs(&self, cx: &EventContext) -> (Vec<K>, Option<usize>) {
+        let keys = cx.data().map_or(Vec::new(), |data| {
+            self.lens.view(data, |tabs| {
+                tabs.map_or(Vec::new(), |tabs| tabs.iter().map(|tab| (self.key)(tab)).collect())
+            })
+        });
+        let active = cx.data().and_then(|data| {
+            self.active.view(data, |active| {
+                active.and_then(|active| keys.iter().position(|key| key == active))
+            })
+        });
+
+        (keys, active)
+    }
+
+    fn select(&self, cx: &mut EventContext, key: K) {
+        if let Some(callback) = &self.on_select {
+            (callback)(cx, key);
+        }
+    }
+
+    // Selects the tab after the active tab, or the tab before it if `backwards` is true.
+    fn select_next(&self, cx: &mut EventContext, backwards: bool) {
+        let (mut keys, active) = self.tabs(cx);
+        if let Some(next) = next_position(active, keys.len(), backwards) {
+            self.select(cx, keys.swap_remove(next));
+        }
+    }
+
+    fn scroll(&mut self, delta: f32) {
+        let max_scroll = (self.tabs_width - self.bar_width).max(0.0);
+        self.scroll_x = (self.scroll_x + delta).clamp(0.0, max_scroll);
+    }
+}
+
+impl<L, T, K, A> View for TabView<L, T, K, A>
+where
+    L: Lens<Target = Vec<T>>,
+    K: 'static + PartialEq + Send,
+    A: Lens<Target = K>,
+    <L as Lens>::Source: 'static,
+    <A as Lens>::Source: 'static,
+{
+    fn element(&self) -> Option<&'static str> {
+        Some("tabview")
+    }
+
+    fn capture_event(&mut self, cx: &mut EventContext, event: &mut Event) {
+        // Ctrl+Tab is handled before it reaches the focused descendant, so that it switches tabs
+        // instead of being handled by the descendant or moving the focus.
+        event.map(|window_event, meta| {
+            if let WindowEvent::KeyDown(Code::Tab, _) = window_event {
+                if cx.modifiers.contains(Modifiers::CTRL) {
+                    let backwards = cx.modifiers.contains(Modifiers::SHIFT);
+                    self.select_next(cx, backwards);
+                    meta.consume();
+                }
+            }
+        });
+    }
+
+    fn event(&mut self, cx: &mut EventContext, event: &mut Event) {
+        event.map(|tab_event: &TabEvent<K>, meta| {
+            match tab_event {
+                TabEvent::Select(key) => {
+                    cx.focus_with_visibility(false);
+                    self.select(cx, key.clone());
+                }
+
+                TabEvent::Close(key) => {
+                    if let Some(callback) = &self.on_close {
+                        (callback)(cx, key.clone());
+                    }
+                }
+
+                TabEvent::Drop(dragged, target, after) => {
+                    let (keys, _) = self.tabs(cx);
+                    let from = keys.iter().position(|key| key == dragged);
+                    let target = keys.iter().position(|key| key == target);
+                    if let (Some(from), Some(target)) = (from, target) {
+                        let to = move_position(from, target, *after);
+                        if from != to {
+                            if let Some(callback) = &self.on_move {
+                                (callback)(cx, from, to);
+                            }
+                        }
+                    }
+                }
+
+                TabEvent::BarResize(width) => {
+                    self.bar_width = cx.style.physical_to_logical(*width);
+                    self.scroll(0.0);
+                }
+
+                TabEvent::TabsResize(width) => {
+                    self.tabs_width = cx.style.physical_to_logical(*width);
+                    self.scroll(0.0);
+                }
+            }
+
+            meta.consume();
+        });
+
+        event.map(|window_event, meta| match window_event {
+            WindowEvent::MouseScroll(x, y)
+                if meta.target == self.bar || meta.target.is_descendant_of(cx.tree, self.bar) =>
+            {
+                // The tabs are only scrolled horizontally, so vertical scrolling scrolls them too.
+                self.scroll(-(*x + *y) * SCROLL_SENSITIVITY);
+                meta.consume();
+            }
+
+            WindowEvent::KeyDown(code, _) if meta.target == cx.current() => {
+                let backwards = match code {
+                    Code::Tab if cx.modifiers.contains(Modifiers::CTRL) => {
+                        cx.modifiers.contains(Modifiers::SHIFT)
+                    }
+                    Code::ArrowLeft => true,
+                    Code::ArrowRight => false,
+                    _ => return,
+                };
+
+                self.select_next(cx, backwards);
+                meta.consume();
+            }
+
+            _ => {}
+        });
+    }
+}
+
+impl<L, T, K, A> Handle<'_, TabView<L, T, K, A>>
+where
+    L: Lens<Target = Vec<T>>,
+    K: 'static,
+    A: Lens<Target = K>,
+{
+    /// Sets the callback which is called when a tab is selected, with the key of the tab.
+    pub fn on_select<F>(self, callback: F) -> Self
+    where
+        F: 'static + Fn(&mut EventContext, K),
+    {
+        self.modify(|tab_view| tab_view.on_select = Some(Box::new(callback)))
+    }
+
+    /// Sets the callback which is called when the close button of a tab is pressed, with the key
+    /// of the tab, and adds close buttons to the tabs.
+    pub fn on_close<F>(self, callback: F) -> Self
+    where
+        F: 'static + Fn(&mut EventContext, K),
+    {
+        self.modify(|tab_view| {
+            tab_view.on_close = Some(Box::new(callback));
+            tab_view.closable = true;
+        })
+    }
+
+    /// Sets the callback which is called when a tab is dragged onto another tab, with the position
+    /// of the tab and the position it should be moved to.
+    pub fn on_move<F>(self, callback: F) -> Self
+    where
+        F: 'static + Fn(&mut EventContext, usize, usize),
+    {
+        self.modify(|tab_view| tab_view.on_move = Some(Box::new(callback)))
+    }
+}
+
+// Returns the position of the tab which follows the active tab, wrapping around at either end.
+fn next_position(active: Option<usize>, len: usize, backwards: bool) -> Option<usize> {
+    if len == 0 {
+        return None;
+    }
+
+    Some(match active {
+        Some(active) if backwards => (active + len - 1) % len,
+        Some(active) => (active + 1) % len,
+        None => 0,
+    })
+}
+
+// Returns the position a tab should be moved to when it is dropped before or after the tab at
+// another position, once the tab has been removed from its current position.
+fn move_position(from: usize, target: usize, after: bool) -> usize {
+    let to = if after { target + 1 } else { target };
+    if from < to {
+        to - 1
+    } else {
+        to
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_next_position() {
+        assert_eq!(next_position(None, 0, false), None);
+        assert_eq!(next_position(None, 3, true), Some(0));
+        assert_eq!(next_position(Some(1), 3, false), Some(2));
+        assert_eq!(next_position(Some(2), 3, false), Some(0));
+        assert_eq!(next_position(Some(0), 3, true), Some(2));
+    }
+
+    #[test]
+    fn test_move_position() {
+        // Dropping a tab next to itself does not move it.
+        assert_eq!(move_position(1, 1, false), 1);
+        assert_eq!(move_position(1, 1, true), 1);
+        assert_eq!(move_position(1, 0, true), 1);
+        assert_eq!(move_position(1, 2, false), 1);
+
+        assert_eq!(move_position(0, 2, true), 2);
+        assert_eq!(move_position(0, 2, false), 1);
+        assert_eq!(move_position(2, 0, false), 0);
+        assert_eq!(move_position(2, 0, true), 1);
+    }
+}
@@ -1,99 +1,729 @@
-use std::marker::PhantomData;
+use std::cmp::Ordering;
 use std::rc::Rc;
 
+use vizia_input::Code;
+
+use super::scrollview::SCROLL_SENSITIVITY;
+use super::virtual_list::{
+    build_rows, build_scrollbar, RowsLens, Selection, VirtualRowEvent, VirtualRows,
+};
+use crate::fonts::icons_names::{DOWN, UP};
 use crate::prelude::*;
 use crate::state::{Index, Then};
 
-// TODO
+// The width of the area at the right edge of a column header which resizes the column when
+// dragged, in logical pixels.
+const RESIZE_HANDLE_WIDTH: f32 = 6.0;
 
-pub struct Table<L, T: 'static>
-where
-    L: Lens<Target = Vec<T>>,
-    T: Data,
-{
-    p: PhantomData<L>,
+/// The direction in which the rows of a [`Table`] are sorted by a column.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Data)]
+pub enum SortDirection {
+    Ascending,
+    Descending,
+}
+
+/// The number of rows of a [`Table`] which can be selected at once.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Data)]
+pub enum SelectionMode {
+    /// Rows cannot be selected.
+    None,
+    /// A single row can be selected.
+    Single,
+    /// Any number of rows can be selected by pressing rows with the control or shift keys held,
+    /// or by moving the selection with the shift key held.
+    Multiple,
+}
+
+/// Events which can be sent to a [`Table`].
+///
+/// Items and columns are identified by their index in the data of the table and in the columns
+/// the table was created with, which does not change when the rows are sorted or the columns are
+/// moved.
+pub enum TableEvent {
+    /// Sorts the rows by a column in a direction, or displays the rows in the order of the items
+    /// for `None`.
+    SortBy(Option<(usize, SortDirection)>),
+    /// Selects the row of an item and scrolls it into view.
+    Select(usize),
+    /// Deselects all of the rows.
+    ClearSelection,
+    /// Scrolls the table so that the row of an item is visible.
+    ScrollTo(usize),
+    /// Moves a column to a position among the displayed columns.
+    MoveColumn(usize, usize),
+    /// Sets the width of a column in logical pixels.
+    SetColumnWidth(usize, f32),
+}
+
+enum TableRowEvent {
+    // The rows were laid out with a width and height in physical pixels.
+    Resize(f32, f32),
+}
+
+// A column header which is being pressed or dragged.
+#[derive(Debug, Clone, Copy)]
+enum ColumnDrag {
+    // The header of a column was pressed at a horizontal position in physical pixels. The column
+    // is moved instead of sorted once the cursor has moved far enough.
+    Move { column: usize, start_x: f32, moving: bool },
+    // The right edge of the header of a column with a width in logical pixels was pressed at a
+    // horizontal position in physical pixels.
+    Resize { column: usize, start_x: f32, width: f32 },
+}
+
+/// A column of a [`Table`], which describes the header of the column and builds its cells.
+pub struct TableColumn<L: Lens<Target = Vec<T>>, T: 'static> {
+    title: String,
+    width: f32,
+    min_width: f32,
+    resizable: bool,
+    #[allow(clippy::type_complexity)]
+    compare: Option<Rc<dyn Fn(&T, &T) -> Ordering>>,
+    #[allow(clippy::type_complexity)]
+    cell: Rc<dyn Fn(&mut Context, usize, Then<L, Index<Vec<T>, T>>)>,
 }
 
-impl<L: 'static + Lens<Target = Vec<T>>, T: Data> Table<L, T> {
-    pub fn new<'a, F>(cx: &'a mut Context, lens: L, list_builder: F) -> Handle<'a, Self>
+impl<L: Lens<Target = Vec<T>>, T: 'static> TableColumn<L, T> {
+    /// Creates a new column with a title and a template for constructing the cell of each item,
+    /// which is given the index of the item and a lens to it.
+    pub fn new<F>(title: impl ToString, cell: F) -> Self
     where
-        F: 'static + Fn(&mut Context, L),
-        <L as Lens>::Source: Model,
+        F: 'static + Fn(&mut Context, usize, Then<L, Index<Vec<T>, T>>),
     {
-        Self { p: PhantomData::default() }.build(cx, move |cx| {
-            HStack::new(cx, move |cx| {
-                (list_builder)(cx, lens);
-            });
-        })
+        Self {
+            title: title.to_string(),
+            width: 100.0,
+            min_width: 20.0,
+            resizable: true,
+            compare: None,
+            cell: Rc::new(cell),
+        }
     }
-}
 
-impl<L: 'static + Lens<Target = Vec<T>>, T: Data> View for Table<L, T>
-where
-    L: 'static + Lens<Target = Vec<T>>,
-{
-    fn element(&self) -> Option<&'static str> {
-        Some("table")
+    /// Sets the initial width of the column in logical pixels. Defaults to 100 pixels.
+    pub fn width(mut self, width: f32) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the width in logical pixels below which the column cannot be resized. Defaults to 20
+    /// pixels.
+    pub fn min_width(mut self, min_width: f32) -> Self {
+        self.min_width = min_width;
+        self
+    }
+
+    /// Sets whether the column can be resized by dragging the right edge of its header. Defaults
+    /// to true.
+    pub fn resizable(mut self, resizable: bool) -> Self {
+        self.resizable = resizable;
+        self
+    }
+
+    /// Makes the rows sortable by the column, by pressing its header, with a function which
+    /// compares two items.
+    pub fn sortable<F>(mut self, compare: F) -> Self
+    where
+        F: 'static + Fn(&T, &T) -> Ordering,
+    {
+        self.compare = Some(Rc::new(compare));
+        self
+    }
+
+    /// Makes the rows sortable by the column, by pressing its header, with a function which
+    /// returns the key of an item to sort by.
+    pub fn sort_by_key<K: Ord, F>(self, key: F) -> Self
+    where
+        F: 'static + Fn(&T) -> K,
+    {
+        self.sortable(move |a, b| key(a).cmp(&key(b)))
     }
 }
 
-pub struct TableColumn<R, L, T, U>
-where
-    R: Lens<Target = Vec<T>>,
-    L: Lens<Source = T, Target = U>,
-    T: Data,
-    U: Data,
-{
-    p1: PhantomData<R>,
-    p2: PhantomData<L>,
+/// A view for displaying a binding to a `Vec<T>` as rows with a cell for each of a number of
+/// columns, which only builds the rows which are visible.
+///
+/// The header of the table stays in view while the rows are scrolled. Pressing the header of a
+/// sortable column sorts the rows by that column in ascending, then descending order, and then
+/// restores the order of the items. Columns can be resized by dragging the right edge of their
+/// header, and moved by dragging their header. Sorting the rows and moving the columns does not
+/// change the data of the table. The rows are sorted again when the number of items changes, but
+/// not when an item changes, which can be done by sending [`TableEvent::SortBy`] with the current
+/// sorting.
+///
+/// Rows can be selected by pressing them or with the arrow, page up, page down, home and end keys
+/// while the table has keyboard focus, and are selected by the index of their item. Selected rows
+/// have the `checked` pseudo-class.
+///
+/// All rows have the same height, and the table must be given a height which does not depend on
+/// its content, such as `Stretch(1.0)`.
+///
+/// # Example
+/// ```
+/// # use vizia_core::prelude::*;
+/// # let cx = &mut Context::default();
+/// # #[derive(Clone, Data, Lens)]
+/// # pub struct Person {
+/// #     name: String,
+/// #     age: u32,
+/// # }
+/// # #[derive(Lens)]
+/// # pub struct AppData {
+/// #     people: Vec<Person>,
+/// # }
+/// # impl Model for AppData {}
+/// # AppData { people: Vec::new() }.build(cx);
+/// Table::new(
+///     cx,
+///     AppData::people,
+///     24.0,
+///     vec![
+///         TableColumn::new("Name", |cx, _, person| {
+///             Label::new(cx, person.then(Person::name));
+///         })
+///         .width(200.0)
+///         .sort_by_key(|person| person.name.clone()),
+///         TableColumn::new("Age", |cx, _, person| {
+///             Label::new(cx, person.then(Person::age));
+///         })
+///         .sort_by_key(|person| person.age),
+///     ],
+/// )
+/// .selection_mode(SelectionMode::Multiple)
+/// .on_select(|_, items| println!("Selected {:?}", items));
+/// ```
+#[derive(Lens)]
+pub struct Table<L: Lens<Target = Vec<T>>, T: 'static> {
+    #[lens(ignore)]
+    lens: L,
+    #[lens(ignore)]
+    columns: Vec<TableColumn<L, T>>,
+    #[lens(ignore)]
+    drag: Option<ColumnDrag>,
+    #[lens(ignore)]
+    header: Entity,
+    // The width of each column in logical pixels.
+    widths: Vec<f32>,
+    // The columns in the order in which they are displayed.
+    order: Vec<usize>,
+    sort: Option<(usize, SortDirection)>,
+    // The index of the item displayed in each row.
+    rows: Vec<usize>,
+    // The selected rows, by the index of their item.
+    #[lens(ignore)]
+    selection: Selection<usize>,
+    #[lens(ignore)]
+    virtual_rows: VirtualRows,
+    // The horizontal scroll offset and the width of the rows, in logical pixels.
+    scroll_x: f32,
+    viewport_width: f32,
+    on_select: Option<Box<dyn Fn(&mut EventContext, &[usize])>>,
 }
 
-impl<R, L, T: Data, U: Data> TableColumn<R, L, T, U>
+impl<L: 'static + Lens<Target = Vec<T>>, T: Clone> Table<L, T>
 where
-    R: Lens<Target = Vec<T>>,
-    L: Lens<Source = T, Target = U>,
+    <L as Lens>::Source: 'static,
 {
-    pub fn new<F, Label>(
+    /// Creates a new table with rows of a fixed height in logical pixels, with a binding to the
+    /// given lens and the columns to display.
+    pub fn new(
         cx: &mut Context,
-        list: R,
-        item: L,
-        label: Label,
-        content: F,
+        lens: L,
+        row_height: f32,
+        columns: Vec<TableColumn<L, T>>,
     ) -> Handle<Self>
     where
-        F: 'static + Fn(&mut Context, usize, Then<Then<R, Index<<R as Lens>::Target, T>>, L>),
-        Label: 'static + Fn(&mut Context),
-        <R as Lens>::Source: Model,
+        <L as Lens>::Source: Model,
     {
-        Self { p1: PhantomData::default(), p2: PhantomData::default() }.build(cx, move |cx| {
-            //VStack::new(cx, move |cx|{
-            (label)(cx);
-            //    Element::new(cx).height(Pixels(1.0)).background_color(Color::black());
-            //}).height(Pixels(30.0));
-
-            let content = Rc::new(content);
-            //let item = item.clone();
-            List::new(cx, list, move |cx, index, it| {
-                let content = content.clone();
-                let item = item.clone();
-                VStack::new(cx, move |cx| {
-                    //let item = item.clone();
-                    Binding::new(cx, it.then(item), move |cx, l| {
-                        (content)(cx, index, l);
-                    });
+        let titles = columns.iter().map(|column| column.title.clone()).collect::<Vec<_>>();
+        let resizable = columns.iter().map(|column| column.resizable).collect::<Vec<_>>();
+        let cells = columns.iter().map(|column| column.cell.clone()).collect::<Vec<_>>();
+        let cells = Rc::new(cells);
+
+        Self {
+            lens: lens.clone(),
+            widths: columns.iter().map(|column| column.width).collect(),
+            order: (0..columns.len()).collect(),
+            columns,
+            drag: None,
+            header: Entity::null(),
+            sort: None,
+            rows: Vec::new(),
+            selection: Selection::new(),
+            virtual_rows: VirtualRows::new(row_height),
+            scroll_x: 0.0,
+            viewport_width: 0.0,
+            on_select: None,
+        }
+        .build(cx, move |cx| {
+            let table = cx.current();
+            let rows = RowsLens::new(|table: &Self| &table.virtual_rows);
+
+            // Changing the number of items sorts the rows again, but changing the items themselves
+            // does not.
+            Binding::new(cx, lens.clone().map(|items| items.len()), move |cx, _| {
+                if let Some(mut view) = cx.views.remove(&table) {
+                    if let Some(table_view) = view.downcast_mut::<Self>() {
+                        table_view.update_rows(&*cx);
+                    }
+
+                    cx.views.insert(table, view);
+                }
+            });
+
+            let header = HStack::new(cx, move |cx| {
+                Binding::new(cx, Self::order, move |cx, order| {
+                    for column in order.get(cx) {
+                        HStack::new(cx, |cx| {
+                            Label::new(cx, &titles[column]).class("title").hoverable(false);
+                            let indicator = Self::sort.map(move |sort| match sort {
+                                Some((sorted, direction)) if *sorted == column => {
+                                    if *direction == SortDirection::Ascending {
+                                        UP
+                                    } else {
+                                        DOWN
+                                    }
+                                }
+                                _ => "",
+                            });
+                            Label::new(cx, indicator.map(|icon| icon.to_string()))
+                                .class("sort_indicator")
+                                .hoverable(false);
+
+                            if resizable[column] {
+                                Element::new(cx)
+                                    .class("column_resize")
+                                    .width(Pixels(RESIZE_HANDLE_WIDTH))
+                                    .cursor(CursorIcon::ColResize);
+                            }
+                        })
+                        .class("column_header")
+                        .role(Role::ColumnHeader)
+                        .name(&titles[column])
+                        .width(Self::widths.map(move |widths| Pixels(widths[column])));
+                    }
                 });
             })
-            .width(Stretch(1.0));
+            .class("table_header")
+            .role(Role::Row)
+            .left(Self::scroll_x.map(|scroll_x| Pixels(-*scroll_x)))
+            .width(Self::widths.map(|widths| Pixels(widths.iter().sum())))
+            .entity;
+
+            if let Some(table) =
+                cx.views.get_mut(&table).and_then(|view| view.downcast_mut::<Self>())
+            {
+                table.header = header;
+            }
+
+            VStack::new(cx, move |cx| {
+                build_rows(cx, rows, move |cx, slot| {
+                    let lens = lens.clone();
+                    let cells = cells.clone();
+                    HStack::new(cx, move |cx| {
+                        let item = Self::root.map(move |table: &Self| table.slot_item(slot));
+                        Binding::new(cx, item, move |cx, item| {
+                            if let Some(item) = item.get(cx) {
+                                let lens = lens.clone();
+                                let cells = cells.clone();
+                                Binding::new(cx, Self::order, move |cx, order| {
+                                    for column in order.get(cx) {
+                                        let cell = cells[column].clone();
+                                        let lens = lens.clone().index(item);
+                                        HStack::new(cx, move |cx| (cell)(cx, item, lens))
+                                            .class("cell")
+                                            .role(Role::Cell)
+                                            .width(
+                                                Self::widths
+                                                    .map(move |widths| Pixels(widths[column])),
+                                            );
+                                    }
+                                });
+                            }
+                        });
+                    })
+                    .role(Role::Row)
+                    .height(Pixels(row_height))
+                    .checked(Self::root.map(move |table: &Self| table.is_slot_selected(slot)))
+                })
+                .class("table_content")
+                .left(Self::scroll_x.map(|scroll_x| Pixels(-*scroll_x)))
+                .width(Self::widths.map(|widths| Pixels(widths.iter().sum())));
+
+                build_scrollbar(cx, rows);
+            })
+            .class("table_body")
+            .on_geo_changed(|cx, _| {
+                let bounds = cx.cache().get_bounds(cx.current());
+                cx.emit(TableRowEvent::Resize(bounds.w, bounds.h));
+            });
         })
+        .role(Role::Table)
+        .navigable(true)
+    }
+}
+
+impl<L: Lens<Target = Vec<T>>, T> Table<L, T> {
+    // Sorts the rows again, and removes the items which no longer exist from the selection.
+    fn update_rows<C: DataContext>(&mut self, cx: &C)
+    where
+        <L as Lens>::Source: 'static,
+    {
+        let compare = self.sort.and_then(|(column, direction)| {
+            Some((self.columns[column].compare.clone()?, direction))
+        });
+        let data =
+            cx.data().expect("Failed to get data from context. Has it been built into the tree?");
+        self.rows = self.lens.view(data, |items| {
+            let items = items.map_or(&[][..], |items| items.as_slice());
+            sorted_rows(
+                items,
+                compare.as_ref().map(|(compare, direction)| (compare.as_ref(), *direction)),
+            )
+        });
+
+        let len = self.rows.len();
+        self.selection.retain(|item| *item < len);
+        self.virtual_rows.resize(len);
+    }
+
+    fn total_width(&self) -> f32 {
+        self.widths.iter().sum()
+    }
+
+    fn clamp_scroll_x(&mut self) {
+        self.scroll_x =
+            self.scroll_x.clamp(0.0, (self.total_width() - self.viewport_width).max(0.0));
+    }
+
+    // Returns the index of the item displayed in a slot.
+    fn slot_item(&self, slot: usize) -> Option<usize> {
+        self.virtual_rows.slot_row(slot).and_then(|row| self.rows.get(row).copied())
+    }
+
+    fn is_slot_selected(&self, slot: usize) -> bool {
+        self.slot_item(slot).map_or(false, |item| self.selection.is_selected(&item))
+    }
+
+    // Returns the row which displays an item.
+    fn row_of(&self, item: usize) -> Option<usize> {
+        self.rows.iter().position(|row| *row == item)
+    }
+
+    // Selects a row, extending the selection from the row of the anchor when `extend` is true, or
+    // adding the row to or removing it from the selection when `toggle` is true.
+    fn select_row(&mut self, cx: &mut EventContext, row: usize, extend: bool, toggle: bool) {
+        if !self.selection.select(&self.rows, |item| item, row, extend, toggle) {
+            return;
+        }
+
+        self.virtual_rows.scroll_to_row(row);
+
+        if let Some(callback) = &self.on_select {
+            (callback)(cx, &self.selection.selected);
+        }
+    }
+
+    // Returns the sorting which follows the current sorting when the header of a column is
+    // pressed, which sorts the rows by the column in ascending, then descending order, and then
+    // restores the order of the items.
+    fn next_sort(&self, column: usize) -> Option<(usize, SortDirection)> {
+        match self.sort {
+            Some((sorted, SortDirection::Ascending)) if sorted == column => {
+                Some((column, SortDirection::Descending))
+            }
+            Some((sorted, SortDirection::Descending)) if sorted == column => None,
+            _ => Some((column, SortDirection::Ascending)),
+        }
+    }
+
+    // Returns the horizontal position of the cursor from the start of the header, in logical
+    // pixels.
+    fn header_offset(&self, cx: &EventContext) -> f32 {
+        let bounds = cx.cache.get_bounds(self.header);
+        cx.style.physical_to_logical(cx.mouse.cursorx - bounds.x)
     }
 }
 
-impl<R, L, T: Data, U: Data> View for TableColumn<R, L, T, U>
+impl<L: 'static + Lens<Target = Vec<T>>, T> View for Table<L, T>
 where
-    R: Lens<Target = Vec<T>>,
-    L: Lens<Source = T, Target = U>,
+    <L as Lens>::Source: 'static,
 {
     fn element(&self) -> Option<&'static str> {
-        Some("tablecolumn")
+        Some("table")
+    }
+
+    fn accessibility(&self, _cx: &mut AccessContext, node: &mut AccessNode) {
+        node.set_table_row_count(self.rows.len());
+        node.set_table_column_count(self.order.len());
+    }
+
+    fn event(&mut self, cx: &mut EventContext, event: &mut Event) {
+        event.map(|table_event, meta| {
+            match table_event {
+                TableEvent::SortBy(sort) => {
+                    self.sort = sort.filter(|(column, _)| *column < self.columns.len());
+                    self.update_rows(&*cx);
+                }
+
+                TableEvent::Select(item) => {
+                    if let Some(row) = self.row_of(*item) {
+                        self.select_row(cx, row, false, false);
+                    }
+                }
+
+                TableEvent::ClearSelection => {
+                    self.selection.clear();
+                    if let Some(callback) = &self.on_select {
+                        (callback)(cx, &self.selection.selected);
+                    }
+                }
+
+                TableEvent::ScrollTo(item) => {
+                    if let Some(row) = self.row_of(*item) {
+                        self.virtual_rows.scroll_to_row(row);
+                    }
+                }
+
+                TableEvent::MoveColumn(column, position) => {
+                    if let Some(from) = self.order.iter().position(|c| c == column) {
+                        let column = self.order.remove(from);
+                        self.order.insert((*position).min(self.order.len()), column);
+                    }
+                }
+
+                TableEvent::SetColumnWidth(column, width) => {
+                    if let Some(column_width) = self.widths.get_mut(*column) {
+                        *column_width = width.max(self.columns[*column].min_width);
+                        self.clamp_scroll_x();
+                    }
+                }
+            }
+
+            meta.consume();
+        });
+
+        event.map(|row_event, meta| {
+            match row_event {
+                VirtualRowEvent::Press(slot) => {
+                    if let Some(row) = self.virtual_rows.slot_row(*slot) {
+                        cx.focus_with_visibility(false);
+                        let extend = cx.modifiers.contains(Modifiers::SHIFT);
+                        let toggle = cx.modifiers.contains(Modifiers::CTRL);
+                        self.select_row(cx, row, extend, toggle);
+                    }
+                }
+
+                VirtualRowEvent::Scroll(value) => self.virtual_rows.scroll_to_ratio(*value),
+
+                VirtualRowEvent::Measure(..) => {}
+            }
+
+            meta.consume();
+        });
+
+        event.map(|row_event, meta| {
+            let TableRowEvent::Resize(width, height) = row_event;
+            self.viewport_width = cx.style.physical_to_logical(*width);
+            self.clamp_scroll_x();
+            self.virtual_rows.set_viewport(cx.style.physical_to_logical(*height));
+            meta.consume();
+        });
+
+        event.map(|window_event, meta| match window_event {
+            WindowEvent::MouseDown(MouseButton::Left)
+                if meta.target == self.header
+                    || meta.target.is_descendant_of(cx.tree, self.header) =>
+            {
+                let offset = self.header_offset(cx);
+                if let Some((position, on_edge)) = column_at(&self.widths, &self.order, offset) {
+                    let column = self.order[position];
+                    let start_x = cx.mouse.cursorx;
+                    self.drag = Some(if on_edge && self.columns[column].resizable {
+                        ColumnDrag::Resize { column, start_x, width: self.widths[column] }
+                    } else {
+                        ColumnDrag::Move { column, start_x, moving: false }
+                    });
+
+                    cx.capture();
+                    meta.consume();
+                }
+            }
+
+            WindowEvent::MouseMove(x, _) => match self.drag {
+                Some(ColumnDrag::Resize { column, start_x, width }) => {
+                    let delta = cx.style.physical_to_logical(*x - start_x);
+                    self.widths[column] = (width + delta).max(self.columns[column].min_width);
+                    self.clamp_scroll_x();
+                }
+
+                Some(ColumnDrag::Move { column, start_x, moving: false }) => {
+                    let threshold = cx.style.logical_to_physical(DEFAULT_DRAG_THRESHOLD);
+                    if (*x - start_x).abs() > threshold {
+                        self.drag = Some(ColumnDrag::Move { column, start_x, moving: true });
+                    }
+                }
+
+                _ => {}
+            },
+
+            WindowEvent::MouseUp(MouseButton::Left) => {
+                match self.drag.take() {
+                    Some(ColumnDrag::Move { column, moving: true, .. }) => {
+                        let offset = self.header_offset(cx);
+                        let position = column_at(&self.widths, &self.order, offset)
+                            .map(|(position, _)| position)
+                            .unwrap_or(if offset < 0.0 { 0 } else { self.order.len() - 1 });
+                        cx.emit(TableEvent::MoveColumn(column, position));
+                    }
+
+                    Some(ColumnDrag::Move { column, moving: false, .. }) => {
+                        if self.columns[column].compare.is_some() {
+                            cx.emit(TableEvent::SortBy(self.next_sort(column)));
+                        }
+                    }
+
+                    Some(ColumnDrag::Resize { .. }) => {}
+
+                    None => return,
+                }
+
+                cx.release();
+                meta.consume();
+            }
+
+            WindowEvent::MouseScroll(x, y) => {
+                let (x, y) =
+                    if cx.modifiers.contains(Modifiers::SHIFT) { (*y, *x) } else { (*x, *y) };
+                self.scroll_x -= x * SCROLL_SENSITIVITY;
+                self.clamp_scroll_x();
+                self.virtual_rows.scroll_by(-y * SCROLL_SENSITIVITY);
+                meta.consume();
+            }
+
+            WindowEvent::KeyDown(code, _) => {
+                let len = self.rows.len();
+                if len == 0 {
+                    return;
+                }
+
+                // The cursor follows its item when the rows are sorted.
+                let cursor = self.selection.cursor_row(&self.rows, |item| item);
+                let row = match code {
+                    Code::ArrowDown => cursor.map_or(0, |row| (row + 1).min(len - 1)),
+                    Code::ArrowUp => cursor.map_or(0, |row| row.saturating_sub(1)),
+                    Code::Home => 0,
+                    Code::End => len - 1,
+                    Code::PageDown => cursor.map_or(0, |row| self.virtual_rows.page_down(row)),
+                    Code::PageUp => cursor.map_or(0, |row| self.virtual_rows.page_up(row)),
+                    Code::KeyA
+                        if cx.modifiers.contains(Modifiers::CTRL)
+                            && self.selection.mode == SelectionMode::Multiple =>
+                    {
+                        self.selection.select_all(&self.rows, |item| item);
+                        if let Some(callback) = &self.on_select {
+                            (callback)(cx, &self.selection.selected);
+                        }
+
+                        meta.consume();
+                        return;
+                    }
+                    _ => return,
+                };
+
+                let extend = cx.modifiers.contains(Modifiers::SHIFT);
+                self.select_row(cx, row, extend, false);
+                meta.consume();
+            }
+
+            _ => {}
+        });
+    }
+}
+
+impl<L: Lens<Target = Vec<T>>, T> Handle<'_, Table<L, T>> {
+    /// Sets how many rows can be selected at once. Defaults to [`SelectionMode::Single`].
+    pub fn selection_mode(self, selection_mode: SelectionMode) -> Self {
+        self.modify(|table| table.selection.mode = selection_mode)
+    }
+
+    /// Sets the callback which is called when the selection changes, with the indices of the
+    /// items of the selected rows.
+    pub fn on_select<F>(self, callback: F) -> Self
+    where
+        F: 'static + Fn(&mut EventContext, &[usize]),
+    {
+        self.modify(|table| table.on_select = Some(Box::new(callback)))
+    }
+}
+
+// Returns the indices of the items in the order in which their rows are displayed, sorted with a
+// function which compares two items in a direction. Items which compare equal keep their order.
+fn sorted_rows<T>(
+    items: &[T],
+    sort: Option<(&dyn Fn(&T, &T) -> Ordering, SortDirection)>,
+) -> Vec<usize> {
+    let mut rows = (0..items.len()).collect::<Vec<_>>();
+    if let Some((compare, direction)) = sort {
+        rows.sort_by(|a, b| {
+            let ordering = compare(&items[*a], &items[*b]);
+            match direction {
+                SortDirection::Ascending => ordering,
+                SortDirection::Descending => ordering.reverse(),
+            }
+        });
+    }
+
+    rows
+}
+
+// Returns the position among the displayed columns of the column at a horizontal offset from the
+// start of the header in logical pixels, and whether the offset is on the resize handle at the
+// right edge of the column.
+fn column_at(widths: &[f32], order: &[usize], offset: f32) -> Option<(usize, bool)> {
+    if offset < 0.0 {
+        return None;
+    }
+
+    let mut right = 0.0;
+    for (position, column) in order.iter().enumerate() {
+        right += widths[*column];
+        if offset < right {
+            return Some((position, offset >= right - RESIZE_HANDLE_WIDTH));
+        }
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sorted_rows() {
+        let items = [3, 1, 2, 1];
+        let compare = |a: &i32, b: &i32| a.cmp(b);
+        assert_eq!(sorted_rows(&items, None), vec![0, 1, 2, 3]);
+        assert_eq!(
+            sorted_rows(&items, Some((&compare, SortDirection::Ascending))),
+            vec![1, 3, 2, 0]
+        );
+        assert_eq!(
+            sorted_rows(&items, Some((&compare, SortDirection::Descending))),
+            vec![0, 2, 1, 3]
+        );
+    }
+
+    #[test]
+    fn test_column_at() {
+        let widths = [100.0, 50.0, 80.0];
+        let order = [2, 0, 1];
+        assert_eq!(column_at(&widths, &order, -1.0), None);
+        assert_eq!(column_at(&widths, &order, 10.0), Some((0, false)));
+        assert_eq!(column_at(&widths, &order, 78.0), Some((0, true)));
+        assert_eq!(column_at(&widths, &order, 80.0), Some((1, false)));
+        assert_eq!(column_at(&widths, &order, 229.0), Some((2, true)));
+        assert_eq!(column_at(&widths, &order, 230.0), None);
     }
 }
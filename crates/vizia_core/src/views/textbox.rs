@@ -3,10 +3,10 @@ use crate::cache::BoundingBox;
 use crate::context::AccessNode;
 use crate::prelude::*;
 
-use crate::text::{enforce_text_bounds, ensure_visible, Direction, Movement};
+use crate::text::{enforce_text_bounds, ensure_visible, visual_grapheme_move, Direction, Movement};
 use crate::views::scrollview::SCROLL_SENSITIVITY;
 use accesskit::{ActionData, ActionRequest, Rect, TextDirection, TextPosition, TextSelection};
-use cosmic_text::{Action, Attrs, Cursor, Edit};
+use cosmic_text::{Action, Attrs, Cursor, Edit, Editor};
 use std::sync::Arc;
 use unicode_segmentation::UnicodeSegmentation;
 use vizia_id::GenerationalId;
@@ -21,6 +21,8 @@ pub struct TextboxData {
     kind: TextboxKind,
     on_edit: Option<Arc<dyn Fn(&mut EventContext, String) + Send + Sync>>,
     on_submit: Option<Arc<dyn Fn(&mut EventContext, String, bool) + Send + Sync>>,
+    // The range of uncommitted IME text in the buffer.
+    preedit: Option<(Cursor, Cursor)>,
 }
 
 impl TextboxData {
@@ -32,6 +34,7 @@ impl TextboxData {
             content_entity: Entity::null(),
             kind: TextboxKind::SingleLine,
             on_submit: None,
+            preedit: None,
         }
     }
 
@@ -70,6 +73,14 @@ impl TextboxData {
             parent_bounds.x -= 1.0;
             parent_bounds.w += 2.0;
             (tx, ty) = ensure_visible(&caret_box, &parent_bounds, (tx, ty));
+
+            // Place the IME candidate window just below the caret.
+            if self.edit {
+                cx.emit(WindowEvent::SetImePosition(
+                    (caret_box.x + tx) as u32,
+                    (caret_box.y + caret_box.h + ty) as u32,
+                ));
+            }
         }
 
         self.transform = (tx.round() / scale, ty.round() / scale);
@@ -82,6 +93,48 @@ impl TextboxData {
         cx.style.needs_text_layout.insert(self.content_entity, true).unwrap();
     }
 
+    /// Replaces the uncommitted IME text at the caret, placing the caret at the given byte offset
+    /// into the text. An empty text removes the uncommitted text.
+    pub fn set_preedit(&mut self, cx: &mut EventContext, text: &str, cursor: Option<usize>) {
+        self.remove_preedit(cx);
+
+        if text.is_empty() {
+            return;
+        }
+
+        let range = cx.text_context.with_editor(self.content_entity, |buf| {
+            buf.delete_selection();
+            let start = buf.cursor();
+            buf.insert_string(text, None);
+            let end = buf.cursor();
+
+            if let Some(offset) = cursor {
+                let target = Cursor::new(start.line, start.index + offset);
+                step_cursor(buf, Action::Previous, |cursor| cursor > target);
+            }
+
+            (start, end)
+        });
+
+        self.preedit = Some(range);
+        cx.text_context.set_preedit(self.content_entity, Some(range));
+        cx.style.needs_text_layout.insert(self.content_entity, true).unwrap();
+    }
+
+    /// Removes the uncommitted IME text, if any.
+    pub fn remove_preedit(&mut self, cx: &mut EventContext) {
+        if let Some((start, end)) = self.preedit.take() {
+            cx.text_context.with_editor(self.content_entity, |buf| {
+                buf.set_select_opt(Some(start));
+                step_cursor(buf, Action::Next, |cursor| cursor < end);
+                buf.delete_selection();
+            });
+
+            cx.text_context.set_preedit(self.content_entity, None);
+            cx.style.needs_text_layout.insert(self.content_entity, true).unwrap();
+        }
+    }
+
     pub fn delete_text(&mut self, cx: &mut EventContext, movement: Movement) {
         if cx.text_context.with_editor(self.content_entity, |buf| !buf.delete_selection()) {
             self.move_cursor(cx, movement, true);
@@ -109,6 +162,12 @@ impl TextboxData {
                 buf.set_select_opt(None);
             }
 
+            if let Movement::Grapheme(direction @ (Direction::Left | Direction::Right)) = movement {
+                if move_cursor_visually(buf, direction) {
+                    return;
+                }
+            }
+
             buf.action(match movement {
                 Movement::Grapheme(Direction::Upstream) => Action::Previous,
                 Movement::Grapheme(Direction::Downstream) => Action::Next,
@@ -230,6 +289,7 @@ impl TextboxData {
 
 pub enum TextEvent {
     InsertText(String),
+    Preedit(String, Option<usize>),
     ResetText(String),
     DeleteText(Movement),
     MoveCursor(Movement, bool),
@@ -259,6 +319,7 @@ impl Model for TextboxData {
         event.map(|text_event, _| match text_event {
             TextEvent::InsertText(text) => {
                 if self.edit {
+                    self.remove_preedit(cx);
                     self.insert_text(cx, text);
                     self.set_caret(cx);
 
@@ -271,6 +332,13 @@ impl Model for TextboxData {
                 }
             }
 
+            TextEvent::Preedit(text, cursor) => {
+                if self.edit {
+                    self.set_preedit(cx, text, *cursor);
+                    self.set_caret(cx);
+                }
+            }
+
             TextEvent::ResetText(text) => {
                 self.reset_text(cx, text);
                 self.scroll(cx, 0.0, 0.0); // ensure_visible
@@ -303,10 +371,16 @@ impl Model for TextboxData {
                     cx.focus_with_visibility(false);
                     cx.capture();
                     cx.set_checked(true);
+                    cx.emit(WindowEvent::SetImeAllowed(true));
+                    self.set_caret(cx);
                 }
             }
 
             TextEvent::EndEdit => {
+                self.remove_preedit(cx);
+                if self.edit {
+                    cx.emit(WindowEvent::SetImeAllowed(false));
+                }
                 self.deselect(cx);
                 self.edit = false;
                 cx.set_checked(false);
@@ -314,6 +388,7 @@ impl Model for TextboxData {
             }
 
             TextEvent::Submit(reason) => {
+                self.remove_preedit(cx);
                 if let Some(callback) = self.on_submit.take() {
                     let text = self.clone_text(cx);
                     (callback)(cx, text, *reason);
@@ -463,6 +538,7 @@ where
                             content_entity: text_data.content_entity,
                             kind: text_data.kind,
                             on_submit: text_data.on_submit.clone(),
+                            preedit: text_data.preedit,
                         };
                         cx.text_context.with_buffer(text_data.content_entity, |buf| {
                             buf.set_text(&text_str, Attrs::new());
@@ -707,6 +783,9 @@ where
     }
 
     fn event(&mut self, cx: &mut EventContext, event: &mut Event) {
+        // Keys pressed while an IME is composing text are handled by the IME.
+        let composing = cx.data::<TextboxData>().map_or(false, |data| data.preedit.is_some());
+
         event.map(|window_event, _| match window_event {
             WindowEvent::MouseDown(MouseButton::Left) => {
                 if cx.is_over() {
@@ -793,6 +872,16 @@ where
                 }
             }
 
+            WindowEvent::ImePreedit(text, cursor) => {
+                cx.emit(TextEvent::Preedit(text.clone(), cursor.map(|(start, _)| start)));
+            }
+
+            WindowEvent::ImeCommit(text) => {
+                cx.emit(TextEvent::InsertText(text.clone()));
+            }
+
+            WindowEvent::KeyDown(..) if composing => {}
+
             WindowEvent::KeyDown(code, _) => match code {
                 Code::Enter => {
                     // Finish editing
@@ -992,6 +1081,57 @@ where
     }
 }
 
+/// Moves the cursor of the editor by one grapheme in visual order within its line.
+///
+/// Returns false if the cursor is at the edge of the line, in which case the regular cosmic action
+/// should be used to move onto the adjacent line.
+fn move_cursor_visually(editor: &mut Editor, direction: Direction) -> bool {
+    let cursor = editor.cursor();
+
+    let line =
+        editor.buffer().layout_runs().filter(|run| run.line_i == cursor.line).find_map(|run| {
+            let start = run.glyphs.iter().map(|glyph| glyph.start).min()?;
+            let end = run.glyphs.iter().map(|glyph| glyph.end).max()?;
+            (start <= cursor.index && cursor.index <= end)
+                .then(|| (run.text.to_owned(), start..end))
+        });
+
+    let target = match line {
+        Some((text, range)) => visual_grapheme_move(&text, range, cursor.index, direction),
+        None => None,
+    };
+
+    let target = match target {
+        Some(target) => target,
+        None => return false,
+    };
+
+    // Cosmic has no way to set the cursor directly, so step through the line logically until the
+    // target is reached.
+    let action = if target > cursor.index { Action::Next } else { Action::Previous };
+    while editor.cursor().line == cursor.line && editor.cursor().index != target {
+        let previous = editor.cursor();
+        editor.action(action);
+        if editor.cursor() == previous {
+            break;
+        }
+    }
+
+    true
+}
+
+// Steps the cursor of the editor with an action while the condition holds, stopping early if the
+// cursor stops moving. Cosmic has no way to set the cursor directly.
+fn step_cursor(editor: &mut Editor, action: Action, condition: impl Fn(Cursor) -> bool) {
+    while condition(editor.cursor()) {
+        let previous = editor.cursor();
+        editor.action(action);
+        if editor.cursor() == previous {
+            break;
+        }
+    }
+}
+
 // can't just be a stack because what if you've styled stacks
 pub struct TextboxContainer {}
 impl View for TextboxContainer {
@@ -0,0 +1,199 @@
+use instant::Duration;
+use morphorm::PositionType;
+
+use super::popup::{place, set_position};
+use crate::cache::BoundingBox;
+use crate::prelude::*;
+
+/// The time for which the cursor must rest over a view before its tooltip is shown.
+pub const TOOLTIP_DELAY: Duration = Duration::from_millis(500);
+
+// The gap between a tooltip and the view it describes, in logical pixels.
+const TOOLTIP_OFFSET: f32 = 4.0;
+
+pub(crate) enum TooltipEvent {
+    // Shows the tooltip below the view it describes, at a horizontal position in physical pixels.
+    Show(f32),
+    Hide,
+}
+
+/// A popup which describes a view and is shown after the cursor has rested over the view for
+/// [`TOOLTIP_DELAY`].
+///
+/// A tooltip is added to a view with the `tooltip` or `tooltip_text` modifiers. The tooltip is
+/// shown below the view, or above it when it does not fit below it and there is more room above
+/// it in the window, and is hidden when the cursor leaves the view or when the view is pressed or
+/// scrolled. The tooltip can be styled with the `tooltip` element selector.
+///
+/// The tooltip is built into the window of the view, so that it is not clipped by the ancestors of
+/// the view, and is removed with the view.
+#[derive(Lens)]
+pub struct Tooltip {
+    is_open: bool,
+    // The horizontal position at which the tooltip was shown, in physical pixels.
+    anchor: f32,
+    // The view which the tooltip describes.
+    target: Entity,
+}
+
+impl Tooltip {
+    // Builds a tooltip for the current view into the window of the view, so that the tooltip is
+    // not clipped by the ancestors of the view.
+    pub(crate) fn new<F>(cx: &mut Context, content: F)
+    where
+        F: 'static + Fn(&mut Context),
+    {
+        let target = cx.current();
+        let window = cx.window_of(target);
+        let mut tooltip = Entity::null();
+        cx.with_current(window, |cx| {
+            tooltip = Self { is_open: false, anchor: 0.0, target }
+                .build(cx, move |cx| {
+                    Binding::new(cx, Tooltip::is_open, move |cx, is_open| {
+                        if is_open.get(cx) {
+                            (content)(cx);
+                        }
+                    });
+                })
+                .role(Role::Tooltip)
+                .position_type(PositionType::SelfDirected)
+                .size(Auto)
+                .z_order(110)
+                .hoverable(false)
+                .display(Tooltip::is_open)
+                .entity;
+        });
+
+        cx.add_dependent(target, tooltip);
+        TooltipTrigger { tooltip, timer: None }.build(cx);
+    }
+
+    // Positions the tooltip below the view it describes at its anchor, moving it above the view
+    // when it does not fit below it and there is more room above it, and keeps it within the
+    // window horizontally.
+    fn place(&self, cx: &mut EventContext) {
+        let tooltip = cx.current();
+        let window = cx.cache.get_bounds(cx.window_of(tooltip));
+        let bounds = cx.cache.get_bounds(tooltip);
+        let target_bounds = cx.cache.get_bounds(self.target);
+        let offset = cx.style.logical_to_physical(TOOLTIP_OFFSET);
+
+        let trigger = BoundingBox { x: self.anchor, w: 0.0, ..target_bounds };
+        let (x, y) = place(Placement::BottomStart, offset, trigger, (bounds.w, bounds.h), window);
+        set_position(cx, tooltip, x, y, bounds.w);
+    }
+}
+
+impl View for Tooltip {
+    fn element(&self) -> Option<&'static str> {
+        Some("tooltip")
+    }
+
+    fn event(&mut self, cx: &mut EventContext, event: &mut Event) {
+        event.map(|tooltip_event, meta| {
+            match tooltip_event {
+                TooltipEvent::Show(x) => {
+                    self.is_open = true;
+                    self.anchor = *x;
+                    self.place(cx);
+                }
+
+                TooltipEvent::Hide => {
+                    self.is_open = false;
+                }
+            }
+
+            meta.consume();
+        });
+
+        event.map(|window_event, meta| {
+            if let WindowEvent::GeometryChanged(_) = window_event {
+                if meta.target == cx.current() && self.is_open {
+                    self.place(cx);
+                }
+            }
+        });
+    }
+}
+
+// Shows a tooltip once the cursor has rested over the view which it is built on, and hides it when
+// the cursor leaves the view or when the view is pressed or scrolled.
+struct TooltipTrigger {
+    tooltip: Entity,
+    timer: Option<Timer>,
+}
+
+impl TooltipTrigger {
+    fn hide(&mut self, cx: &mut EventContext) {
+        if let Some(timer) = self.timer.take() {
+            cx.cancel_timer(timer);
+        }
+
+        cx.emit_to(self.tooltip, TooltipEvent::Hide);
+    }
+}
+
+impl Model for TooltipTrigger {
+    fn capture_event(&mut self, cx: &mut EventContext, event: &mut Event) {
+        // Descendants which handle presses usually consume them, so the tooltip is hidden while a
+        // press travels down the tree instead.
+        event.map(|window_event, _| {
+            if let WindowEvent::MouseDown(_) = window_event {
+                self.hide(cx);
+            }
+        });
+    }
+
+    fn event(&mut self, cx: &mut EventContext, event: &mut Event) {
+        event.map(|timer_event, meta| {
+            let TimerEvent::Tick(timer) = timer_event;
+            if self.timer == Some(*timer) {
+                self.timer = None;
+                cx.emit_to(self.tooltip, TooltipEvent::Show(cx.mouse.cursorx));
+                meta.consume();
+            }
+        });
+
+        event.map(|window_event, meta| match window_event {
+            WindowEvent::MouseOver if meta.target == cx.current() => {
+                if self.timer.is_none() {
+                    self.timer = Some(cx.add_timer(TOOLTIP_DELAY, false, cx.current()));
+                }
+            }
+
+            WindowEvent::MouseOut if meta.target == cx.current() => {
+                self.hide(cx);
+            }
+
+            WindowEvent::MouseDown(_) | WindowEvent::MouseScroll(_, _) => {
+                self.hide(cx);
+            }
+
+            _ => {}
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tooltip_removed_with_target() {
+        let cx = &mut Context::default();
+        let stack = VStack::new(cx, |cx| {
+            Element::new(cx).tooltip(|cx| {
+                Label::new(cx, "Tooltip");
+            });
+        })
+        .entity;
+
+        let target = cx.tree.get_first_child(stack).unwrap();
+        let tooltip = cx.dependents[&target][0];
+        assert_eq!(cx.tree.get_parent(tooltip), Some(Entity::root()));
+
+        cx.remove(stack);
+        assert!(!cx.entity_manager.is_alive(tooltip));
+        assert!(cx.dependents.is_empty());
+    }
+}
@@ -0,0 +1,792 @@
+use std::collections::HashSet;
+use std::rc::Rc;
+
+use vizia_input::Code;
+
+use super::scrollview::SCROLL_SENSITIVITY;
+use super::virtual_list::{
+    build_rows, build_scrollbar, RowsLens, Selection, VirtualRowEvent, VirtualRows,
+};
+use crate::fonts::icons_names::{DOWN, RIGHT};
+use crate::prelude::*;
+use crate::state::{Then, TreeIndex};
+use crate::views::SelectionMode;
+
+/// Where a node of a [`TreeView`] was dropped relative to the node it was dropped onto.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TreeDropPosition {
+    /// Before the node, as its previous sibling.
+    Before,
+    /// Into the node, as one of its children.
+    Inside,
+    /// After the node, as its next sibling.
+    After,
+}
+
+/// Events which can be sent to a [`TreeView`].
+///
+/// Nodes are identified by their path, which is the index of the root item followed by the index
+/// of each child from that item down to the node.
+pub enum TreeViewEvent {
+    /// Expands a node and its ancestors.
+    Expand(Vec<usize>),
+    /// Collapses a node.
+    Collapse(Vec<usize>),
+    /// Expands all of the nodes which have been loaded.
+    ExpandAll,
+    /// Collapses all of the nodes.
+    CollapseAll,
+    /// Selects a node, expanding its ancestors and scrolling it into view.
+    Select(Vec<usize>),
+    /// Deselects all of the nodes.
+    ClearSelection,
+    /// Expands the ancestors of a node and scrolls it into view.
+    ScrollTo(Vec<usize>),
+}
+
+enum TreeRowEvent {
+    // The expander of a row in a slot was pressed.
+    Toggle(usize),
+    // A node was dropped onto a row in a slot, at a vertical position between 0 and 1.
+    Drop(usize, Vec<usize>, f32),
+}
+
+// The payload of a dragged row, which can only be dropped onto the rows of the same tree view.
+#[derive(Clone)]
+struct TreeDrag {
+    tree: Entity,
+    path: Vec<usize>,
+}
+
+// A node which is displayed as a row, because all of its ancestors are expanded.
+#[derive(Debug, Clone, PartialEq)]
+struct TreeRow {
+    path: Vec<usize>,
+    expandable: bool,
+    expanded: bool,
+}
+
+/// A view for displaying a binding to a tree of items, in which the children of each item are
+/// returned by a function, which only builds the rows which are visible.
+///
+/// Only the root items are displayed at first. A node with children can be expanded, which
+/// displays its children as indented rows below it, by pressing its expander or with the right
+/// arrow key, and collapsed again with the left arrow key. The `*` key expands all of the siblings
+/// of the focused node. Each level of indentation is an `indent_guide` element, which can be
+/// styled to draw guide lines.
+///
+/// The children of a node can be loaded lazily with [`TreeView::new_lazy`], by adding them to the
+/// data of the tree from the callback set with [`on_expand`](Handle::on_expand).
+///
+/// Nodes can be selected by pressing them or with the arrow, page up, page down, home and end keys
+/// while the tree has keyboard focus, and are selected by their path. Selected rows have the
+/// `checked` pseudo-class. Rows can also be dragged onto other rows, which calls the callback set
+/// with [`on_drop`](Handle::on_drop) so that the nodes can be moved in the data of the tree.
+///
+/// Nodes are identified by their path, so the expanded and selected nodes do not follow a node
+/// which is moved to another path. All rows have the same height, and the tree must be given a
+/// height which does not depend on its content, such as `Stretch(1.0)`.
+///
+/// # Example
+/// ```
+/// # use vizia_core::prelude::*;
+/// # let cx = &mut Context::default();
+/// # #[derive(Clone, Data, Lens)]
+/// # pub struct File {
+/// #     name: String,
+/// #     children: Vec<File>,
+/// # }
+/// # #[derive(Lens)]
+/// # pub struct AppData {
+/// #     files: Vec<File>,
+/// # }
+/// # impl Model for AppData {}
+/// # AppData { files: Vec::new() }.build(cx);
+/// TreeView::new(cx, AppData::files, 24.0, |file| &file.children, |cx, _, file| {
+///     Label::new(cx, file.then(File::name));
+/// })
+/// .selection_mode(SelectionMode::Multiple)
+/// .on_select(|_, paths| println!("Selected {:?}", paths));
+/// ```
+#[derive(Lens)]
+pub struct TreeView<L: Lens<Target = Vec<T>>, T: 'static> {
+    #[lens(ignore)]
+    lens: L,
+    #[lens(ignore)]
+    children: Rc<dyn Fn(&T) -> &Vec<T>>,
+    #[lens(ignore)]
+    has_children: Option<Rc<dyn Fn(&T) -> bool>>,
+    // The paths of the expanded nodes.
+    #[lens(ignore)]
+    expanded: HashSet<Vec<usize>>,
+    // The nodes in the order in which they are displayed.
+    #[lens(ignore)]
+    rows: Vec<TreeRow>,
+    // The selected nodes, by their path.
+    #[lens(ignore)]
+    selection: Selection<Vec<usize>>,
+    #[lens(ignore)]
+    virtual_rows: VirtualRows,
+    on_select: Option<Box<dyn Fn(&mut EventContext, &[Vec<usize>])>>,
+    on_expand: Option<Box<dyn Fn(&mut EventContext, &[usize])>>,
+    on_collapse: Option<Box<dyn Fn(&mut EventContext, &[usize])>>,
+    #[lens(ignore)]
+    #[allow(clippy::type_complexity)]
+    on_drop: Option<Box<dyn Fn(&mut EventContext, &[usize], &[usize], TreeDropPosition)>>,
+}
+
+impl<L: 'static + Lens<Target = Vec<T>>, T> TreeView<L, T>
+where
+    <L as Lens>::Source: 'static,
+{
+    /// Creates a new tree view with rows of a fixed height in logical pixels, with a binding to
+    /// the root items of the tree, a function which returns the children of an item and a
+    /// template for constructing the row of an item, which is given the path of the item and a
+    /// lens to it.
+    pub fn new<C, F>(
+        cx: &mut Context,
+        lens: L,
+        row_height: f32,
+        children: C,
+        item: F,
+    ) -> Handle<Self>
+    where
+        C: 'static + Fn(&T) -> &Vec<T>,
+        F: 'static + Fn(&mut Context, &[usize], Then<L, TreeIndex<T>>),
+        <L as Lens>::Source: Model,
+    {
+        Self::build_tree(cx, lens, row_height, Rc::new(children), None, item)
+    }
+
+    /// Creates a new tree view in which the children of an item can be loaded when it is
+    /// expanded.
+    ///
+    /// Items for which `has_children` returns true can be expanded before their children have
+    /// been added to the data of the tree, such as from the callback set with
+    /// [`on_expand`](Handle::on_expand).
+    pub fn new_lazy<C, H, F>(
+        cx: &mut Context,
+        lens: L,
+        row_height: f32,
+        children: C,
+        has_children: H,
+        item: F,
+    ) -> Handle<Self>
+    where
+        C: 'static + Fn(&T) -> &Vec<T>,
+        H: 'static + Fn(&T) -> bool,
+        F: 'static + Fn(&mut Context, &[usize], Then<L, TreeIndex<T>>),
+        <L as Lens>::Source: Model,
+    {
+        Self::build_tree(cx, lens, row_height, Rc::new(children), Some(Rc::new(has_children)), item)
+    }
+
+    fn build_tree<F>(
+        cx: &mut Context,
+        lens: L,
+        row_height: f32,
+        children: Rc<dyn Fn(&T) -> &Vec<T>>,
+        has_children: Option<Rc<dyn Fn(&T) -> bool>>,
+        item: F,
+    ) -> Handle<Self>
+    where
+        F: 'static + Fn(&mut Context, &[usize], Then<L, TreeIndex<T>>),
+        <L as Lens>::Source: Model,
+    {
+        let item = Rc::new(item);
+
+        Self {
+            lens: lens.clone(),
+            children: children.clone(),
+            has_children: has_children.clone(),
+            expanded: HashSet::new(),
+            rows: Vec::new(),
+            selection: Selection::new(),
+            virtual_rows: VirtualRows::new(row_height),
+            on_select: None,
+            on_expand: None,
+            on_collapse: None,
+            on_drop: None,
+        }
+        .build(cx, move |cx| {
+            let tree = cx.current();
+            let rows = RowsLens::new(|tree: &Self| &tree.virtual_rows);
+
+            // Adding or removing nodes anywhere in the tree displays the rows again, but changing
+            // the items themselves only updates the bindings within the rows.
+            let shape_children = children.clone();
+            let shape = lens
+                .clone()
+                .map(move |items| tree_shape(items, &*shape_children, has_children.as_deref()));
+            Binding::new(cx, shape, move |cx, _| {
+                if let Some(mut view) = cx.views.remove(&tree) {
+                    if let Some(tree_view) = view.downcast_mut::<Self>() {
+                        tree_view.update_rows(&*cx);
+                    }
+
+                    cx.views.insert(tree, view);
+                }
+            });
+
+            build_rows(cx, rows, move |cx, slot| {
+                let lens = lens.clone();
+                let children = children.clone();
+                let item = item.clone();
+                HStack::new(cx, move |cx| {
+                    let path = Self::root
+                        .map(move |tree: &Self| tree.slot_row(slot).map(|row| row.path.clone()));
+                    Binding::new(cx, path, move |cx, path| {
+                        if let Some(path) = path.get(cx) {
+                            let lens = lens.clone();
+                            let children = children.clone();
+                            let item = item.clone();
+                            HStack::new(cx, |cx| {
+                                for _ in 1..path.len() {
+                                    Element::new(cx).class("indent_guide");
+                                }
+
+                                let icon = Self::root.map(move |tree: &Self| {
+                                    let expanded =
+                                        tree.slot_row(slot).map_or(false, |row| row.expanded);
+                                    let icon = if expanded { DOWN } else { RIGHT };
+                                    icon.to_string()
+                                });
+                                Label::new(cx, icon)
+                                    .class("icon")
+                                    .class("expander")
+                                    .visibility(Self::root.map(move |tree: &Self| {
+                                        tree.slot_row(slot).map_or(false, |row| row.expandable)
+                                    }))
+                                    .on_press(move |cx| cx.emit(TreeRowEvent::Toggle(slot)));
+
+                                let index = TreeIndex::new(path.clone(), children);
+                                HStack::new(cx, |cx| (item)(cx, &path, lens.then(index)))
+                                    .class("content");
+                            })
+                            .class("node")
+                            .draggable(TreeDrag { tree, path: path.clone() });
+                        }
+                    });
+                })
+                .role(Role::TreeItem)
+                .height(Pixels(row_height))
+                .checked(Self::root.map(move |tree: &Self| tree.is_slot_selected(slot)))
+                .drop_target(
+                    move |drag: &TreeDrag| drag.tree == tree,
+                    move |cx, drag: TreeDrag| {
+                        let bounds = cx.cache.get_bounds(cx.current());
+                        let offset = if bounds.h > 0.0 {
+                            (cx.mouse.cursory - bounds.y) / bounds.h
+                        } else {
+                            0.5
+                        };
+                        cx.emit(TreeRowEvent::Drop(slot, drag.path, offset));
+                    },
+                )
+            })
+            .class("tree_content");
+
+            build_scrollbar(cx, rows);
+        })
+        .role(Role::Tree)
+        .navigable(true)
+    }
+}
+
+impl<L: Lens<Target = Vec<T>>, T> TreeView<L, T> {
+    // Displays the rows of the expanded nodes again, and removes the nodes which no longer exist
+    // from the expanded and selected nodes.
+    fn update_rows<C: DataContext>(&mut self, cx: &C)
+    where
+        <L as Lens>::Source: 'static,
+    {
+        let data =
+            cx.data().expect("Failed to get data from context. Has it been built into the tree?");
+        let lens = self.lens.clone();
+        lens.view(data, |items| {
+            let items = items.map_or(&[][..], |items| items.as_slice());
+            let children = &*self.children;
+            let exists = |path: &Vec<usize>| find_node(items, children, path).is_some();
+            self.expanded.retain(exists);
+            self.selection.retain(exists);
+
+            let expanded = &self.expanded;
+            self.rows = flatten(items, children, self.has_children.as_deref(), &|path| {
+                expanded.contains(path)
+            });
+        });
+
+        self.virtual_rows.resize(self.rows.len());
+    }
+
+    // Returns the row displayed in a slot.
+    fn slot_row(&self, slot: usize) -> Option<&TreeRow> {
+        self.virtual_rows.slot_row(slot).and_then(|row| self.rows.get(row))
+    }
+
+    fn is_slot_selected(&self, slot: usize) -> bool {
+        self.slot_row(slot).map_or(false, |row| self.selection.is_selected(&row.path))
+    }
+
+    // Returns the index of the row of a node, if the node is displayed.
+    fn row_of(&self, path: &[usize]) -> Option<usize> {
+        self.rows.iter().position(|row| row.path == path)
+    }
+
+    // Expands or collapses nodes, calling the expand or collapse callback for each node which
+    // changed. The cursor and anchor move to a collapsed node from its descendants.
+    fn set_expanded(&mut self, cx: &mut EventContext, paths: Vec<Vec<usize>>, expanded: bool)
+    where
+        <L as Lens>::Source: 'static,
+    {
+        for path in paths {
+            if expanded {
+                if !self.expanded.insert(path.clone()) {
+                    continue;
+                }
+
+                if let Some(callback) = &self.on_expand {
+                    (callback)(cx, &path);
+                }
+            } else {
+                if !self.expanded.remove(&path) {
+                    continue;
+                }
+
+                for node in [&mut self.selection.cursor, &mut self.selection.anchor] {
+                    if node.as_ref().map_or(false, |node| node.starts_with(&path)) {
+                        *node = Some(path.clone());
+                    }
+                }
+
+                if let Some(callback) = &self.on_collapse {
+                    (callback)(cx, &path);
+                }
+            }
+        }
+
+        self.update_rows(&*cx);
+    }
+
+    // Expands the ancestors of a node, so that the node is displayed.
+    fn reveal(&mut self, cx: &mut EventContext, path: &[usize])
+    where
+        <L as Lens>::Source: 'static,
+    {
+        let ancestors = (1..path.len()).map(|len| path[..len].to_vec()).collect();
+        self.set_expanded(cx, ancestors, true);
+    }
+
+    // Selects a row, extending the selection from the anchor node when `extend` is true, or
+    // adding the node to or removing it from the selection when `toggle` is true.
+    fn select_row(&mut self, cx: &mut EventContext, row: usize, extend: bool, toggle: bool) {
+        if !self.selection.select(&self.rows, |row| &row.path, row, extend, toggle) {
+            return;
+        }
+
+        self.virtual_rows.scroll_to_row(row);
+
+        if let Some(callback) = &self.on_select {
+            (callback)(cx, &self.selection.selected);
+        }
+    }
+}
+
+impl<L: 'static + Lens<Target = Vec<T>>, T> View for TreeView<L, T>
+where
+    <L as Lens>::Source: 'static,
+{
+    fn element(&self) -> Option<&'static str> {
+        Some("treeview")
+    }
+
+    fn event(&mut self, cx: &mut EventContext, event: &mut Event) {
+        event.map(|tree_event, meta| {
+            match tree_event {
+                TreeViewEvent::Expand(path) => {
+                    let mut paths =
+                        (1..path.len()).map(|len| path[..len].to_vec()).collect::<Vec<_>>();
+                    paths.push(path.clone());
+                    self.set_expanded(cx, paths, true);
+                }
+
+                TreeViewEvent::Collapse(path) => self.set_expanded(cx, vec![path.clone()], false),
+
+                TreeViewEvent::ExpandAll => {
+                    let data = cx.data().expect(
+                        "Failed to get data from context. Has it been built into the tree?",
+                    );
+                    let rows = self.lens.view(data, |items| {
+                        let items = items.map_or(&[][..], |items| items.as_slice());
+                        let has_children = self.has_children.as_deref();
+                        flatten(items, &*self.children, has_children, &|_| true)
+                    });
+                    let paths =
+                        rows.into_iter().filter(|row| row.expandable).map(|row| row.path).collect();
+                    self.set_expanded(cx, paths, true);
+                }
+
+                TreeViewEvent::CollapseAll => {
+                    let paths = self.expanded.iter().cloned().collect();
+                    self.set_expanded(cx, paths, false);
+                }
+
+                TreeViewEvent::Select(path) => {
+                    self.reveal(cx, path);
+                    if let Some(row) = self.row_of(path) {
+                        self.select_row(cx, row, false, false);
+                    }
+                }
+
+                TreeViewEvent::ClearSelection => {
+                    self.selection.clear();
+                    if let Some(callback) = &self.on_select {
+                        (callback)(cx, &self.selection.selected);
+                    }
+                }
+
+                TreeViewEvent::ScrollTo(path) => {
+                    self.reveal(cx, path);
+                    if let Some(row) = self.row_of(path) {
+                        self.virtual_rows.scroll_to_row(row);
+                    }
+                }
+            }
+
+            meta.consume();
+        });
+
+        event.map(|row_event, meta| {
+            match row_event {
+                VirtualRowEvent::Press(slot) => {
+                    if let Some(row) = self.virtual_rows.slot_row(*slot) {
+                        cx.focus_with_visibility(false);
+                        let extend = cx.modifiers.contains(Modifiers::SHIFT);
+                        let toggle = cx.modifiers.contains(Modifiers::CTRL);
+                        self.select_row(cx, row, extend, toggle);
+                    }
+                }
+
+                VirtualRowEvent::Scroll(value) => self.virtual_rows.scroll_to_ratio(*value),
+
+                VirtualRowEvent::Measure(..) => {}
+            }
+
+            meta.consume();
+        });
+
+        event.map(|row_event, meta| {
+            match row_event {
+                TreeRowEvent::Toggle(slot) => {
+                    if let Some(row) = self.slot_row(*slot).cloned() {
+                        self.set_expanded(cx, vec![row.path], !row.expanded);
+                    }
+                }
+
+                TreeRowEvent::Drop(slot, dragged, offset) => {
+                    if let Some(row) = self.slot_row(*slot) {
+                        // A node cannot be dropped onto itself or one of its descendants.
+                        if row.path.starts_with(dragged) {
+                            return;
+                        }
+
+                        let position = if *offset < 0.25 {
+                            TreeDropPosition::Before
+                        } else if *offset > 0.75 {
+                            TreeDropPosition::After
+                        } else {
+                            TreeDropPosition::Inside
+                        };
+
+                        if let Some(callback) = &self.on_drop {
+                            (callback)(cx, dragged, &row.path, position);
+                        }
+                    }
+                }
+            }
+
+            meta.consume();
+        });
+
+        event.map(|window_event, meta| match window_event {
+            WindowEvent::GeometryChanged(_) if meta.target == cx.current() => {
+                let height = cx.style.physical_to_logical(cx.cache.get_height(cx.current()));
+                self.virtual_rows.set_viewport(height);
+            }
+
+            WindowEvent::MouseScroll(_, y) => {
+                self.virtual_rows.scroll_by(-*y * SCROLL_SENSITIVITY);
+                meta.consume();
+            }
+
+            WindowEvent::KeyDown(code, key) => {
+                let len = self.rows.len();
+                if len == 0 {
+                    return;
+                }
+
+                let cursor = self.selection.cursor_row(&self.rows, |row| &row.path);
+                let row = match code {
+                    Code::ArrowDown => cursor.map_or(0, |row| (row + 1).min(len - 1)),
+                    Code::ArrowUp => cursor.map_or(0, |row| row.saturating_sub(1)),
+                    Code::Home => 0,
+                    Code::End => len - 1,
+                    Code::PageDown => cursor.map_or(0, |row| self.virtual_rows.page_down(row)),
+                    Code::PageUp => cursor.map_or(0, |row| self.virtual_rows.page_up(row)),
+
+                    // Expands a collapsed node, or moves to the first child of an expanded node.
+                    Code::ArrowRight => match cursor {
+                        Some(row) => {
+                            let node = self.rows[row].clone();
+                            if node.expandable && !node.expanded {
+                                self.set_expanded(cx, vec![node.path], true);
+                                meta.consume();
+                                return;
+                            }
+
+                            match self.rows.get(row + 1) {
+                                Some(next) if next.path.len() > node.path.len() => row + 1,
+                                _ => return,
+                            }
+                        }
+
+                        None => 0,
+                    },
+
+                    // Collapses an expanded node, or moves to the parent of a collapsed node.
+                    Code::ArrowLeft => match cursor {
+                        Some(row) => {
+                            let node = self.rows[row].clone();
+                            if node.expanded {
+                                self.set_expanded(cx, vec![node.path], false);
+                                meta.consume();
+                                return;
+                            }
+
+                            match self.row_of(&node.path[..node.path.len() - 1]) {
+                                Some(parent) => parent,
+                                None => return,
+                            }
+                        }
+
+                        None => 0,
+                    },
+
+                    Code::KeyA
+                        if cx.modifiers.contains(Modifiers::CTRL)
+                            && self.selection.mode == SelectionMode::Multiple =>
+                    {
+                        self.selection.select_all(&self.rows, |row| &row.path);
+                        if let Some(callback) = &self.on_select {
+                            (callback)(cx, &self.selection.selected);
+                        }
+
+                        meta.consume();
+                        return;
+                    }
+
+                    // Expands all of the siblings of the focused node.
+                    _ if *code == Code::NumpadMultiply
+                        || matches!(key, Some(Key::Character(c)) if c == "*") =>
+                    {
+                        if let Some(row) = cursor {
+                            let parent = &self.rows[row].path[..self.rows[row].path.len() - 1];
+                            let siblings = self
+                                .rows
+                                .iter()
+                                .filter(|row| {
+                                    row.expandable
+                                        && row.path.len() == parent.len() + 1
+                                        && row.path.starts_with(parent)
+                                })
+                                .map(|row| row.path.clone())
+                                .collect();
+                            self.set_expanded(cx, siblings, true);
+                        }
+
+                        meta.consume();
+                        return;
+                    }
+
+                    _ => return,
+                };
+
+                let extend = cx.modifiers.contains(Modifiers::SHIFT);
+                self.select_row(cx, row, extend, false);
+                meta.consume();
+            }
+
+            _ => {}
+        });
+    }
+}
+
+impl<L: Lens<Target = Vec<T>>, T> Handle<'_, TreeView<L, T>> {
+    /// Sets how many nodes can be selected at once. Defaults to [`SelectionMode::Single`].
+    pub fn selection_mode(self, selection_mode: SelectionMode) -> Self {
+        self.modify(|tree| tree.selection.mode = selection_mode)
+    }
+
+    /// Sets the callback which is called when the selection changes, with the paths of the
+    /// selected nodes.
+    pub fn on_select<F>(self, callback: F) -> Self
+    where
+        F: 'static + Fn(&mut EventContext, &[Vec<usize>]),
+    {
+        self.modify(|tree| tree.on_select = Some(Box::new(callback)))
+    }
+
+    /// Sets the callback which is called when a node is expanded, with the path of the node.
+    ///
+    /// The children of the node can be added to the data of the tree from this callback, see
+    /// [`TreeView::new_lazy`].
+    pub fn on_expand<F>(self, callback: F) -> Self
+    where
+        F: 'static + Fn(&mut EventContext, &[usize]),
+    {
+        self.modify(|tree| tree.on_expand = Some(Box::new(callback)))
+    }
+
+    /// Sets the callback which is called when a node is collapsed, with the path of the node.
+    pub fn on_collapse<F>(self, callback: F) -> Self
+    where
+        F: 'static + Fn(&mut EventContext, &[usize]),
+    {
+        self.modify(|tree| tree.on_collapse = Some(Box::new(callback)))
+    }
+
+    /// Sets the callback which is called when a row is dragged onto another row, with the path of
+    /// the dragged node, the path of the node it was dropped onto and where it was dropped
+    /// relative to that node.
+    ///
+    /// The tree view does not change the data of the tree, so the callback should move the node.
+    pub fn on_drop<F>(self, callback: F) -> Self
+    where
+        F: 'static + Fn(&mut EventContext, &[usize], &[usize], TreeDropPosition),
+    {
+        self.modify(|tree| tree.on_drop = Some(Box::new(callback)))
+    }
+}
+
+// Returns the node at a path.
+fn find_node<'a, T>(
+    items: &'a [T],
+    children: &dyn Fn(&T) -> &Vec<T>,
+    path: &[usize],
+) -> Option<&'a T> {
+    let (first, rest) = path.split_first()?;
+    let mut node = items.get(*first)?;
+    for index in rest {
+        node = children(node).get(*index)?;
+    }
+
+    Some(node)
+}
+
+fn is_expandable<T>(
+    node: &T,
+    children: &dyn Fn(&T) -> &Vec<T>,
+    has_children: Option<&dyn Fn(&T) -> bool>,
+) -> bool {
+    !children(node).is_empty() || has_children.map_or(false, |has_children| has_children(node))
+}
+
+// Returns the number of children of each node and whether it can be expanded, in depth-first
+// order, which changes when nodes are added or removed anywhere in the tree.
+fn tree_shape<T>(
+    items: &[T],
+    children: &dyn Fn(&T) -> &Vec<T>,
+    has_children: Option<&dyn Fn(&T) -> bool>,
+) -> Vec<(usize, bool)> {
+    let mut shape = vec![(items.len(), true)];
+    let mut stack = items.iter().rev().collect::<Vec<_>>();
+    while let Some(node) = stack.pop() {
+        let node_children = children(node);
+        shape.push((node_children.len(), is_expandable(node, children, has_children)));
+        stack.extend(node_children.iter().rev());
+    }
+
+    shape
+}
+
+// Returns the rows of the nodes which are displayed, which are the root items and the children of
+// expanded nodes, in depth-first order.
+fn flatten<T>(
+    items: &[T],
+    children: &dyn Fn(&T) -> &Vec<T>,
+    has_children: Option<&dyn Fn(&T) -> bool>,
+    is_expanded: &dyn Fn(&[usize]) -> bool,
+) -> Vec<TreeRow> {
+    let mut rows = Vec::new();
+    let mut stack =
+        items.iter().enumerate().rev().map(|(index, item)| (vec![index], item)).collect::<Vec<_>>();
+    while let Some((path, node)) = stack.pop() {
+        let expandable = is_expandable(node, children, has_children);
+        let expanded = expandable && is_expanded(&path);
+        if expanded {
+            stack.extend(children(node).iter().enumerate().rev().map(|(index, child)| {
+                let mut child_path = path.clone();
+                child_path.push(index);
+                (child_path, child)
+            }));
+        }
+
+        rows.push(TreeRow { path, expandable, expanded });
+    }
+
+    rows
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Node(Vec<Node>);
+
+    fn children(node: &Node) -> &Vec<Node> {
+        &node.0
+    }
+
+    #[test]
+    fn test_flatten() {
+        let items = vec![Node(vec![Node(vec![]), Node(vec![Node(vec![])])]), Node(vec![])];
+        let paths = |rows: Vec<TreeRow>| rows.into_iter().map(|row| row.path).collect::<Vec<_>>();
+
+        let rows = flatten(&items, &children, None, &|_| false);
+        assert_eq!(rows[0], TreeRow { path: vec![0], expandable: true, expanded: false });
+        assert_eq!(rows[1], TreeRow { path: vec![1], expandable: false, expanded: false });
+        assert_eq!(rows.len(), 2);
+
+        let rows = flatten(&items, &children, None, &|_| true);
+        assert_eq!(paths(rows), vec![vec![0], vec![0, 0], vec![0, 1], vec![0, 1, 0], vec![1]]);
+
+        // The children of a collapsed node are not displayed, even if the children are expanded.
+        let rows = flatten(&items, &children, None, &|path| path != [0]);
+        assert_eq!(paths(rows), vec![vec![0], vec![1]]);
+
+        // Nodes without children can be expandable before their children are loaded.
+        let has_children = |node: &Node| node.0.is_empty();
+        let rows = flatten(&items, &children, Some(&has_children), &|_| true);
+        assert!(rows.iter().all(|row| row.expandable && row.expanded));
+
+        assert_eq!(find_node(&items, &children, &[0, 1, 0]).map(|node| node.0.len()), Some(0));
+        assert!(find_node(&items, &children, &[0, 2]).is_none());
+        assert!(find_node(&items, &children, &[]).is_none());
+    }
+
+    #[test]
+    fn test_tree_shape() {
+        let items = vec![Node(vec![Node(vec![])]), Node(vec![])];
+        assert_eq!(
+            tree_shape(&items, &children, None),
+            vec![(2, true), (1, true), (0, false), (0, false)]
+        );
+
+        // Adding a child to any node changes the shape.
+        let items = vec![Node(vec![Node(vec![Node(vec![])])]), Node(vec![])];
+        assert_eq!(
+            tree_shape(&items, &children, None),
+            vec![(2, true), (1, true), (1, true), (0, false), (0, false)]
+        );
+    }
+}
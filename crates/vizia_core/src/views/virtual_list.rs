@@ -0,0 +1,740 @@
+use std::marker::PhantomData;
+use std::ops::Range;
+use std::rc::Rc;
+
+use morphorm::PositionType;
+use vizia_input::Code;
+
+use super::scrollview::SCROLL_SENSITIVITY;
+use crate::prelude::*;
+use crate::state::{Index, Then};
+use crate::views::Orientation;
+
+/// Events which can be sent to a [`VirtualList`].
+pub enum VirtualListEvent {
+    /// Scrolls the list so that the row at the given index is visible.
+    ScrollTo(usize),
+    /// Selects the row at the given index and scrolls it into view.
+    Select(usize),
+}
+
+// Events which are sent to a view with virtual rows by its rows and its scrollbar.
+pub(crate) enum VirtualRowEvent {
+    // A row in a slot was pressed.
+    Press(usize),
+    // A row in a slot was laid out with a height in physical pixels.
+    Measure(usize, f32),
+    // The scrollbar was moved to a position between 0 and 1.
+    Scroll(f32),
+}
+
+/// A view for displaying a large list of items from a binding to a `Vec<T>`, which only builds
+/// the rows which are visible.
+///
+/// The rows are built into a fixed number of slots, enough to fill the height of the list, and a
+/// slot is rebuilt with the item of another row when its row is scrolled out of view. The list
+/// must be given a height which does not depend on its content, such as `Stretch(1.0)`.
+///
+/// The rows can be selected by pressing them or with the arrow, page up, page down, home and end
+/// keys while the list has keyboard focus. The selected row has the `checked` pseudo-class.
+///
+/// # Example
+/// ```
+/// # use vizia_core::prelude::*;
+/// # let cx = &mut Context::default();
+/// # #[derive(Lens)]
+/// # pub struct AppData {
+/// #     items: Vec<u32>,
+/// # }
+/// # impl Model for AppData {}
+/// # AppData { items: (0..200_000).collect() }.build(cx);
+/// VirtualList::new(cx, AppData::items, 24.0, |cx, _, item| {
+///     Label::new(cx, item);
+/// })
+/// .on_select(|_, index| println!("Selected row {}", index));
+/// ```
+#[derive(Lens)]
+pub struct VirtualList<L: Lens<Target = Vec<T>>, T: 'static> {
+    p: PhantomData<L>,
+    #[lens(ignore)]
+    virtual_rows: VirtualRows,
+    selected: Option<usize>,
+    on_select: Option<Box<dyn Fn(&mut EventContext, usize)>>,
+}
+
+impl<L: 'static + Lens<Target = Vec<T>>, T: Clone> VirtualList<L, T> {
+    /// Creates a new virtual list with rows of a fixed height in logical pixels, with a binding
+    /// to the given lens and a template for constructing the list items.
+    pub fn new<F>(cx: &mut Context, lens: L, row_height: f32, item: F) -> Handle<Self>
+    where
+        F: 'static + Fn(&mut Context, usize, Then<L, Index<Vec<T>, T>>),
+        <L as Lens>::Source: Model,
+    {
+        Self::build_list(cx, lens, row_height, false, item)
+    }
+
+    /// Creates a new virtual list with rows which are sized to their content, with a binding to
+    /// the given lens and a template for constructing the list items.
+    ///
+    /// Rows which have not been displayed yet are assumed to have the estimated height, in
+    /// logical pixels, and are measured once they have been laid out.
+    pub fn new_measured<F>(
+        cx: &mut Context,
+        lens: L,
+        estimated_row_height: f32,
+        item: F,
+    ) -> Handle<Self>
+    where
+        F: 'static + Fn(&mut Context, usize, Then<L, Index<Vec<T>, T>>),
+        <L as Lens>::Source: Model,
+    {
+        Self::build_list(cx, lens, estimated_row_height, true, item)
+    }
+
+    fn build_list<F>(
+        cx: &mut Context,
+        lens: L,
+        row_height: f32,
+        measured: bool,
+        item: F,
+    ) -> Handle<Self>
+    where
+        F: 'static + Fn(&mut Context, usize, Then<L, Index<Vec<T>, T>>),
+        <L as Lens>::Source: Model,
+    {
+        let item = Rc::new(item);
+
+        Self {
+            p: PhantomData::default(),
+            virtual_rows: VirtualRows::new(row_height),
+            selected: None,
+            on_select: None,
+        }
+        .build(cx, move |cx| {
+            let list = cx.current();
+            let rows = RowsLens::new(|list: &Self| &list.virtual_rows);
+
+            // Changing the number of items rebuilds the slots, but scrolling only rebuilds the
+            // slots which display a different row.
+            Binding::new(cx, lens.clone().map(|items| items.len()), move |cx, len| {
+                let len = len.get_fallible(cx).unwrap_or_default();
+                if let Some(virtual_list) =
+                    cx.views.get_mut(&list).and_then(|view| view.downcast_mut::<Self>())
+                {
+                    virtual_list.virtual_rows.resize(len);
+                }
+
+                let lens = lens.clone();
+                let item = item.clone();
+                build_rows(cx, rows, move |cx, slot| {
+                    let index = rows.map(move |rows| rows.slot_row(slot));
+
+                    let row_index = index.clone();
+                    let lens = lens.clone();
+                    let item = item.clone();
+                    let row = VStack::new(cx, move |cx| {
+                        Binding::new(cx, row_index, move |cx, index| {
+                            if let Some(index) = index.get(cx) {
+                                (item)(cx, index, lens.clone().index(index));
+                            }
+                        });
+                    })
+                    .role(Role::ListItem)
+                    .height(if measured { Auto } else { Pixels(row_height) })
+                    .checked(
+                        (Self::selected, index)
+                            .map(|(selected, index)| index.is_some() && selected == index),
+                    );
+
+                    if measured {
+                        row.on_geo_changed(move |cx, _| {
+                            let height = cx.cache().get_height(cx.current());
+                            cx.emit(VirtualRowEvent::Measure(slot, height));
+                        })
+                    } else {
+                        row
+                    }
+                })
+                .class("virtual_content");
+            });
+
+            build_scrollbar(cx, rows);
+        })
+        .role(Role::List)
+        .navigable(true)
+    }
+}
+
+impl<L: Lens<Target = Vec<T>>, T> VirtualList<L, T> {
+    fn select(&mut self, cx: &mut EventContext, index: usize) {
+        if index >= self.virtual_rows.len() {
+            return;
+        }
+
+        self.selected = Some(index);
+        self.virtual_rows.scroll_to_row(index);
+
+        if let Some(callback) = &self.on_select {
+            (callback)(cx, index);
+        }
+    }
+}
+
+impl<L: 'static + Lens<Target = Vec<T>>, T> View for VirtualList<L, T> {
+    fn element(&self) -> Option<&'static str> {
+        Some("virtuallist")
+    }
+
+    fn event(&mut self, cx: &mut EventContext, event: &mut Event) {
+        event.map(|virtual_list_event, meta| {
+            match virtual_list_event {
+                VirtualListEvent::ScrollTo(index) => self.virtual_rows.scroll_to_row(*index),
+                VirtualListEvent::Select(index) => self.select(cx, *index),
+            }
+
+            meta.consume();
+        });
+
+        event.map(|row_event, meta| {
+            match row_event {
+                VirtualRowEvent::Press(slot) => {
+                    if let Some(index) = self.virtual_rows.slot_row(*slot) {
+                        cx.focus_with_visibility(false);
+                        self.select(cx, index);
+                    }
+                }
+
+                VirtualRowEvent::Measure(slot, height) => {
+                    if let Some(index) = self.virtual_rows.slot_row(*slot) {
+                        let height = cx.style.physical_to_logical(*height);
+                        self.virtual_rows.set_height(index, height);
+                    }
+                }
+
+                VirtualRowEvent::Scroll(value) => self.virtual_rows.scroll_to_ratio(*value),
+            }
+
+            meta.consume();
+        });
+
+        event.map(|window_event, meta| match window_event {
+            WindowEvent::GeometryChanged(_) if meta.target == cx.current() => {
+                let height = cx.style.physical_to_logical(cx.cache.get_height(cx.current()));
+                self.virtual_rows.set_viewport(height);
+            }
+
+            WindowEvent::MouseScroll(_, y) => {
+                self.virtual_rows.scroll_by(-*y * SCROLL_SENSITIVITY);
+                meta.consume();
+            }
+
+            WindowEvent::KeyDown(code, _) => {
+                let len = self.virtual_rows.len();
+                if len == 0 {
+                    return;
+                }
+
+                let index = match code {
+                    Code::ArrowDown => self.selected.map_or(0, |index| (index + 1).min(len - 1)),
+                    Code::ArrowUp => self.selected.map_or(0, |index| index.saturating_sub(1)),
+                    Code::Home => 0,
+                    Code::End => len - 1,
+                    Code::PageDown => {
+                        self.virtual_rows.page_down(self.selected.unwrap_or_default())
+                    }
+                    Code::PageUp => self.virtual_rows.page_up(self.selected.unwrap_or_default()),
+                    _ => return,
+                };
+
+                self.select(cx, index);
+                meta.consume();
+            }
+
+            _ => {}
+        });
+    }
+}
+
+impl<L: Lens<Target = Vec<T>>, T> Handle<'_, VirtualList<L, T>> {
+    /// Sets the callback which is called when a row is selected, with the index of the row.
+    pub fn on_select<F>(self, callback: F) -> Self
+    where
+        F: 'static + Fn(&mut EventContext, usize),
+    {
+        self.modify(|virtual_list| virtual_list.on_select = Some(Box::new(callback)))
+    }
+}
+
+// Returns the index of the visible row displayed in a slot. Each row is displayed in the slot
+// given by its index modulo the number of slots, so that scrolling by a row only changes the row
+// of a single slot.
+pub(crate) fn slot_index(visible: Range<usize>, slot: usize, num_slots: usize) -> Option<usize> {
+    if slot >= num_slots {
+        return None;
+    }
+
+    let index = visible.start + (slot + num_slots - visible.start % num_slots) % num_slots;
+    if index < visible.end {
+        Some(index)
+    } else {
+        None
+    }
+}
+
+// The heights of the rows of a virtual list in logical pixels. The heights of rows which have not
+// been measured are estimated.
+#[derive(Debug, Clone)]
+struct RowHeights {
+    estimate: f32,
+    heights: Vec<f32>,
+    // A Fenwick tree of the heights, so that the offset of a row and the row at an offset can be
+    // found without summing the heights of all of the rows before it.
+    sums: Vec<f32>,
+}
+
+impl RowHeights {
+    fn new(estimate: f32) -> Self {
+        Self { estimate, heights: Vec::new(), sums: vec![0.0] }
+    }
+
+    fn len(&self) -> usize {
+        self.heights.len()
+    }
+
+    // Sets the number of rows, keeping the heights of the existing rows.
+    fn resize(&mut self, len: usize) {
+        self.heights.resize(len, self.estimate);
+
+        self.sums = std::iter::once(0.0).chain(self.heights.iter().copied()).collect();
+        for i in 1..self.sums.len() {
+            let parent = i + (i & i.wrapping_neg());
+            if parent < self.sums.len() {
+                self.sums[parent] += self.sums[i];
+            }
+        }
+    }
+
+    fn height(&self, index: usize) -> f32 {
+        self.heights.get(index).copied().unwrap_or_default()
+    }
+
+    // Sets the measured height of a row. Returns true if the height changed.
+    fn set(&mut self, index: usize, height: f32) -> bool {
+        let delta = match self.heights.get_mut(index) {
+            Some(old) if *old != height => {
+                let delta = height - *old;
+                *old = height;
+                delta
+            }
+            _ => return false,
+        };
+
+        let mut i = index + 1;
+        while i < self.sums.len() {
+            self.sums[i] += delta;
+            i += i & i.wrapping_neg();
+        }
+
+        true
+    }
+
+    // Returns the sum of the heights of the rows before the row at an index.
+    fn offset(&self, index: usize) -> f32 {
+        let mut i = index.min(self.len());
+        let mut offset = 0.0;
+        while i > 0 {
+            offset += self.sums[i];
+            i -= i & i.wrapping_neg();
+        }
+
+        offset
+    }
+
+    fn total(&self) -> f32 {
+        self.offset(self.len())
+    }
+
+    // Returns the index of the row at an offset, clamped to the rows of the list.
+    fn index_at(&self, offset: f32) -> usize {
+        let mut index = 0;
+        let mut remaining = offset;
+        let mut step = self.sums.len().next_power_of_two() / 2;
+        while step > 0 {
+            let next = index + step;
+            if next < self.sums.len() && self.sums[next] <= remaining {
+                index = next;
+                remaining -= self.sums[next];
+            }
+
+            step /= 2;
+        }
+
+        index.min(self.len().saturating_sub(1))
+    }
+}
+
+// The rows of a view which only builds the rows which are visible, into slots which are
+// positioned at the rows they display. Offsets and heights are in logical pixels.
+#[derive(Debug, Clone)]
+pub(crate) struct VirtualRows {
+    heights: RowHeights,
+    // The scroll offset, the height of the view and the height of all of the rows.
+    scroll_y: f32,
+    viewport: f32,
+    total: f32,
+    visible: Range<usize>,
+    num_slots: usize,
+}
+
+impl VirtualRows {
+    // Creates rows with a height which is used for the rows which have not been measured.
+    pub(crate) fn new(row_height: f32) -> Self {
+        Self {
+            heights: RowHeights::new(row_height),
+            scroll_y: 0.0,
+            viewport: 0.0,
+            total: 0.0,
+            visible: 0..0,
+            num_slots: 0,
+        }
+    }
+
+    pub(crate) fn len(&self) -> usize {
+        self.heights.len()
+    }
+
+    // Sets the number of rows, keeping the heights of the existing rows.
+    pub(crate) fn resize(&mut self, len: usize) {
+        self.heights.resize(len);
+        self.update_visible();
+    }
+
+    // Sets the measured height of a row.
+    pub(crate) fn set_height(&mut self, index: usize, height: f32) {
+        if self.heights.set(index, height) {
+            self.update_visible();
+        }
+    }
+
+    pub(crate) fn set_viewport(&mut self, viewport: f32) {
+        self.viewport = viewport;
+        self.update_visible();
+    }
+
+    pub(crate) fn scroll_by(&mut self, delta: f32) {
+        self.scroll_y += delta;
+        self.update_visible();
+    }
+
+    // Scrolls to a position between 0 and 1, such as the position of a scrollbar.
+    pub(crate) fn scroll_to_ratio(&mut self, ratio: f32) {
+        self.scroll_y = ratio * self.max_scroll();
+        self.update_visible();
+    }
+
+    // Scrolls by the least amount which makes a row visible.
+    pub(crate) fn scroll_to_row(&mut self, index: usize) {
+        if index >= self.len() {
+            return;
+        }
+
+        let top = self.heights.offset(index);
+        let bottom = top + self.heights.height(index);
+        if top < self.scroll_y {
+            self.scroll_y = top;
+        } else if bottom > self.scroll_y + self.viewport {
+            self.scroll_y = bottom - self.viewport;
+        }
+
+        self.update_visible();
+    }
+
+    // Returns the row displayed in a slot.
+    pub(crate) fn slot_row(&self, slot: usize) -> Option<usize> {
+        slot_index(self.visible.clone(), slot, self.num_slots)
+    }
+
+    fn slot_top(&self, slot: usize) -> f32 {
+        self.slot_row(slot).map_or(0.0, |index| self.heights.offset(index))
+    }
+
+    // Returns the row which is a page below a row.
+    pub(crate) fn page_down(&self, index: usize) -> usize {
+        self.heights.index_at(self.heights.offset(index) + self.viewport)
+    }
+
+    // Returns the row which is a page above a row.
+    pub(crate) fn page_up(&self, index: usize) -> usize {
+        self.heights.index_at((self.heights.offset(index) - self.viewport).max(0.0))
+    }
+
+    fn max_scroll(&self) -> f32 {
+        (self.total - self.viewport).max(0.0)
+    }
+
+    fn scroll_ratio(&self) -> f32 {
+        let max_scroll = self.max_scroll();
+        if max_scroll > 0.0 {
+            self.scroll_y / max_scroll
+        } else {
+            0.0
+        }
+    }
+
+    fn viewport_ratio(&self) -> f32 {
+        if self.total > 0.0 {
+            (self.viewport / self.total).min(1.0)
+        } else {
+            1.0
+        }
+    }
+
+    // Determines the visible rows from the scroll offset and the height of the view.
+    fn update_visible(&mut self) {
+        self.total = self.heights.total();
+        self.scroll_y = self.scroll_y.clamp(0.0, self.max_scroll());
+
+        let len = self.len();
+        self.visible = if len == 0 || self.viewport <= 0.0 {
+            0..0
+        } else {
+            let start = self.heights.index_at(self.scroll_y);
+            let end = self.heights.index_at(self.scroll_y + self.viewport) + 1;
+            start..end.min(len)
+        };
+
+        // Slots are only ever added, so that a view which shrinks does not rebuild its rows.
+        self.num_slots = self.num_slots.max(self.visible.len());
+    }
+}
+
+// A lens to the virtual rows of a view, from the view.
+pub(crate) struct RowsLens<V> {
+    get: fn(&V) -> &VirtualRows,
+}
+
+impl<V> RowsLens<V> {
+    pub(crate) fn new(get: fn(&V) -> &VirtualRows) -> Self {
+        Self { get }
+    }
+}
+
+impl<V> Clone for RowsLens<V> {
+    fn clone(&self) -> Self {
+        Self { get: self.get }
+    }
+}
+
+impl<V> Copy for RowsLens<V> {}
+
+impl<V: 'static> Lens for RowsLens<V> {
+    type Source = V;
+    type Target = VirtualRows;
+
+    fn view<O, F: FnOnce(Option<&Self::Target>) -> O>(&self, source: &Self::Source, map: F) -> O {
+        map(Some((self.get)(source)))
+    }
+}
+
+// Builds the content of a view with virtual rows, which is moved by the scroll offset, with a
+// slot for each of the visible rows. The row of each slot is built by a function which is given
+// the slot, and sends `VirtualRowEvent::Press` with the slot when it is pressed.
+pub(crate) fn build_rows<V, R, F>(cx: &mut Context, rows: RowsLens<V>, row: F) -> Handle<VStack>
+where
+    V: 'static,
+    R: View,
+    F: 'static + Fn(&mut Context, usize) -> Handle<R>,
+{
+    VStack::new(cx, move |cx| {
+        Binding::new(cx, rows.map(|rows| rows.num_slots), move |cx, num_slots| {
+            for slot in 0..num_slots.get(cx) {
+                (row)(cx, slot)
+                    .class("row")
+                    .position_type(PositionType::SelfDirected)
+                    .top(rows.map(move |rows| Pixels(rows.slot_top(slot))))
+                    .display(rows.map(move |rows| rows.slot_row(slot).is_some()))
+                    .on_press(move |cx| cx.emit(VirtualRowEvent::Press(slot)));
+            }
+        });
+    })
+    .height(rows.map(|rows| Pixels(rows.total)))
+    .top(rows.map(|rows| Pixels(-rows.scroll_y)))
+}
+
+// Builds the vertical scrollbar of a view with virtual rows, which sends
+// `VirtualRowEvent::Scroll`.
+pub(crate) fn build_scrollbar<V: 'static>(cx: &mut Context, rows: RowsLens<V>) {
+    Scrollbar::new(
+        cx,
+        rows.map(|rows| rows.scroll_ratio()),
+        rows.map(|rows| rows.viewport_ratio()),
+        Orientation::Vertical,
+        |cx, value| cx.emit(VirtualRowEvent::Scroll(value)),
+    )
+    .position_type(PositionType::SelfDirected);
+}
+
+// The selected rows of a view, which are identified by a key which follows a row when the rows
+// change, such as the item of a table row or the path of a tree node.
+#[derive(Debug, Clone)]
+pub(crate) struct Selection<K> {
+    pub(crate) mode: SelectionMode,
+    pub(crate) selected: Vec<K>,
+    // The key from which a range of rows is selected, and the key which is moved by the keyboard.
+    pub(crate) anchor: Option<K>,
+    pub(crate) cursor: Option<K>,
+}
+
+impl<K: Clone + PartialEq> Selection<K> {
+    pub(crate) fn new() -> Self {
+        Self { mode: SelectionMode::Single, selected: Vec::new(), anchor: None, cursor: None }
+    }
+
+    // Selects a row, extending the selection from the row of the anchor when `extend` is true, or
+    // adding the row to or removing it from the selection when `toggle` is true. Returns false if
+    // the row cannot be selected.
+    pub(crate) fn select<R>(
+        &mut self,
+        rows: &[R],
+        key: impl Fn(&R) -> &K,
+        row: usize,
+        extend: bool,
+        toggle: bool,
+    ) -> bool {
+        if row >= rows.len() || self.mode == SelectionMode::None {
+            return false;
+        }
+
+        let selected = key(&rows[row]).clone();
+        let multiple = self.mode == SelectionMode::Multiple;
+        if multiple && extend {
+            let anchor = self
+                .anchor
+                .as_ref()
+                .and_then(|anchor| rows.iter().position(|row| key(row) == anchor))
+                .unwrap_or(row);
+            self.selected = rows[anchor.min(row)..=anchor.max(row)]
+                .iter()
+                .map(|row| key(row).clone())
+                .collect();
+        } else if multiple && toggle {
+            if let Some(position) = self.selected.iter().position(|item| *item == selected) {
+                self.selected.remove(position);
+            } else {
+                self.selected.push(selected.clone());
+            }
+
+            self.anchor = Some(selected.clone());
+        } else {
+            self.selected = vec![selected.clone()];
+            self.anchor = Some(selected.clone());
+        }
+
+        self.cursor = Some(selected);
+        true
+    }
+
+    pub(crate) fn select_all<R>(&mut self, rows: &[R], key: impl Fn(&R) -> &K) {
+        self.selected = rows.iter().map(|row| key(row).clone()).collect();
+    }
+
+    pub(crate) fn clear(&mut self) {
+        self.selected.clear();
+        self.anchor = None;
+    }
+
+    // Removes the keys of the rows which no longer exist.
+    pub(crate) fn retain(&mut self, exists: impl Fn(&K) -> bool) {
+        self.selected.retain(&exists);
+        self.anchor = self.anchor.take().filter(&exists);
+        self.cursor = self.cursor.take().filter(&exists);
+    }
+
+    // Returns the row of the cursor.
+    pub(crate) fn cursor_row<R>(&self, rows: &[R], key: impl Fn(&R) -> &K) -> Option<usize> {
+        let cursor = self.cursor.as_ref()?;
+        rows.iter().position(|row| key(row) == cursor)
+    }
+
+    pub(crate) fn is_selected(&self, key: &K) -> bool {
+        self.selected.contains(key)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_row_heights() {
+        let mut heights = RowHeights::new(10.0);
+        heights.resize(5);
+        assert_eq!(heights.offset(3), 30.0);
+        assert_eq!(heights.total(), 50.0);
+        assert_eq!(heights.index_at(0.0), 0);
+        assert_eq!(heights.index_at(25.0), 2);
+        assert_eq!(heights.index_at(30.0), 3);
+        assert_eq!(heights.index_at(1000.0), 4);
+
+        assert!(heights.set(1, 30.0));
+        assert!(!heights.set(1, 30.0));
+        assert_eq!(heights.offset(2), 40.0);
+        assert_eq!(heights.total(), 70.0);
+        assert_eq!(heights.index_at(39.0), 1);
+        assert_eq!(heights.index_at(40.0), 2);
+
+        heights.resize(7);
+        assert_eq!(heights.offset(2), 40.0);
+        assert_eq!(heights.total(), 90.0);
+
+        heights.resize(0);
+        assert_eq!(heights.total(), 0.0);
+        assert_eq!(heights.index_at(10.0), 0);
+    }
+
+    #[test]
+    fn test_slot_index() {
+        assert_eq!(slot_index(0..3, 0, 4), Some(0));
+        assert_eq!(slot_index(0..3, 2, 4), Some(2));
+        assert_eq!(slot_index(0..3, 3, 4), None);
+
+        // Scrolling by a row only changes the row of one slot.
+        let before = (0..4).map(|slot| slot_index(5..9, slot, 4)).collect::<Vec<_>>();
+        let after = (0..4).map(|slot| slot_index(6..10, slot, 4)).collect::<Vec<_>>();
+        assert_eq!(before, vec![Some(8), Some(5), Some(6), Some(7)]);
+        assert_eq!(after, vec![Some(8), Some(9), Some(6), Some(7)]);
+    }
+
+    #[test]
+    fn test_selection() {
+        fn key(item: &usize) -> &usize {
+            item
+        }
+
+        let rows = [3, 1, 2, 0];
+        let mut selection = Selection::new();
+        assert!(selection.select(&rows, key, 1, true, false));
+        assert_eq!(selection.selected, vec![1]);
+
+        // Multiple rows are only selected in the multiple selection mode.
+        selection.mode = SelectionMode::Multiple;
+        assert!(selection.select(&rows, key, 3, true, false));
+        assert_eq!(selection.selected, vec![1, 2, 0]);
+        assert!(selection.select(&rows, key, 2, false, true));
+        assert_eq!(selection.selected, vec![1, 0]);
+        assert_eq!(selection.cursor_row(&rows, key), Some(2));
+
+        // The anchor and the cursor follow their keys when the rows change.
+        let rows = [2, 0, 1, 3];
+        assert!(selection.select(&rows, key, 3, true, false));
+        assert_eq!(selection.selected, vec![2, 0, 1, 3]);
+        assert_eq!(selection.anchor, Some(2));
+
+        selection.retain(|item| *item < 2);
+        assert_eq!(selection.selected, vec![0, 1]);
+        assert_eq!(selection.anchor, None);
+
+        selection.mode = SelectionMode::None;
+        assert!(!selection.select(&rows, key, 0, false, false));
+    }
+}
@@ -0,0 +1,308 @@
+use morphorm::PositionType;
+use vizia_window::Position;
+
+use crate::prelude::*;
+use crate::window::WindowState;
+
+/// A view which displays its content in an additional window.
+///
+/// The content of the window is part of the same tree as the rest of the application, so it can
+/// bind to the models of its ancestors. The window has its own canvas, size, scale factor and
+/// keyboard focus, and receives the window events emitted by its content, such as
+/// [`WindowEvent::SetTitle`]. The window is opened at the end of the frame in which the view is
+/// built and is closed when the view is removed.
+///
+/// When the user closes the window, or its content emits [`WindowEvent::WindowClose`], the
+/// [`on_close`](Handle::on_close) callback is called and the view is removed. The callback should
+/// update the state which caused the view to be built so that it is not built again.
+///
+/// Additional windows are only supported by the winit backend. Images are only loaded into the
+/// canvas of the main window and are not drawn in additional windows yet.
+///
+/// # Example
+/// ```
+/// # use vizia_core::prelude::*;
+/// # let cx = &mut Context::default();
+/// # #[derive(Lens)]
+/// # pub struct AppData {
+/// #     show_settings: bool,
+/// # }
+/// # impl Model for AppData {}
+/// # pub enum AppEvent { HideSettings }
+/// # AppData { show_settings: true }.build(cx);
+/// Binding::new(cx, AppData::show_settings, |cx, show| {
+///     if show.get(cx) {
+///         Window::new(cx, |cx| {
+///             Label::new(cx, "Settings");
+///         })
+///         .title("Settings")
+///         .inner_size((300, 200))
+///         .on_close(|cx| cx.emit(AppEvent::HideSettings));
+///     }
+/// });
+/// ```
+pub struct Window {
+    pub(crate) requests: Vec<WindowEvent>,
+    on_close: Option<Box<dyn Fn(&mut EventContext)>>,
+}
+
+impl Window {
+    /// Creates a new window which displays the content built by the provided closure.
+    pub fn new<F>(cx: &mut Context, content: F) -> Handle<Self>
+    where
+        F: FnOnce(&mut Context),
+    {
+        let description = WindowDescription::new();
+        let (width, height) = (description.inner_size.width, description.inner_size.height);
+
+        Self { requests: Vec::new(), on_close: None }
+            .build(cx, |cx| {
+                let entity = cx.current();
+                let scale_factor = cx.style.dpi_factor;
+                cx.windows.insert(entity, WindowState::new(Some(description), scale_factor));
+
+                (content)(cx);
+            })
+            .role(Role::Window)
+            .position_type(PositionType::SelfDirected)
+            .width(Pixels(width as f32))
+            .height(Pixels(height as f32))
+            .lock_focus_to_within()
+    }
+}
+
+impl<'a> Handle<'a, Window> {
+    /// Sets the callback which is called when the window is closed by the user or by its content.
+    pub fn on_close<F>(self, callback: F) -> Self
+    where
+        F: 'static + Fn(&mut EventContext),
+    {
+        self.modify(|window| window.on_close = Some(Box::new(callback)))
+    }
+}
+
+// Changes the description of a window which has not been created yet, or otherwise asks the
+// backend to change the window.
+fn update_window(
+    cx: &mut Context,
+    window: Entity,
+    request: WindowEvent,
+    update: impl FnOnce(&mut WindowDescription),
+) {
+    match cx.windows.get_mut(&window).and_then(|state| state.description.as_mut()) {
+        Some(description) => (update)(description),
+        None => cx.emit_to(window, request),
+    }
+}
+
+impl<'a> WindowModifiers for Handle<'a, Window> {
+    fn title<T: ToString>(self, title: impl Res<T>) -> Self {
+        title.set_or_bind(self.cx, self.entity, |cx, entity, val| {
+            let title = val.to_string();
+            update_window(cx, entity, WindowEvent::SetTitle(title.clone()), |description| {
+                description.title = title
+            });
+        });
+
+        self
+    }
+
+    fn inner_size<S: Into<WindowSize>>(self, size: impl Res<S>) -> Self {
+        size.set_or_bind(self.cx, self.entity, |cx, entity, val| {
+            let size = val.into();
+            update_window(cx, entity, WindowEvent::SetSize(size), |description| {
+                description.inner_size = size
+            });
+
+            // Until the window has been created its content is sized here, so that it is laid out
+            // at the requested size. Afterwards the content is resized by the backend once the
+            // window has been resized, which may be to a different size than was requested. Both
+            // the window size and the `Pixels` lengths are in logical pixels.
+            if cx.windows.get(&entity).map_or(false, |state| state.description.is_some()) {
+                cx.style.width.insert(entity, Pixels(size.width as f32));
+                cx.style.height.insert(entity, Pixels(size.height as f32));
+                cx.style.needs_relayout();
+            }
+        });
+
+        self
+    }
+
+    fn min_inner_size<S: Into<WindowSize>>(self, size: impl Res<Option<S>>) -> Self {
+        size.set_or_bind(self.cx, self.entity, |cx, entity, val| {
+            let size = val.map(|size| size.into());
+            update_window(cx, entity, WindowEvent::SetMinSize(size), |description| {
+                description.min_inner_size = size
+            });
+        });
+
+        self
+    }
+
+    fn max_inner_size<S: Into<WindowSize>>(self, size: impl Res<Option<S>>) -> Self {
+        size.set_or_bind(self.cx, self.entity, |cx, entity, val| {
+            let size = val.map(|size| size.into());
+            update_window(cx, entity, WindowEvent::SetMaxSize(size), |description| {
+                description.max_inner_size = size
+            });
+        });
+
+        self
+    }
+
+    fn position<P: Into<Position>>(self, position: impl Res<P>) -> Self {
+        position.set_or_bind(self.cx, self.entity, |cx, entity, val| {
+            let position = val.into();
+            update_window(cx, entity, WindowEvent::SetPosition(position), |description| {
+                description.position = Some(position)
+            });
+        });
+
+        self
+    }
+
+    fn resizable(self, flag: impl Res<bool>) -> Self {
+        flag.set_or_bind(self.cx, self.entity, |cx, entity, val| {
+            update_window(cx, entity, WindowEvent::SetResizable(val), |description| {
+                description.resizable = val
+            });
+        });
+
+        self
+    }
+
+    fn minimized(self, flag: impl Res<bool>) -> Self {
+        flag.set_or_bind(self.cx, self.entity, |cx, entity, val| {
+            update_window(cx, entity, WindowEvent::SetMinimized(val), |description| {
+                description.minimized = val
+            });
+        });
+
+        self
+    }
+
+    fn maximized(self, flag: impl Res<bool>) -> Self {
+        flag.set_or_bind(self.cx, self.entity, |cx, entity, val| {
+            update_window(cx, entity, WindowEvent::SetMaximized(val), |description| {
+                description.maximized = val
+            });
+        });
+
+        self
+    }
+
+    fn visible(self, flag: impl Res<bool>) -> Self {
+        flag.set_or_bind(self.cx, self.entity, |cx, entity, val| {
+            update_window(cx, entity, WindowEvent::SetVisible(val), |description| {
+                description.visible = val
+            });
+        });
+
+        self
+    }
+
+    fn transparent(self, flag: bool) -> Self {
+        if let Some(description) =
+            self.cx.windows.get_mut(&self.entity).and_then(|state| state.description.as_mut())
+        {
+            description.transparent = flag;
+        }
+
+        self
+    }
+
+    fn decorations(self, flag: impl Res<bool>) -> Self {
+        flag.set_or_bind(self.cx, self.entity, |cx, entity, val| {
+            update_window(cx, entity, WindowEvent::SetDecorations(val), |description| {
+                description.decorations = val
+            });
+        });
+
+        self
+    }
+
+    fn always_on_top(self, flag: impl Res<bool>) -> Self {
+        flag.set_or_bind(self.cx, self.entity, |cx, entity, val| {
+            update_window(cx, entity, WindowEvent::SetAlwaysOnTop(val), |description| {
+                description.always_on_top = val
+            });
+        });
+
+        self
+    }
+
+    fn vsync(self, flag: bool) -> Self {
+        if let Some(description) =
+            self.cx.windows.get_mut(&self.entity).and_then(|state| state.description.as_mut())
+        {
+            description.vsync = flag;
+        }
+
+        self
+    }
+
+    fn icon(self, image: Vec<u8>, width: u32, height: u32) -> Self {
+        if let Some(description) =
+            self.cx.windows.get_mut(&self.entity).and_then(|state| state.description.as_mut())
+        {
+            description.icon = Some(image);
+            description.icon_width = width;
+            description.icon_height = height;
+        }
+
+        self
+    }
+
+    #[cfg(target_arch = "wasm32")]
+    fn canvas(self, _canvas: &str) -> Self {
+        self
+    }
+}
+
+impl View for Window {
+    fn element(&self) -> Option<&'static str> {
+        Some("window")
+    }
+
+    fn event(&mut self, cx: &mut EventContext, event: &mut Event) {
+        // Requests to change the window are kept for the backend instead of propagating to the
+        // main window.
+        let mut is_request = false;
+        event.map(|window_event, _| {
+            is_request = matches!(
+                window_event,
+                WindowEvent::WindowClose
+                    | WindowEvent::SetCursor(_)
+                    | WindowEvent::GrabCursor(_)
+                    | WindowEvent::SetCursorPosition(_, _)
+                    | WindowEvent::SetImeAllowed(_)
+                    | WindowEvent::SetImePosition(_, _)
+                    | WindowEvent::SetTitle(_)
+                    | WindowEvent::SetSize(_)
+                    | WindowEvent::SetPosition(_)
+                    | WindowEvent::SetMaxSize(_)
+                    | WindowEvent::SetMinSize(_)
+                    | WindowEvent::SetResizable(_)
+                    | WindowEvent::SetMinimized(_)
+                    | WindowEvent::SetMaximized(_)
+                    | WindowEvent::SetVisible(_)
+                    | WindowEvent::SetDecorations(_)
+                    | WindowEvent::SetAlwaysOnTop(_)
+            );
+        });
+
+        if !is_request {
+            return;
+        }
+
+        if let Some(window_event) = event.take::<WindowEvent>() {
+            if let WindowEvent::WindowClose = window_event {
+                if let Some(callback) = &self.on_close {
+                    (callback)(cx);
+                }
+            }
+
+            self.requests.push(window_event);
+        }
+    }
+}
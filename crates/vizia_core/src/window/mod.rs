@@ -1,4 +1,7 @@
 mod window_modifiers;
 pub use window_modifiers::*;
 
+mod window_state;
+pub(crate) use window_state::*;
+
 pub use vizia_window;
@@ -0,0 +1,18 @@
+use crate::prelude::*;
+
+/// The state of a window which displays a subtree of the application.
+pub(crate) struct WindowState {
+    /// The properties used to create the window, until the backend has created it.
+    pub description: Option<WindowDescription>,
+    /// The scale factor of the window. Content is laid out at the scale factor of the main window
+    /// and scaled by the ratio between the two when it is drawn.
+    pub scale_factor: f64,
+    /// The entity which had keyboard focus when the window last lost focus.
+    pub focused: Entity,
+}
+
+impl WindowState {
+    pub fn new(description: Option<WindowDescription>, scale_factor: f64) -> Self {
+        Self { description, scale_factor, focused: Entity::null() }
+    }
+}
@@ -0,0 +1,17 @@
+/// The phase of a continuous gesture such as a pinch, rotation or pan.
+///
+/// This type is part of the prelude.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum GesturePhase {
+    /// The gesture has started. Gesture events with this phase may carry an initial delta.
+    Started,
+    /// The gesture has continued.
+    Changed,
+    /// The gesture has ended normally.
+    Ended,
+    /// The gesture was interrupted, for example by another gesture taking over.
+    Cancelled,
+    /// The gesture has ended but the content keeps moving due to inertia. Only emitted for pans
+    /// on trackpads which report momentum.
+    Momentum,
+}
@@ -1,10 +1,14 @@
 mod chord;
+mod gesture;
 mod modifiers;
 mod mouse;
+mod touch;
 
 pub use chord::*;
+pub use gesture::*;
 pub use modifiers::*;
 pub use mouse::*;
+pub use touch::*;
 
 pub use keyboard_types::Modifiers as KeyboardModifiers;
 pub use keyboard_types::{Code, Key, KeyState};
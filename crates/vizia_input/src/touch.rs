@@ -0,0 +1,105 @@
+use vizia_id::GenerationalId;
+
+/// A touch or pen contact with the window.
+///
+/// This type is part of the prelude.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Touch {
+    /// The id of the contact, which stays the same from the start to the end of the contact.
+    pub id: u64,
+    /// The horizontal position of the contact in physical pixels.
+    pub x: f32,
+    /// The vertical position of the contact in physical pixels.
+    pub y: f32,
+    /// The pressure of the contact between 0.0 and 1.0, if the device reports it.
+    pub pressure: Option<f32>,
+    /// The angle between a pen and the surface in radians, where 0.0 is parallel to the surface
+    /// and pi/2 is perpendicular to it, if the device reports it.
+    pub tilt: Option<f32>,
+}
+
+/// The current state of the touches in contact with the window.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TouchState<I>
+where
+    I: GenerationalId,
+{
+    /// The touches which are in contact with the window, in the order they started.
+    pub touches: Vec<Touch>,
+    /// The id of the touch which emulates the mouse. This is the first touch to start while no
+    /// other touches were in contact.
+    pub primary: Option<u64>,
+    captures: Vec<(u64, I)>,
+}
+
+impl<I> Default for TouchState<I>
+where
+    I: GenerationalId,
+{
+    fn default() -> Self {
+        TouchState { touches: Vec::new(), primary: None, captures: Vec::new() }
+    }
+}
+
+impl<I> TouchState<I>
+where
+    I: GenerationalId,
+{
+    /// Returns the touch with the given id, if it is in contact.
+    pub fn get(&self, id: u64) -> Option<&Touch> {
+        self.touches.iter().find(|touch| touch.id == id)
+    }
+
+    /// Returns the entity which captured the touch with the given id, or a null entity.
+    pub fn captured(&self, id: u64) -> I {
+        self.captures
+            .iter()
+            .find(|(touch_id, _)| *touch_id == id)
+            .map_or(I::null(), |(_, entity)| *entity)
+    }
+
+    /// Sends all future events of the touch with the given id to an entity until it ends.
+    pub fn capture(&mut self, id: u64, entity: I) {
+        self.captures.retain(|(touch_id, _)| *touch_id != id);
+        self.captures.push((id, entity));
+    }
+
+    /// Releases the touch with the given id if it was captured by an entity.
+    pub fn release(&mut self, id: u64, entity: I) {
+        self.captures.retain(|(touch_id, captured)| *touch_id != id || *captured != entity);
+    }
+
+    /// Releases every touch captured by an entity.
+    pub fn release_all(&mut self, entity: I) {
+        self.captures.retain(|(_, captured)| *captured != entity);
+    }
+
+    /// Records the start or movement of a touch. Returns true if it is the primary touch.
+    pub fn update(&mut self, touch: Touch) -> bool {
+        if let Some(existing) = self.touches.iter_mut().find(|existing| existing.id == touch.id) {
+            *existing = touch;
+        } else {
+            if self.touches.is_empty() {
+                self.primary = Some(touch.id);
+            }
+
+            self.touches.push(touch);
+        }
+
+        self.primary == Some(touch.id)
+    }
+
+    /// Records the end of a touch and releases its capture. Returns true if it was the primary
+    /// touch.
+    pub fn remove(&mut self, id: u64) -> bool {
+        self.touches.retain(|touch| touch.id != id);
+        self.captures.retain(|(touch_id, _)| *touch_id != id);
+
+        if self.primary == Some(id) {
+            self.primary = None;
+            true
+        } else {
+            false
+        }
+    }
+}
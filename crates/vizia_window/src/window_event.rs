@@ -1,6 +1,7 @@
 use crate::{CursorIcon, Position, WindowSize};
 use morphorm::GeometryChanged;
-use vizia_input::{Code, Key, MouseButton};
+use std::path::PathBuf;
+use vizia_input::{Code, GesturePhase, Key, MouseButton, Touch};
 
 /// Events generated by the application in response to OS events as well as events that can be used
 /// to set properties of the window.
@@ -30,6 +31,27 @@ pub enum WindowEvent {
     MouseMove(f32, f32),
     /// Emitted when the mouse scroll wheel is scrolled.
     MouseScroll(f32, f32),
+    /// Emitted when the content under the cursor is panned, with the horizontal and vertical
+    /// movement in physical pixels.
+    ///
+    /// Pans are recognized when the left mouse button or the primary touch moves while pressed,
+    /// and are also emitted alongside `MouseScroll` by trackpads which report precise scrolling.
+    Pan(f32, f32, GesturePhase),
+    /// Emitted when the content under the cursor is pinched, with the change in scale since the
+    /// last event. Positive values magnify and negative values shrink.
+    ///
+    /// Emitted by trackpads and recognized from two touches.
+    Magnify(f32, GesturePhase),
+    /// Emitted when the content under the cursor is rotated, with the counterclockwise rotation
+    /// in degrees since the last event.
+    ///
+    /// Emitted by trackpads and recognized from two touches.
+    Rotate(f32, GesturePhase),
+    /// Emitted when a trackpad is double tapped with two fingers to toggle zooming.
+    SmartMagnify,
+    /// Emitted when the left mouse button or the primary touch is held in place for the long press
+    /// duration. A long press prevents the `Press` event from being emitted on release.
+    LongPress,
     /// Emitted when the mouse cursor enters the bounding box of an entity.
     MouseOver,
     /// Emitted when the mouse cursor leaves the bounding box of an entity.
@@ -38,12 +60,32 @@ pub enum WindowEvent {
     MouseEnter,
     /// Emitted when the mouse cursor leaves an entity.
     MouseLeave,
+    /// Emitted when a touch or pen makes contact with the window.
+    TouchStart(Touch),
+    /// Emitted when a touch or pen in contact with the window moves.
+    TouchMove(Touch),
+    /// Emitted when a touch or pen is lifted from the window.
+    TouchEnd(Touch),
+    /// Emitted when the system cancels a touch, for example because the window lost focus.
+    TouchCancel(Touch),
+    /// Emitted when a file is dragged over the window from another application. Emitted once for
+    /// each file being dragged.
+    FileHovered(PathBuf),
+    /// Emitted when a file is dropped onto the window. Emitted once for each file being dropped.
+    FileDropped(PathBuf),
+    /// Emitted when files dragged over the window leave it without being dropped.
+    FileHoverCancelled,
     // Emitted when an entity gains keyboard focus.
     FocusIn,
     // Emitted when an entity loses keyboard focus.
     FocusOut,
     /// Emitted when a character is typed.
     CharInput(char),
+    /// Emitted when the uncommitted text of an input method editor (IME) changes, with the byte
+    /// range of the IME cursor within the text. An empty string clears the uncommitted text.
+    ImePreedit(String, Option<(usize, usize)>),
+    /// Emitted when an input method editor (IME) commits composed text.
+    ImeCommit(String),
     /// Emitted when a keyboard key is pressed.
     KeyDown(Code, Option<Key>),
     /// Emitted when a keyboard key is released.
@@ -54,6 +96,11 @@ pub enum WindowEvent {
     GrabCursor(bool),
     /// Sets the (x,y) position of the mouse cursor in window coordinates.
     SetCursorPosition(u32, u32),
+    /// Sets whether the window receives input from an input method editor (IME).
+    SetImeAllowed(bool),
+    /// Sets the (x,y) position of the input method editor (IME) candidate window in window
+    /// coordinates, usually just below the text caret.
+    SetImePosition(u32, u32),
     /// Sets the title of the window.
     SetTitle(String),
     /// Sets the size of the window.
@@ -7,6 +7,7 @@ use accesskit::{Action, NodeBuilder, TreeUpdate};
 #[cfg(not(target_arch = "wasm32"))]
 use accesskit_winit;
 use std::cell::RefCell;
+use std::collections::HashMap;
 #[cfg(not(target_arch = "wasm32"))]
 use vizia_core::accessibility::IntoNode;
 use vizia_core::cache::BoundingBox;
@@ -223,6 +224,7 @@ impl Application {
             }
         }
 
+        let main_window_id = window.id;
         let scale_factor = window.window().scale_factor() as f32;
         BackendContext::new(&mut context).add_main_window(
             &self.window_description,
@@ -254,8 +256,12 @@ impl Application {
 
         let mut cursor_moved = false;
         let mut cursor = (0.0f32, 0.0f32);
+        // Whether precise scrolling continues after the fingers were lifted from a trackpad.
+        let mut trackpad_momentum = false;
+        // The windows created for `Window` views, keyed by the entity at the root of their content.
+        let mut windows: HashMap<Entity, Window> = HashMap::new();
 
-        event_loop.run(move |event, _, control_flow| {
+        event_loop.run(move |event, event_loop_window_target, control_flow| {
             let mut cx = BackendContext::new(&mut context);
 
             match event {
@@ -301,11 +307,45 @@ impl Application {
                         cursor_moved = false;
                     }
 
+                    cx.process_timers();
+
                     // Events
                     while event_manager.flush_events(cx.0) {}
 
                     cx.process_data_updates();
 
+                    // Close, update and open the windows of `Window` views.
+                    for (entity, window) in windows.iter_mut() {
+                        for request in cx.take_window_requests(*entity) {
+                            window.apply_request(&window_request(&cx, *entity, request));
+                        }
+
+                        if window.should_close {
+                            cx.close_window(*entity);
+                        }
+                    }
+
+                    windows.retain(|entity, window| {
+                        if cx.is_window(*entity) {
+                            true
+                        } else {
+                            // The canvas must be dropped while its context is current.
+                            window.make_current();
+                            cx.remove_canvas(*entity);
+                            false
+                        }
+                    });
+
+                    for (entity, description) in cx.pending_windows() {
+                        let (window, canvas) = Window::new(event_loop_window_target, &description);
+                        window.window().set_visible(description.visible);
+
+                        let size = window.window().inner_size();
+                        let scale_factor = window.window().scale_factor();
+                        cx.add_window(entity, canvas, (size.width, size.height), scale_factor);
+                        windows.insert(entity, window);
+                    }
+
                     cx.process_style_updates();
 
                     if cx.process_animations() {
@@ -322,6 +362,10 @@ impl Application {
 
                             cx.views().insert(Entity::root(), window_event_handler);
                         }
+
+                        for window in windows.values() {
+                            window.window().request_redraw();
+                        }
                     }
 
                     cx.process_visual_updates();
@@ -337,6 +381,10 @@ impl Application {
                         if let Some(window) = window_view.downcast_ref::<Window>() {
                             cx.style().should_redraw(|| {
                                 window.window().request_redraw();
+
+                                for window in windows.values() {
+                                    window.window().request_redraw();
+                                }
                             });
                         }
 
@@ -355,6 +403,14 @@ impl Application {
                             .expect("Failed to send event");
                     }
 
+                    // Wake up when the next timer is due instead of waiting for a window event.
+                    if let Some(deadline) = cx.next_timer_deadline() {
+                        let mut control_flow = stored_control_flow.borrow_mut();
+                        if *control_flow == ControlFlow::Wait {
+                            *control_flow = ControlFlow::WaitUntil(deadline);
+                        }
+                    }
+
                     if let Some(window_event_handler) = cx.views().remove(&Entity::root()) {
                         if let Some(window) = window_event_handler.downcast_ref::<Window>() {
                             if window.should_close {
@@ -366,25 +422,59 @@ impl Application {
                     }
                 }
 
-                winit::event::Event::RedrawRequested(_) => {
+                winit::event::Event::RedrawRequested(window_id) => {
                     // Redraw here
-                    context_draw(&mut cx);
+                    if window_id == main_window_id {
+                        context_draw(&mut cx);
+                    } else if let Some((entity, window)) =
+                        windows.iter().find(|(_, window)| window.id == window_id)
+                    {
+                        window.make_current();
+                        cx.draw_window(*entity);
+                        window.swap_buffers();
+                    }
                 }
 
-                winit::event::Event::WindowEvent { window_id: _, event } => {
+                winit::event::Event::WindowEvent { window_id, event }
+                    if window_id == main_window_id
+                        || windows.values().any(|window| window.id == window_id) =>
+                {
+                    let window_entity = windows
+                        .iter()
+                        .find(|(_, window)| window.id == window_id)
+                        .map(|(entity, _)| *entity)
+                        .unwrap_or(Entity::root());
+
                     match event {
                         winit::event::WindowEvent::CloseRequested => {
-                            cx.0.emit(WindowEvent::WindowClose);
+                            cx.0.with_current(window_entity, |cx| {
+                                cx.emit(WindowEvent::WindowClose)
+                            });
                         }
 
                         winit::event::WindowEvent::Focused(is_focused) => {
-                            cx.0.window_has_focus = is_focused;
-                            #[cfg(not(target_arch = "wasm32"))]
-                            accesskit.update_if_active(|| TreeUpdate {
-                                nodes: vec![],
-                                tree: None,
-                                focus: is_focused.then_some(cx.focused().accesskit_id()),
-                            });
+                            cx.set_window_focused(window_entity, is_focused);
+
+                            if window_entity == Entity::root() {
+                                cx.0.window_has_focus = is_focused;
+                                #[cfg(not(target_arch = "wasm32"))]
+                                accesskit.update_if_active(|| TreeUpdate {
+                                    nodes: vec![],
+                                    tree: None,
+                                    focus: is_focused.then_some(cx.focused().accesskit_id()),
+                                });
+                            }
+                        }
+
+                        winit::event::WindowEvent::ScaleFactorChanged {
+                            scale_factor,
+                            new_inner_size,
+                        } if window_entity != Entity::root() => {
+                            cx.set_window_scale_factor(window_entity, scale_factor);
+                            cx.set_window_size(
+                                window_entity,
+                                (new_inner_size.width, new_inner_size.height),
+                            );
                         }
 
                         winit::event::WindowEvent::ScaleFactorChanged {
@@ -414,10 +504,15 @@ impl Application {
                             position,
                             modifiers: _,
                         } => {
+                            cx.set_cursor_window(window_entity);
+
                             if !cursor_moved {
                                 cursor_moved = true;
-                                cursor.0 = position.x as f32;
-                                cursor.1 = position.y as f32;
+                                cursor = cx.window_to_layout(
+                                    window_entity,
+                                    position.x as f32,
+                                    position.y as f32,
+                                );
                             }
                         }
 
@@ -444,10 +539,11 @@ impl Application {
                                 }
                             };
 
+                            cx.set_cursor_window(window_entity);
                             cx.emit_origin(event);
                         }
 
-                        winit::event::WindowEvent::MouseWheel { delta, phase: _, .. } => {
+                        winit::event::WindowEvent::MouseWheel { delta, phase, .. } => {
                             let out_event = match delta {
                                 winit::event::MouseScrollDelta::LineDelta(x, y) => {
                                     WindowEvent::MouseScroll(x, y)
@@ -461,6 +557,45 @@ impl Application {
                             };
 
                             cx.emit_origin(out_event);
+
+                            // Precise scrolling comes from trackpads, so it is also a pan.
+                            if let winit::event::MouseScrollDelta::PixelDelta(pos) = delta {
+                                let phase = match phase {
+                                    winit::event::TouchPhase::Started => {
+                                        trackpad_momentum = false;
+                                        GesturePhase::Started
+                                    }
+                                    winit::event::TouchPhase::Moved if trackpad_momentum => {
+                                        GesturePhase::Momentum
+                                    }
+                                    winit::event::TouchPhase::Moved => GesturePhase::Changed,
+                                    winit::event::TouchPhase::Ended => {
+                                        trackpad_momentum = true;
+                                        GesturePhase::Ended
+                                    }
+                                    winit::event::TouchPhase::Cancelled => {
+                                        trackpad_momentum = false;
+                                        GesturePhase::Cancelled
+                                    }
+                                };
+
+                                cx.emit_origin(WindowEvent::Pan(pos.x as f32, pos.y as f32, phase));
+                            }
+                        }
+
+                        winit::event::WindowEvent::TouchpadMagnify { delta, phase, .. } => {
+                            cx.emit_origin(WindowEvent::Magnify(
+                                delta as f32,
+                                gesture_phase(phase),
+                            ));
+                        }
+
+                        winit::event::WindowEvent::TouchpadRotate { delta, phase, .. } => {
+                            cx.emit_origin(WindowEvent::Rotate(delta, gesture_phase(phase)));
+                        }
+
+                        winit::event::WindowEvent::SmartMagnify { .. } => {
+                            cx.emit_origin(WindowEvent::SmartMagnify);
                         }
 
                         winit::event::WindowEvent::KeyboardInput {
@@ -494,6 +629,82 @@ impl Application {
                             cx.emit_origin(WindowEvent::CharInput(character));
                         }
 
+                        winit::event::WindowEvent::Touch(winit::event::Touch {
+                            phase,
+                            location,
+                            force,
+                            id,
+                            ..
+                        }) => {
+                            let tilt = match force {
+                                Some(winit::event::Force::Calibrated {
+                                    altitude_angle, ..
+                                }) => altitude_angle.map(|angle| angle as f32),
+                                _ => None,
+                            };
+
+                            cx.set_cursor_window(window_entity);
+                            let (x, y) = cx.window_to_layout(
+                                window_entity,
+                                location.x as f32,
+                                location.y as f32,
+                            );
+
+                            let touch = Touch {
+                                id,
+                                x,
+                                y,
+                                pressure: force.map(|force| force.normalized() as f32),
+                                tilt,
+                            };
+
+                            cx.emit_origin(match phase {
+                                winit::event::TouchPhase::Started => WindowEvent::TouchStart(touch),
+                                winit::event::TouchPhase::Moved => WindowEvent::TouchMove(touch),
+                                winit::event::TouchPhase::Ended => WindowEvent::TouchEnd(touch),
+                                winit::event::TouchPhase::Cancelled => {
+                                    WindowEvent::TouchCancel(touch)
+                                }
+                            });
+                        }
+
+                        winit::event::WindowEvent::Ime(ime) => match ime {
+                            winit::event::Ime::Preedit(text, cursor) => {
+                                cx.emit_origin(WindowEvent::ImePreedit(text, cursor));
+                            }
+
+                            winit::event::Ime::Commit(text) => {
+                                cx.emit_origin(WindowEvent::ImeCommit(text));
+                            }
+
+                            _ => {}
+                        },
+
+                        winit::event::WindowEvent::HoveredFile(path) => {
+                            cx.emit_origin(WindowEvent::FileHovered(path));
+                        }
+
+                        winit::event::WindowEvent::DroppedFile(path) => {
+                            cx.emit_origin(WindowEvent::FileDropped(path));
+                        }
+
+                        winit::event::WindowEvent::HoveredFileCancelled => {
+                            cx.emit_origin(WindowEvent::FileHoverCancelled);
+                        }
+
+                        winit::event::WindowEvent::Resized(physical_size)
+                            if window_entity != Entity::root() =>
+                        {
+                            if let Some(window) = windows.get(&window_entity) {
+                                window.resize(physical_size);
+                            }
+
+                            cx.set_window_size(
+                                window_entity,
+                                (physical_size.width, physical_size.height),
+                            );
+                        }
+
                         winit::event::WindowEvent::Resized(physical_size) => {
                             if let Some(mut window_view) = cx.views().remove(&Entity::root()) {
                                 if let Some(window) = window_view.downcast_mut::<Window>() {
@@ -684,9 +895,36 @@ impl WindowModifiers for Application {
 //     }
 // }
 
+fn gesture_phase(phase: winit::event::TouchPhase) -> GesturePhase {
+    match phase {
+        winit::event::TouchPhase::Started => GesturePhase::Started,
+        winit::event::TouchPhase::Moved => GesturePhase::Changed,
+        winit::event::TouchPhase::Ended => GesturePhase::Ended,
+        winit::event::TouchPhase::Cancelled => GesturePhase::Cancelled,
+    }
+}
+
+// Converts the coordinates of a request from the content of a `Window` view to its window.
+fn window_request(cx: &BackendContext, window: Entity, request: WindowEvent) -> WindowEvent {
+    match request {
+        WindowEvent::SetCursorPosition(x, y) => {
+            let (x, y) = cx.layout_to_window(window, x as f32, y as f32);
+            WindowEvent::SetCursorPosition(x as u32, y as u32)
+        }
+
+        WindowEvent::SetImePosition(x, y) => {
+            let (x, y) = cx.layout_to_window(window, x as f32, y as f32);
+            WindowEvent::SetImePosition(x as u32, y as u32)
+        }
+
+        request => request,
+    }
+}
+
 fn context_draw(cx: &mut BackendContext) {
     if let Some(mut window_view) = cx.views().remove(&Entity::root()) {
         if let Some(window) = window_view.downcast_mut::<Window>() {
+            window.make_current();
             cx.draw();
             window.swap_buffers();
         }
@@ -22,7 +22,7 @@ use glutin::{
 };
 
 use vizia_core::prelude::*;
-use winit::event_loop::EventLoop;
+use winit::event_loop::EventLoopWindowTarget;
 use winit::window::{CursorGrabMode, WindowBuilder};
 use winit::{dpi::*, window::WindowId};
 
@@ -39,7 +39,7 @@ pub struct Window {
 #[cfg(target_arch = "wasm32")]
 impl Window {
     pub fn new(
-        events_loop: &EventLoop<UserEvent>,
+        events_loop: &EventLoopWindowTarget<UserEvent>,
         window_description: &WindowDescription,
     ) -> (Self, Canvas<OpenGl>) {
         let window_builder = WindowBuilder::new();
@@ -96,12 +96,16 @@ impl Window {
     pub fn swap_buffers(&self) {
         // Intentional no-op
     }
+
+    pub fn make_current(&self) {
+        // Intentional no-op
+    }
 }
 
 #[cfg(not(target_arch = "wasm32"))]
 impl Window {
     pub fn new(
-        events_loop: &EventLoop<UserEvent>,
+        events_loop: &EventLoopWindowTarget<UserEvent>,
         window_description: &WindowDescription,
     ) -> (Self, Canvas<OpenGl>) {
         let window_builder = WindowBuilder::new();
@@ -213,11 +217,18 @@ impl Window {
     pub fn swap_buffers(&self) {
         self.surface.swap_buffers(&self.context).expect("Failed to swap buffers");
     }
+
+    /// Makes the OpenGL context of the window current, which is required before drawing to or
+    /// dropping its canvas when there is more than one window.
+    pub fn make_current(&self) {
+        self.context.make_current(&self.surface).expect("Failed to make context current");
+    }
 }
 
-impl View for Window {
-    fn event(&mut self, cx: &mut EventContext, event: &mut Event) {
-        event.map(|window_event, _| match window_event {
+impl Window {
+    /// Applies a request to change the window or the cursor, such as [`WindowEvent::SetTitle`].
+    pub fn apply_request(&mut self, window_event: &WindowEvent) {
+        match window_event {
             WindowEvent::GrabCursor(flag) => {
                 let grab_mode = if *flag { CursorGrabMode::Locked } else { CursorGrabMode::None };
                 self.window().set_cursor_grab(grab_mode).expect("Failed to set cursor grab");
@@ -231,6 +242,16 @@ impl View for Window {
                     .expect("Failed to set cursor position");
             }
 
+            WindowEvent::SetImeAllowed(flag) => {
+                self.window().set_ime_allowed(*flag);
+            }
+
+            WindowEvent::SetImePosition(x, y) => {
+                self.window().set_ime_position(winit::dpi::Position::Physical(
+                    PhysicalPosition::new(*x as i32, *y as i32),
+                ));
+            }
+
             WindowEvent::SetCursor(cursor) => {
                 if let Some(icon) = cursor_icon_to_cursor_icon(*cursor) {
                     self.window().set_cursor_visible(true);
@@ -282,15 +303,23 @@ impl View for Window {
                 self.window().set_decorations(*flag);
             }
 
-            WindowEvent::ReloadStyles => {
-                cx.reload_styles().unwrap();
-            }
-
             WindowEvent::WindowClose => {
                 self.should_close = true;
             }
 
             _ => {}
+        }
+    }
+}
+
+impl View for Window {
+    fn event(&mut self, cx: &mut EventContext, event: &mut Event) {
+        event.map(|window_event, _| match window_event {
+            WindowEvent::ReloadStyles => {
+                cx.reload_styles().unwrap();
+            }
+
+            _ => self.apply_request(window_event),
         })
     }
 }
@@ -11,6 +11,11 @@ fn main() {
                 println!("You right clicked on a label!")
             }
         });
+        Label::new(cx, "Drop a file on me!")
+            .on_file_drop(|_, path| println!("You dropped {} on a label!", path.display()));
+        Label::new(cx, "Hold or pinch me!")
+            .on_long_press(|_| println!("You long pressed a label!"))
+            .on_pinch(|_, delta, _| println!("You zoomed a label by {}!", 1.0 + delta));
         CustomView::new(cx);
     })
     .title("Action Modifiers")
@@ -0,0 +1,87 @@
+use vizia::prelude::*;
+
+fn main() {
+    Application::new(|cx| {
+        AppData { status: String::from("No file deleted") }.build(cx);
+
+        VStack::new(cx, |cx| {
+            Button::new(cx, |cx| cx.emit(AppEvent::Delete), |cx| Label::new(cx, "Delete file"))
+                .width(Pixels(150.0));
+            Label::new(cx, AppData::status);
+        })
+        .space(Pixels(50.0))
+        .row_between(Pixels(20.0));
+    })
+    .title("Dialog")
+    .run();
+}
+
+// Builds the buttons of a dialog which closes with a boolean result.
+fn dialog_buttons(cx: &mut Context, confirm: &str) {
+    HStack::new(cx, |cx| {
+        Button::new(cx, |cx| cx.emit(DialogEvent::Close(true)), |cx| Label::new(cx, confirm))
+            .class("accent");
+        Button::new(cx, |cx| cx.emit(DialogEvent::<bool>::Cancel), |cx| Label::new(cx, "Cancel"));
+    })
+    .height(Auto)
+    .col_between(Pixels(10.0));
+}
+
+#[derive(Debug)]
+pub enum AppEvent {
+    Delete,
+    SetStatus(String),
+}
+
+#[derive(Lens)]
+pub struct AppData {
+    status: String,
+}
+
+impl Model for AppData {
+    fn event(&mut self, cx: &mut EventContext, event: &mut Event) {
+        event.map(|app_event, _| match app_event {
+            AppEvent::Delete => {
+                cx.open_dialog(
+                    |cx| {
+                        Dialog::title(cx, "Delete file?");
+                        Label::new(cx, "The file will be moved to the trash.");
+                        Button::new(
+                            cx,
+                            |cx| {
+                                // Opens a second dialog above the first, which closes the first
+                                // dialog when it is confirmed.
+                                cx.open_dialog(
+                                    |cx| {
+                                        Dialog::title(cx, "Delete permanently?");
+                                        Label::new(cx, "This cannot be undone.");
+                                        dialog_buttons(cx, "Delete");
+                                    },
+                                    |cx, delete: Option<bool>| {
+                                        if delete == Some(true) {
+                                            cx.emit(AppEvent::SetStatus(String::from(
+                                                "File deleted permanently",
+                                            )));
+                                            cx.emit(DialogEvent::<bool>::Cancel);
+                                        }
+                                    },
+                                );
+                            },
+                            |cx| Label::new(cx, "Delete permanently..."),
+                        );
+                        dialog_buttons(cx, "Delete");
+                    },
+                    |cx, delete: Option<bool>| {
+                        if delete == Some(true) {
+                            cx.emit(AppEvent::SetStatus(String::from("File moved to the trash")));
+                        }
+                    },
+                );
+            }
+
+            AppEvent::SetStatus(status) => {
+                self.status = status.clone();
+            }
+        });
+    }
+}
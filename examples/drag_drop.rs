@@ -0,0 +1,68 @@
+use vizia::prelude::*;
+
+const STYLE: &str = r#"
+    .item {
+        width: 150px;
+        height: 30px;
+        child-space: 1s;
+        background-color: white;
+        border-color: black;
+        border-width: 1px;
+    }
+
+    .item:drag-over {
+        background-color: #c0d8f0;
+    }
+
+    drag-preview {
+        background-color: #e0e0e0;
+        child-space: 8px;
+        opacity: 0.8;
+    }
+"#;
+
+#[derive(Lens)]
+pub struct AppData {
+    list: Vec<String>,
+}
+
+pub enum AppEvent {
+    Move(usize, usize),
+}
+
+impl Model for AppData {
+    fn event(&mut self, _: &mut EventContext, event: &mut Event) {
+        event.map(|app_event, _| match app_event {
+            AppEvent::Move(from, to) => {
+                let item = self.list.remove(*from);
+                self.list.insert(*to, item);
+            }
+        });
+    }
+}
+
+// Example of reordering the items of a list by dragging them onto each other
+fn main() {
+    Application::new(|cx| {
+        cx.add_theme(STYLE);
+
+        AppData { list: (1..=6).map(|i| format!("Preset {}", i)).collect() }.build(cx);
+
+        List::new(cx, AppData::list, |cx, index, item| {
+            Label::new(cx, item)
+                .class("item")
+                .draggable(index)
+                .drag_preview(move |cx| {
+                    Label::new(cx, item);
+                })
+                .drop_target(
+                    move |from: &usize| *from != index,
+                    move |cx, from: usize| cx.emit(AppEvent::Move(from, index)),
+                );
+        })
+        .row_between(Pixels(5.0))
+        .space(Stretch(1.0));
+    })
+    .title("Drag and Drop")
+    .run();
+}
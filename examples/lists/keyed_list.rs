@@ -0,0 +1,109 @@
+use std::time::Duration;
+
+use vizia::prelude::*;
+
+#[derive(Debug, Clone, Lens, Data)]
+pub struct Todo {
+    id: u32,
+    text: String,
+}
+
+#[derive(Lens)]
+pub struct AppData {
+    todos: Vec<Todo>,
+    next_id: u32,
+}
+
+pub enum AppEvent {
+    Insert,
+    Remove(u32),
+    Rotate,
+    SetText(u32, String),
+}
+
+impl Model for AppData {
+    fn event(&mut self, _: &mut EventContext, event: &mut Event) {
+        event.map(|app_event, _| match app_event {
+            AppEvent::Insert => {
+                // Insert into the middle of the list to show that the other rows are kept.
+                let todo = Todo { id: self.next_id, text: format!("Todo {}", self.next_id) };
+                self.todos.insert(self.todos.len() / 2, todo);
+                self.next_id += 1;
+            }
+
+            AppEvent::Remove(id) => {
+                self.todos.retain(|todo| todo.id != *id);
+            }
+
+            AppEvent::Rotate => {
+                if !self.todos.is_empty() {
+                    self.todos.rotate_left(1);
+                }
+            }
+
+            AppEvent::SetText(id, text) => {
+                if let Some(todo) = self.todos.iter_mut().find(|todo| todo.id == *id) {
+                    todo.text = text.clone();
+                }
+            }
+        });
+    }
+}
+
+fn main() {
+    Application::new(|cx| {
+        let todos = (0..5).map(|id| Todo { id, text: format!("Todo {}", id) }).collect();
+        AppData { todos, next_id: 5 }.build(cx);
+
+        let enter = cx
+            .add_animation(Duration::from_millis(200))
+            .add_keyframe(0.0, |keyframe| keyframe.set_opacity(0.0))
+            .add_keyframe(1.0, |keyframe| keyframe.set_opacity(1.0))
+            .build();
+
+        let exit = cx
+            .add_animation(Duration::from_millis(200))
+            .persistent()
+            .add_keyframe(0.0, |keyframe| keyframe.set_opacity(1.0))
+            .add_keyframe(1.0, |keyframe| keyframe.set_opacity(0.0))
+            .build();
+
+        VStack::new(cx, |cx| {
+            HStack::new(cx, |cx| {
+                Button::new(cx, |cx| cx.emit(AppEvent::Insert), |cx| Label::new(cx, "Insert"));
+                Button::new(cx, |cx| cx.emit(AppEvent::Rotate), |cx| Label::new(cx, "Rotate"));
+            })
+            .size(Auto)
+            .col_between(Pixels(5.0));
+
+            List::new_keyed(
+                cx,
+                AppData::todos,
+                |todo| todo.id,
+                |cx, todo| {
+                    let id = todo.get(cx).id;
+                    HStack::new(cx, move |cx| {
+                        // Text being edited is kept when other todos are inserted or moved.
+                        Textbox::new(cx, todo.then(Todo::text))
+                            .on_edit(move |cx, text| cx.emit(AppEvent::SetText(id, text)))
+                            .width(Pixels(200.0));
+                        Button::new(
+                            cx,
+                            move |cx| cx.emit(AppEvent::Remove(id)),
+                            |cx| Label::new(cx, "Remove"),
+                        );
+                    })
+                    .size(Auto)
+                    .col_between(Pixels(5.0));
+                },
+            )
+            .enter_animation(enter)
+            .exit_animation(exit, Duration::from_millis(200))
+            .row_between(Pixels(5.0));
+        })
+        .row_between(Pixels(10.0))
+        .child_space(Pixels(20.0));
+    })
+    .title("Keyed List")
+    .run();
+}
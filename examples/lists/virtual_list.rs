@@ -0,0 +1,40 @@
+use vizia::prelude::*;
+
+#[derive(Lens)]
+pub struct AppData {
+    pub samples: Vec<String>,
+}
+
+impl Model for AppData {}
+
+fn main() {
+    Application::new(|cx| {
+        AppData { samples: (0..200_000).map(|index| format!("Sample {}", index)).collect() }
+            .build(cx);
+
+        VStack::new(cx, |cx| {
+            Button::new(
+                cx,
+                |cx| {
+                    if let Some(list) = cx.resolve_entity_identifier("samples") {
+                        cx.emit_to(list, VirtualListEvent::Select(100_000));
+                    }
+                },
+                |cx| Label::new(cx, "Go to sample 100000"),
+            );
+
+            VirtualList::new(cx, AppData::samples, 24.0, |cx, _, item| {
+                Label::new(cx, item)
+                    .child_left(Pixels(5.0))
+                    .child_top(Stretch(1.0))
+                    .child_bottom(Stretch(1.0));
+            })
+            .id("samples")
+            .on_select(|_, index| println!("Selected sample {}", index));
+        })
+        .row_between(Pixels(10.0))
+        .child_space(Pixels(10.0));
+    })
+    .title("Virtual List")
+    .run();
+}
@@ -0,0 +1,49 @@
+use vizia::prelude::*;
+
+#[derive(Lens)]
+pub struct AppData {
+    pub snap: bool,
+}
+
+pub enum AppEvent {
+    ToggleSnap,
+}
+
+impl Model for AppData {
+    fn event(&mut self, _: &mut EventContext, event: &mut Event) {
+        event.map(|app_event, _| match app_event {
+            AppEvent::ToggleSnap => self.snap ^= true,
+        });
+    }
+}
+
+fn main() {
+    Application::new(|cx| {
+        AppData { snap: false }.build(cx);
+
+        Label::new(cx, "Right click anywhere in the window").space(Stretch(1.0)).hoverable(false);
+
+        Element::new(cx).size(Stretch(1.0)).position_type(PositionType::SelfDirected).context_menu(
+            |cx| {
+                MenuButton::new_simple(cx, "Cut", |_| println!("Cut"));
+                MenuButton::new_simple(cx, "Copy", |_| println!("Copy"));
+                MenuButton::new_simple(cx, "Paste", |_| println!("Paste"));
+                Menu::new(
+                    cx,
+                    |cx| Label::new(cx, "Grid"),
+                    |cx| {
+                        MenuButton::new_check_simple(
+                            cx,
+                            "Snap to grid",
+                            |cx| cx.emit(AppEvent::ToggleSnap),
+                            AppData::snap,
+                        );
+                        MenuButton::new_simple(cx, "Reset grid", |_| println!("Reset grid"));
+                    },
+                );
+            },
+        );
+    })
+    .title("Context Menu")
+    .run();
+}
@@ -0,0 +1,56 @@
+use vizia::prelude::*;
+
+#[derive(Lens)]
+pub struct AppData {
+    // With the serde feature the layout can be saved with the project and loaded again.
+    layout: DockLayout,
+}
+
+pub enum AppEvent {
+    SetLayout(DockLayout),
+    ResetLayout,
+}
+
+fn default_layout() -> DockLayout {
+    DockLayout {
+        left: DockZone::new(&["Browser"], 200.0),
+        right: DockZone::new(&["Inspector"], 250.0),
+        bottom: DockZone::new(&["Mixer", "Console"], 150.0),
+        center: DockZone::new(&["Arrangement", "Piano Roll"], 0.0),
+        floating: Vec::new(),
+    }
+}
+
+impl Model for AppData {
+    fn event(&mut self, _: &mut EventContext, event: &mut Event) {
+        event.map(|app_event, _| match app_event {
+            AppEvent::SetLayout(layout) => self.layout = layout.clone(),
+            AppEvent::ResetLayout => self.layout = default_layout(),
+        });
+    }
+}
+
+fn main() {
+    Application::new(|cx| {
+        AppData { layout: default_layout() }.build(cx);
+
+        VStack::new(cx, |cx| {
+            Button::new(
+                cx,
+                |cx| cx.emit(AppEvent::ResetLayout),
+                |cx| Label::new(cx, "Reset Layout"),
+            );
+
+            DockSpace::new(cx, AppData::layout, |cx, panel| {
+                Label::new(cx, panel).child_space(Stretch(1.0)).size(Stretch(1.0));
+            })
+            .on_change(|cx, layout| cx.emit(AppEvent::SetLayout(layout)))
+            .height(Stretch(1.0));
+        })
+        .row_between(Pixels(10.0))
+        .child_space(Pixels(10.0));
+    })
+    .title("Dock Space")
+    .inner_size((1000, 700))
+    .run();
+}
@@ -0,0 +1,69 @@
+use vizia::prelude::*;
+
+fn main() {
+    Application::new(|cx| {
+        AppData { exports: 0 }.build(cx);
+
+        VStack::new(cx, |cx| {
+            Button::new(
+                cx,
+                |cx| {
+                    cx.emit(NotificationEvent::Show(
+                        Notification::new("Preset saved").severity(Severity::Success),
+                    ))
+                },
+                |cx| Label::new(cx, "Save preset"),
+            );
+
+            Button::new(
+                cx,
+                |cx| {
+                    cx.emit(NotificationEvent::Show(
+                        Notification::new("Export failed")
+                            .severity(Severity::Error)
+                            .timeout(None)
+                            .action("Retry", |cx| cx.emit(AppEvent::Export)),
+                    ))
+                },
+                |cx| Label::new(cx, "Export"),
+            );
+
+            Button::new(
+                cx,
+                |cx| cx.emit(NotificationEvent::DismissAll),
+                |cx| Label::new(cx, "Dismiss all"),
+            );
+
+            Label::new(cx, AppData::exports.map(|exports| format!("Exports: {}", exports)));
+        })
+        .space(Pixels(50.0))
+        .row_between(Pixels(10.0));
+
+        Notifications::new(cx);
+    })
+    .title("Notifications")
+    .run();
+}
+
+#[derive(Debug)]
+pub enum AppEvent {
+    Export,
+}
+
+#[derive(Lens)]
+pub struct AppData {
+    exports: usize,
+}
+
+impl Model for AppData {
+    fn event(&mut self, cx: &mut EventContext, event: &mut Event) {
+        event.map(|app_event, _| match app_event {
+            AppEvent::Export => {
+                self.exports += 1;
+                cx.emit(NotificationEvent::Show(
+                    Notification::new("Export finished").severity(Severity::Success),
+                ));
+            }
+        });
+    }
+}
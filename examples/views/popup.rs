@@ -4,13 +4,20 @@ fn main() {
     Application::new(|cx| {
         PopupData::default().build(cx);
 
-        Button::new(cx, |cx| cx.emit(PopupEvent::Switch), |cx| Label::new(cx, "Open"));
+        VStack::new(cx, |cx| {
+            Button::new(cx, |cx| cx.emit(PopupEvent::Switch), |cx| Label::new(cx, "Open"));
 
-        Popup::new(cx, PopupData::is_open, true, |_| {})
-            .on_blur(|cx| cx.emit(PopupEvent::Close))
-            .space(Pixels(100.0))
-            .size(Pixels(200.0))
-            .background_color(Color::red());
+            // The popup is placed below the button, and flipped above it when the window is too
+            // short to fit it below.
+            Popup::new(cx, PopupData::is_open, true, |_| {})
+                .on_blur(|cx| cx.emit(PopupEvent::Close))
+                .placement(Placement::BottomStart)
+                .offset(4.0)
+                .size(Pixels(200.0))
+                .background_color(Color::red());
+        })
+        .size(Auto)
+        .space(Pixels(100.0));
     })
     .title("Popup")
     .run();
@@ -0,0 +1,65 @@
+use vizia::prelude::*;
+
+#[derive(Lens)]
+pub struct AppData {
+    // The sizes are kept in the application data so that they can be saved with the project.
+    columns: Vec<f32>,
+    rows: Vec<f32>,
+}
+
+pub enum AppEvent {
+    SetColumns(Vec<f32>),
+    SetRows(Vec<f32>),
+}
+
+impl Model for AppData {
+    fn event(&mut self, _: &mut EventContext, event: &mut Event) {
+        event.map(|app_event, _| match app_event {
+            AppEvent::SetColumns(sizes) => self.columns = sizes.clone(),
+            AppEvent::SetRows(sizes) => self.rows = sizes.clone(),
+        });
+    }
+}
+
+fn main() {
+    Application::new(|cx| {
+        AppData { columns: vec![200.0], rows: Vec::new() }.build(cx);
+
+        HSplit::new(
+            cx,
+            AppData::columns,
+            vec![
+                SplitPane::new(|cx| {
+                    Label::new(cx, "Sidebar");
+                })
+                .min_size(100.0)
+                .max_size(400.0),
+                SplitPane::new(|cx| {
+                    VSplit::new(
+                        cx,
+                        AppData::rows,
+                        vec![
+                            SplitPane::new(|cx| {
+                                Label::new(cx, "Editor");
+                            })
+                            .size(300.0)
+                            .min_size(50.0)
+                            .collapsible(false),
+                            SplitPane::new(|cx| {
+                                Label::new(cx, "Console");
+                            })
+                            .min_size(50.0),
+                        ],
+                    )
+                    .on_resize(|cx, sizes| cx.emit(AppEvent::SetRows(sizes.to_vec())));
+                }),
+                SplitPane::new(|cx| {
+                    Label::new(cx, "Inspector");
+                }),
+            ],
+        )
+        .on_resize(|cx, sizes| cx.emit(AppEvent::SetColumns(sizes.to_vec())));
+    })
+    .title("Split")
+    .run();
+}
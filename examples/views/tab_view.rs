@@ -0,0 +1,104 @@
+use vizia::prelude::*;
+
+#[derive(Debug, Clone, Lens, Data)]
+pub struct Document {
+    id: u32,
+    title: String,
+    text: String,
+}
+
+#[derive(Lens)]
+pub struct AppData {
+    documents: Vec<Document>,
+    active: u32,
+    next_id: u32,
+}
+
+pub enum AppEvent {
+    Add,
+    Select(u32),
+    Close(u32),
+    Move(usize, usize),
+    SetText(u32, String),
+}
+
+impl Model for AppData {
+    fn event(&mut self, _: &mut EventContext, event: &mut Event) {
+        event.map(|app_event, _| match app_event {
+            AppEvent::Add => {
+                let id = self.next_id;
+                self.documents.push(Document {
+                    id,
+                    title: format!("Document {}", id),
+                    text: String::new(),
+                });
+                self.active = id;
+                self.next_id += 1;
+            }
+
+            AppEvent::Select(id) => self.active = *id,
+
+            AppEvent::Close(id) => {
+                if let Some(index) = self.documents.iter().position(|doc| doc.id == *id) {
+                    self.documents.remove(index);
+
+                    // Closing the active tab activates the tab which took its place.
+                    if self.active == *id {
+                        if let Some(doc) = self.documents.get(index).or(self.documents.last()) {
+                            self.active = doc.id;
+                        }
+                    }
+                }
+            }
+
+            AppEvent::Move(from, to) => {
+                let doc = self.documents.remove(*from);
+                self.documents.insert(*to, doc);
+            }
+
+            AppEvent::SetText(id, text) => {
+                if let Some(doc) = self.documents.iter_mut().find(|doc| doc.id == *id) {
+                    doc.text = text.clone();
+                }
+            }
+        });
+    }
+}
+
+fn main() {
+    Application::new(|cx| {
+        let documents = (0..3)
+            .map(|id| Document { id, title: format!("Document {}", id), text: String::new() })
+            .collect();
+        AppData { documents, active: 0, next_id: 3 }.build(cx);
+
+        VStack::new(cx, |cx| {
+            Button::new(cx, |cx| cx.emit(AppEvent::Add), |cx| Label::new(cx, "New Tab"));
+
+            TabView::new(
+                cx,
+                AppData::documents,
+                AppData::active,
+                |doc| doc.id,
+                |cx, doc| {
+                    Label::new(cx, doc.then(Document::title));
+                },
+                |cx, doc| {
+                    let id = doc.get(cx).id;
+                    // The text being edited is kept when switching between tabs.
+                    Textbox::new_multiline(cx, doc.then(Document::text), true)
+                        .on_edit(move |cx, text| cx.emit(AppEvent::SetText(id, text)))
+                        .size(Stretch(1.0));
+                },
+            )
+            .on_select(|cx, id| cx.emit(AppEvent::Select(id)))
+            .on_close(|cx, id| cx.emit(AppEvent::Close(id)))
+            .on_move(|cx, from, to| cx.emit(AppEvent::Move(from, to)))
+            .height(Stretch(1.0));
+        })
+        .row_between(Pixels(10.0))
+        .child_space(Pixels(20.0));
+    })
+    .title("Tab View")
+    .run();
+}
@@ -0,0 +1,24 @@
+use vizia::prelude::*;
+
+fn main() {
+    Application::new(|cx| {
+        HStack::new(cx, |cx| {
+            Button::new(cx, |_| println!("Save"), |cx| Label::new(cx, "Save"))
+                .tooltip_text("Saves the current document");
+
+            Button::new(cx, |_| println!("Delete"), |cx| Label::new(cx, "Delete")).tooltip(|cx| {
+                VStack::new(cx, |cx| {
+                    Label::new(cx, "Delete").font_weight(Weight::BOLD);
+                    Label::new(cx, "Removes the selected items permanently");
+                })
+                .size(Auto)
+                .row_between(Pixels(4.0));
+            });
+        })
+        .size(Auto)
+        .col_between(Pixels(10.0))
+        .space(Stretch(1.0));
+    })
+    .title("Tooltip")
+    .run();
+}
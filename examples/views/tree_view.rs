@@ -0,0 +1,120 @@
+use vizia::prelude::*;
+
+#[derive(Debug, Clone, Lens, Data)]
+pub struct Node {
+    name: String,
+    // Folders are loaded when they are first expanded.
+    folder: bool,
+    children: Vec<Node>,
+}
+
+impl Node {
+    fn file(name: &str) -> Self {
+        Self { name: name.to_string(), folder: false, children: Vec::new() }
+    }
+
+    fn folder(name: &str) -> Self {
+        Self { name: name.to_string(), folder: true, children: Vec::new() }
+    }
+}
+
+#[derive(Lens)]
+pub struct AppData {
+    nodes: Vec<Node>,
+    selected: String,
+}
+
+pub enum AppEvent {
+    Load(Vec<usize>),
+    Move(Vec<usize>, Vec<usize>, TreeDropPosition),
+    SetSelected(Vec<Vec<usize>>),
+}
+
+fn children_mut<'a>(mut nodes: &'a mut Vec<Node>, path: &[usize]) -> &'a mut Vec<Node> {
+    for index in path {
+        nodes = &mut nodes[*index].children;
+    }
+
+    nodes
+}
+
+impl Model for AppData {
+    fn event(&mut self, _: &mut EventContext, event: &mut Event) {
+        event.map(|app_event, _| match app_event {
+            AppEvent::Load(path) => {
+                let (last, parent) = path.split_last().unwrap();
+                let node = &mut children_mut(&mut self.nodes, parent)[*last];
+                if node.folder && node.children.is_empty() {
+                    node.children = vec![
+                        Node::folder(&format!("{} folder", node.name)),
+                        Node::file("a.txt"),
+                        Node::file("b.txt"),
+                    ];
+                }
+            }
+
+            AppEvent::Move(from, to, position) => {
+                let (last, parent) = from.split_last().unwrap();
+                let node = children_mut(&mut self.nodes, parent).remove(*last);
+
+                // Removing the node moves its later siblings, and their descendants, back by one.
+                let mut to = to.clone();
+                let depth = parent.len();
+                if to.len() > depth && to[..depth] == *parent && to[depth] > *last {
+                    to[depth] -= 1;
+                }
+
+                let (last, parent) = to.split_last().unwrap();
+                match position {
+                    TreeDropPosition::Before => {
+                        children_mut(&mut self.nodes, parent).insert(*last, node);
+                    }
+                    TreeDropPosition::Inside => children_mut(&mut self.nodes, &to).push(node),
+                    TreeDropPosition::After => {
+                        children_mut(&mut self.nodes, parent).insert(*last + 1, node);
+                    }
+                }
+            }
+
+            AppEvent::SetSelected(paths) => {
+                self.selected = format!("Selected {:?}", paths);
+            }
+        });
+    }
+}
+
+fn main() {
+    Application::new(|cx| {
+        AppData {
+            nodes: vec![Node::folder("src"), Node::folder("examples"), Node::file("Cargo.toml")],
+            selected: String::from("Selected []"),
+        }
+        .build(cx);
+
+        VStack::new(cx, |cx| {
+            TreeView::new_lazy(
+                cx,
+                AppData::nodes,
+                24.0,
+                |node| &node.children,
+                |node| node.folder,
+                |cx, _, node| {
+                    Label::new(cx, node.then(Node::name));
+                },
+            )
+            .selection_mode(SelectionMode::Multiple)
+            .on_expand(|cx, path| cx.emit(AppEvent::Load(path.to_vec())))
+            .on_drop(|cx, from, to, position| {
+                cx.emit(AppEvent::Move(from.to_vec(), to.to_vec(), position))
+            })
+            .on_select(|cx, paths| cx.emit(AppEvent::SetSelected(paths.to_vec())))
+            .height(Stretch(1.0));
+
+            Label::new(cx, AppData::selected);
+        })
+        .row_between(Pixels(10.0))
+        .child_space(Pixels(20.0));
+    })
+    .title("Tree View")
+    .run();
+}
@@ -0,0 +1,83 @@
+use vizia::prelude::*;
+
+#[derive(Lens)]
+pub struct AppData {
+    show_settings: bool,
+    volume: f32,
+}
+
+pub enum AppEvent {
+    ShowSettings,
+    HideSettings,
+    SetVolume(f32),
+}
+
+impl Model for AppData {
+    fn event(&mut self, _: &mut EventContext, event: &mut Event) {
+        event.map(|app_event, _| match app_event {
+            AppEvent::ShowSettings => {
+                self.show_settings = true;
+            }
+
+            AppEvent::HideSettings => {
+                self.show_settings = false;
+            }
+
+            AppEvent::SetVolume(volume) => {
+                self.volume = *volume;
+            }
+        });
+    }
+}
+
+#[cfg(feature = "baseview")]
+fn main() {
+    panic!("This example is not supported on baseview");
+}
+
+#[cfg(all(not(feature = "baseview")))]
+fn main() {
+    Application::new(|cx| {
+        AppData { show_settings: false, volume: 0.5 }.build(cx);
+
+        VStack::new(cx, |cx| {
+            Button::new(
+                cx,
+                |cx| cx.emit(AppEvent::ShowSettings),
+                |cx| Label::new(cx, "Open Settings"),
+            )
+            .disabled(AppData::show_settings);
+
+            Label::new(cx, AppData::volume.map(|volume| format!("Volume: {:.2}", volume)));
+        })
+        .child_space(Stretch(1.0))
+        .row_between(Pixels(10.0));
+
+        // The settings window shares the application data with the main window.
+        Binding::new(cx, AppData::show_settings, |cx, show_settings| {
+            if show_settings.get(cx) {
+                Window::new(cx, |cx| {
+                    VStack::new(cx, |cx| {
+                        Label::new(cx, "Volume");
+                        Slider::new(cx, AppData::volume)
+                            .on_changing(|cx, val| cx.emit(AppEvent::SetVolume(val)))
+                            .width(Pixels(200.0));
+                        Button::new(
+                            cx,
+                            |cx| cx.emit(WindowEvent::WindowClose),
+                            |cx| Label::new(cx, "Close"),
+                        );
+                    })
+                    .child_space(Stretch(1.0))
+                    .row_between(Pixels(10.0));
+                })
+                .title("Settings")
+                .inner_size((300, 200))
+                .on_close(|cx| cx.emit(AppEvent::HideSettings));
+            }
+        });
+    })
+    .title("Windows")
+    .inner_size((400, 300))
+    .run();
+}